@@ -250,6 +250,138 @@ typedef struct UniffiForeignFutureStructVoid {
 typedef void (*UniffiForeignFutureCompleteVoid)(uint64_t, UniffiForeignFutureStructVoid
     );
 
+#endif
+#ifndef UNIFFI_FFIDEF_CALLBACK_INTERFACE_CLOCK_DRIFT_CALLBACK_METHOD0
+#define UNIFFI_FFIDEF_CALLBACK_INTERFACE_CLOCK_DRIFT_CALLBACK_METHOD0
+typedef void (*UniffiCallbackInterfaceClockDriftCallbackMethod0)(uint64_t, int64_t, void* _Nonnull, 
+        RustCallStatus *_Nonnull uniffiCallStatus
+    );
+
+#endif
+#ifndef UNIFFI_FFIDEF_CALLBACK_INTERFACE_CONNECTION_STATUS_CALLBACK_METHOD0
+#define UNIFFI_FFIDEF_CALLBACK_INTERFACE_CONNECTION_STATUS_CALLBACK_METHOD0
+typedef void (*UniffiCallbackInterfaceConnectionStatusCallbackMethod0)(uint64_t, RustBuffer, void* _Nonnull, 
+        RustCallStatus *_Nonnull uniffiCallStatus
+    );
+
+#endif
+#ifndef UNIFFI_FFIDEF_CALLBACK_INTERFACE_L2_BOOK_CALLBACK_METHOD0
+#define UNIFFI_FFIDEF_CALLBACK_INTERFACE_L2_BOOK_CALLBACK_METHOD0
+typedef void (*UniffiCallbackInterfaceL2BookCallbackMethod0)(uint64_t, RustBuffer, void* _Nonnull, 
+        RustCallStatus *_Nonnull uniffiCallStatus
+    );
+
+#endif
+#ifndef UNIFFI_FFIDEF_CALLBACK_INTERFACE_LOG_CALLBACK_METHOD0
+#define UNIFFI_FFIDEF_CALLBACK_INTERFACE_LOG_CALLBACK_METHOD0
+typedef void (*UniffiCallbackInterfaceLogCallbackMethod0)(uint64_t, RustBuffer, RustBuffer, RustBuffer, void* _Nonnull, 
+        RustCallStatus *_Nonnull uniffiCallStatus
+    );
+
+#endif
+#ifndef UNIFFI_FFIDEF_CALLBACK_INTERFACE_MIDS_CALLBACK_METHOD0
+#define UNIFFI_FFIDEF_CALLBACK_INTERFACE_MIDS_CALLBACK_METHOD0
+typedef void (*UniffiCallbackInterfaceMidsCallbackMethod0)(uint64_t, RustBuffer, void* _Nonnull, 
+        RustCallStatus *_Nonnull uniffiCallStatus
+    );
+
+#endif
+#ifndef UNIFFI_FFIDEF_CALLBACK_INTERFACE_USER_EVENT_CALLBACK_METHOD0
+#define UNIFFI_FFIDEF_CALLBACK_INTERFACE_USER_EVENT_CALLBACK_METHOD0
+typedef void (*UniffiCallbackInterfaceUserEventCallbackMethod0)(uint64_t, RustBuffer, void* _Nonnull, 
+        RustCallStatus *_Nonnull uniffiCallStatus
+    );
+
+#endif
+#ifndef UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_CLOCK_DRIFT_CALLBACK
+#define UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_CLOCK_DRIFT_CALLBACK
+typedef struct UniffiVTableCallbackInterfaceClockDriftCallback {
+    UniffiCallbackInterfaceClockDriftCallbackMethod0 _Nonnull onClockDrift;
+    UniffiCallbackInterfaceFree _Nonnull uniffiFree;
+} UniffiVTableCallbackInterfaceClockDriftCallback;
+
+#endif
+#ifndef UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_CONNECTION_STATUS_CALLBACK
+#define UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_CONNECTION_STATUS_CALLBACK
+typedef struct UniffiVTableCallbackInterfaceConnectionStatusCallback {
+    UniffiCallbackInterfaceConnectionStatusCallbackMethod0 _Nonnull onStatus;
+    UniffiCallbackInterfaceFree _Nonnull uniffiFree;
+} UniffiVTableCallbackInterfaceConnectionStatusCallback;
+
+#endif
+#ifndef UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_L2_BOOK_CALLBACK
+#define UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_L2_BOOK_CALLBACK
+typedef struct UniffiVTableCallbackInterfaceL2BookCallback {
+    UniffiCallbackInterfaceL2BookCallbackMethod0 _Nonnull onBook;
+    UniffiCallbackInterfaceFree _Nonnull uniffiFree;
+} UniffiVTableCallbackInterfaceL2BookCallback;
+
+#endif
+#ifndef UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_LOG_CALLBACK
+#define UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_LOG_CALLBACK
+typedef struct UniffiVTableCallbackInterfaceLogCallback {
+    UniffiCallbackInterfaceLogCallbackMethod0 _Nonnull onLog;
+    UniffiCallbackInterfaceFree _Nonnull uniffiFree;
+} UniffiVTableCallbackInterfaceLogCallback;
+
+#endif
+#ifndef UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_MIDS_CALLBACK
+#define UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_MIDS_CALLBACK
+typedef struct UniffiVTableCallbackInterfaceMidsCallback {
+    UniffiCallbackInterfaceMidsCallbackMethod0 _Nonnull onMids;
+    UniffiCallbackInterfaceFree _Nonnull uniffiFree;
+} UniffiVTableCallbackInterfaceMidsCallback;
+
+#endif
+#ifndef UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_USER_EVENT_CALLBACK
+#define UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_USER_EVENT_CALLBACK
+typedef struct UniffiVTableCallbackInterfaceUserEventCallback {
+    UniffiCallbackInterfaceUserEventCallbackMethod0 _Nonnull onEvent;
+    UniffiCallbackInterfaceFree _Nonnull uniffiFree;
+} UniffiVTableCallbackInterfaceUserEventCallback;
+
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_CLONE_CANCELLATIONTOKEN
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_CLONE_CANCELLATIONTOKEN
+void*_Nonnull uniffi_hyperliquid_swift_fn_clone_cancellationtoken(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_FREE_CANCELLATIONTOKEN
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_FREE_CANCELLATIONTOKEN
+void uniffi_hyperliquid_swift_fn_free_cancellationtoken(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_CONSTRUCTOR_CANCELLATIONTOKEN_NEW
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_CONSTRUCTOR_CANCELLATIONTOKEN_NEW
+void*_Nonnull uniffi_hyperliquid_swift_fn_constructor_cancellationtoken_new(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_CANCELLATIONTOKEN_CANCEL
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_CANCELLATIONTOKEN_CANCEL
+void uniffi_hyperliquid_swift_fn_method_cancellationtoken_cancel(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_CANCELLATIONTOKEN_IS_CANCELLED
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_CANCELLATIONTOKEN_IS_CANCELLED
+int8_t uniffi_hyperliquid_swift_fn_method_cancellationtoken_is_cancelled(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_CLONE_CONNECTIONPOOL
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_CLONE_CONNECTIONPOOL
+void*_Nonnull uniffi_hyperliquid_swift_fn_clone_connectionpool(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_FREE_CONNECTIONPOOL
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_FREE_CONNECTIONPOOL
+void uniffi_hyperliquid_swift_fn_free_connectionpool(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_CONSTRUCTOR_CONNECTIONPOOL_NEW
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_CONSTRUCTOR_CONNECTIONPOOL_NEW
+void*_Nonnull uniffi_hyperliquid_swift_fn_constructor_connectionpool_new(RustCallStatus *_Nonnull out_status
+    
+);
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_CLONE_HYPERLIQUIDEXCHANGE
 #define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_CLONE_HYPERLIQUIDEXCHANGE
@@ -261,6 +393,41 @@ void*_Nonnull uniffi_hyperliquid_swift_fn_clone_hyperliquidexchange(void*_Nonnul
 void uniffi_hyperliquid_swift_fn_free_hyperliquidexchange(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_APPROVE_AGENT
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_APPROVE_AGENT
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_approve_agent(void*_Nonnull ptr, RustBuffer agent_address, RustBuffer name, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_APPROVE_AGENT_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_APPROVE_AGENT_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_approve_agent_async(void*_Nonnull ptr, RustBuffer agent_address, RustBuffer name
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_APPROVE_BUILDER_FEE
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_APPROVE_BUILDER_FEE
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_approve_builder_fee(void*_Nonnull ptr, RustBuffer builder, RustBuffer max_fee_rate, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_APPROVE_BUILDER_FEE_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_APPROVE_BUILDER_FEE_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_approve_builder_fee_async(void*_Nonnull ptr, RustBuffer builder, RustBuffer max_fee_rate
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_ASSET_TO_INDEX
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_ASSET_TO_INDEX
+uint32_t uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_asset_to_index(void*_Nonnull ptr, RustBuffer name, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_BULK_MODIFY
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_BULK_MODIFY
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_bulk_modify(void*_Nonnull ptr, RustBuffer modifies, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_BULK_MODIFY_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_BULK_MODIFY_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_bulk_modify_async(void*_Nonnull ptr, RustBuffer modifies
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_ALL_ORDERS
 #define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_ALL_ORDERS
 RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_cancel_all_orders(void*_Nonnull ptr, RustBuffer asset, RustCallStatus *_Nonnull out_status
@@ -271,6 +438,16 @@ RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_cancel_all_ord
 uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_cancel_all_orders_async(void*_Nonnull ptr, RustBuffer asset
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_BY_CLOID
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_BY_CLOID
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_cancel_by_cloid(void*_Nonnull ptr, RustBuffer asset, RustBuffer cloid, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_BY_CLOID_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_BY_CLOID_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_cancel_by_cloid_async(void*_Nonnull ptr, RustBuffer asset, RustBuffer cloid
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_ORDER
 #define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_ORDER
 RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_cancel_order(void*_Nonnull ptr, RustBuffer cancel, RustCallStatus *_Nonnull out_status
@@ -281,436 +458,1979 @@ RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_cancel_order(v
 uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_cancel_order_async(void*_Nonnull ptr, RustBuffer cancel
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_GET_WALLET_ADDRESS
-#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_GET_WALLET_ADDRESS
-RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_get_wallet_address(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_ORDERS
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_ORDERS
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_cancel_orders(void*_Nonnull ptr, RustBuffer cancels, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER
-#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER
-RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_place_order(void*_Nonnull ptr, RustBuffer order, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_ORDERS_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_ORDERS_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_cancel_orders_async(void*_Nonnull ptr, RustBuffer cancels
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER_ASYNC
-#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER_ASYNC
-uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_place_order_async(void*_Nonnull ptr, RustBuffer order
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_REPLACE
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_REPLACE
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_cancel_replace(void*_Nonnull ptr, uint64_t oid, RustBuffer new_order, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_CLONE_HYPERLIQUIDINFO
-#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_CLONE_HYPERLIQUIDINFO
-void*_Nonnull uniffi_hyperliquid_swift_fn_clone_hyperliquidinfo(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_REPLACE_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_REPLACE_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_cancel_replace_async(void*_Nonnull ptr, uint64_t oid, RustBuffer new_order
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_FREE_HYPERLIQUIDINFO
-#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_FREE_HYPERLIQUIDINFO
-void uniffi_hyperliquid_swift_fn_free_hyperliquidinfo(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_TWAP
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_TWAP
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_cancel_twap(void*_Nonnull ptr, RustBuffer asset, uint64_t twap_id, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_ALL_MIDS
-#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_ALL_MIDS
-RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_all_mids(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_TWAP_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_TWAP_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_cancel_twap_async(void*_Nonnull ptr, RustBuffer asset, uint64_t twap_id
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_ALL_MIDS_ASYNC
-#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_ALL_MIDS_ASYNC
-uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_all_mids_async(void*_Nonnull ptr
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_CANCELLABLE
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_CANCELLABLE
+void*_Nonnull uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_cancellable(void*_Nonnull ptr, void*_Nonnull token, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_OPEN_ORDERS
-#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_OPEN_ORDERS
-RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_open_orders(void*_Nonnull ptr, RustBuffer address, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_CLASS_TRANSFER
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_CLASS_TRANSFER
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_class_transfer(void*_Nonnull ptr, double amount, int8_t to_perp, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_OPEN_ORDERS_ASYNC
-#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_OPEN_ORDERS_ASYNC
-uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_open_orders_async(void*_Nonnull ptr, RustBuffer address
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_CLASS_TRANSFER_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_CLASS_TRANSFER_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_class_transfer_async(void*_Nonnull ptr, double amount, int8_t to_perp
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_USER_BALANCES
-#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_USER_BALANCES
-RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_user_balances(void*_Nonnull ptr, RustBuffer address, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_CLOSE_POSITION
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_CLOSE_POSITION
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_close_position(void*_Nonnull ptr, RustBuffer asset, RustBuffer slippage, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_USER_BALANCES_ASYNC
-#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_USER_BALANCES_ASYNC
-uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_user_balances_async(void*_Nonnull ptr, RustBuffer address
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_CLOSE_POSITION_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_CLOSE_POSITION_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_close_position_async(void*_Nonnull ptr, RustBuffer asset, RustBuffer slippage
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_USER_STATE
-#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_USER_STATE
-RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_user_state(void*_Nonnull ptr, RustBuffer address, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_GET_BASE_URL
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_GET_BASE_URL
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_get_base_url(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_USER_STATE_ASYNC
-#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_USER_STATE_ASYNC
-uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_user_state_async(void*_Nonnull ptr, RustBuffer address
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_GET_SERVER_TIME
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_GET_SERVER_TIME
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_get_server_time(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_FUNC_CREATE_EXCHANGE_CLIENT
-#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_FUNC_CREATE_EXCHANGE_CLIENT
-void*_Nonnull uniffi_hyperliquid_swift_fn_func_create_exchange_client(RustBuffer private_key, RustBuffer base_url, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_GET_SERVER_TIME_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_GET_SERVER_TIME_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_get_server_time_async(void*_Nonnull ptr
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_FUNC_CREATE_INFO_CLIENT
-#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_FUNC_CREATE_INFO_CLIENT
-void*_Nonnull uniffi_hyperliquid_swift_fn_func_create_info_client(RustBuffer base_url, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_GET_WALLET_ADDRESS
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_GET_WALLET_ADDRESS
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_get_wallet_address(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUSTBUFFER_ALLOC
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUSTBUFFER_ALLOC
-RustBuffer ffi_hyperliquid_swift_rustbuffer_alloc(uint64_t size, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_INDEX_TO_ASSET
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_INDEX_TO_ASSET
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_index_to_asset(void*_Nonnull ptr, uint32_t index, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUSTBUFFER_FROM_BYTES
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUSTBUFFER_FROM_BYTES
-RustBuffer ffi_hyperliquid_swift_rustbuffer_from_bytes(ForeignBytes bytes, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_MODIFY_ORDER
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_MODIFY_ORDER
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_modify_order(void*_Nonnull ptr, uint64_t oid, RustBuffer new_order, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUSTBUFFER_FREE
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUSTBUFFER_FREE
-void ffi_hyperliquid_swift_rustbuffer_free(RustBuffer buf, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_MODIFY_ORDER_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_MODIFY_ORDER_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_modify_order_async(void*_Nonnull ptr, uint64_t oid, RustBuffer new_order
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUSTBUFFER_RESERVE
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUSTBUFFER_RESERVE
-RustBuffer ffi_hyperliquid_swift_rustbuffer_reserve(RustBuffer buf, uint64_t additional, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_place_order(void*_Nonnull ptr, RustBuffer order, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_U8
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_U8
-void ffi_hyperliquid_swift_rust_future_poll_u8(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_place_order_async(void*_Nonnull ptr, RustBuffer order
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_U8
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_U8
-void ffi_hyperliquid_swift_rust_future_cancel_u8(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER_OID
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER_OID
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_place_order_oid(void*_Nonnull ptr, RustBuffer order, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_U8
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_U8
-void ffi_hyperliquid_swift_rust_future_free_u8(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER_OID_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER_OID_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_place_order_oid_async(void*_Nonnull ptr, RustBuffer order
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_U8
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_U8
-uint8_t ffi_hyperliquid_swift_rust_future_complete_u8(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER_RELATIVE
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER_RELATIVE
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_place_order_relative(void*_Nonnull ptr, RustBuffer asset, RustBuffer side, double size, double offset_bps, RustBuffer tif, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_I8
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_I8
-void ffi_hyperliquid_swift_rust_future_poll_i8(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER_RELATIVE_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER_RELATIVE_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_place_order_relative_async(void*_Nonnull ptr, RustBuffer asset, RustBuffer side, double size, double offset_bps, RustBuffer tif
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_I8
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_I8
-void ffi_hyperliquid_swift_rust_future_cancel_i8(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER_WITH_MARGIN
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER_WITH_MARGIN
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_place_order_with_margin(void*_Nonnull ptr, RustBuffer order, RustBuffer margin_mode, uint32_t leverage, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_I8
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_I8
-void ffi_hyperliquid_swift_rust_future_free_i8(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER_WITH_MARGIN_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER_WITH_MARGIN_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_place_order_with_margin_async(void*_Nonnull ptr, RustBuffer order, RustBuffer margin_mode, uint32_t leverage
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_I8
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_I8
-int8_t ffi_hyperliquid_swift_rust_future_complete_i8(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER_WITH_TPSL
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER_WITH_TPSL
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_place_order_with_tpsl(void*_Nonnull ptr, RustBuffer entry, RustBuffer take_profit, RustBuffer stop_loss, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_U16
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_U16
-void ffi_hyperliquid_swift_rust_future_poll_u16(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER_WITH_TPSL_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER_WITH_TPSL_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_place_order_with_tpsl_async(void*_Nonnull ptr, RustBuffer entry, RustBuffer take_profit, RustBuffer stop_loss
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_U16
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_U16
-void ffi_hyperliquid_swift_rust_future_cancel_u16(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDERS_BULK
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDERS_BULK
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_place_orders_bulk(void*_Nonnull ptr, RustBuffer orders, RustBuffer grouping, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_U16
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_U16
-void ffi_hyperliquid_swift_rust_future_free_u16(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDERS_BULK_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDERS_BULK_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_place_orders_bulk_async(void*_Nonnull ptr, RustBuffer orders, RustBuffer grouping
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_U16
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_U16
-uint16_t ffi_hyperliquid_swift_rust_future_complete_u16(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_PLACE_TWAP
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_PLACE_TWAP
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_place_twap(void*_Nonnull ptr, RustBuffer asset, RustBuffer side, double size, uint32_t duration_minutes, int8_t reduce_only, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_I16
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_I16
-void ffi_hyperliquid_swift_rust_future_poll_i16(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_PLACE_TWAP_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_PLACE_TWAP_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_place_twap_async(void*_Nonnull ptr, RustBuffer asset, RustBuffer side, double size, uint32_t duration_minutes, int8_t reduce_only
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_I16
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_I16
-void ffi_hyperliquid_swift_rust_future_cancel_i16(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_REFRESH_META
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_REFRESH_META
+void uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_refresh_meta(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_I16
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_I16
-void ffi_hyperliquid_swift_rust_future_free_i16(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_REFRESH_META_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_REFRESH_META_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_refresh_meta_async(void*_Nonnull ptr
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_I16
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_I16
-int16_t ffi_hyperliquid_swift_rust_future_complete_i16(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_SCHEDULE_CANCEL
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_SCHEDULE_CANCEL
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_schedule_cancel(void*_Nonnull ptr, RustBuffer time_ms, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_U32
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_U32
-void ffi_hyperliquid_swift_rust_future_poll_u32(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_SCHEDULE_CANCEL_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_SCHEDULE_CANCEL_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_schedule_cancel_async(void*_Nonnull ptr, RustBuffer time_ms
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_U32
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_U32
-void ffi_hyperliquid_swift_rust_future_cancel_u32(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_SET_CLOCK_DRIFT_CALLBACK
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_SET_CLOCK_DRIFT_CALLBACK
+void uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_set_clock_drift_callback(void*_Nonnull ptr, uint64_t callback, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_U32
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_U32
-void ffi_hyperliquid_swift_rust_future_free_u32(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_SET_DEFAULT_SLIPPAGE
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_SET_DEFAULT_SLIPPAGE
+void uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_set_default_slippage(void*_Nonnull ptr, double fraction, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_U32
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_U32
-uint32_t ffi_hyperliquid_swift_rust_future_complete_u32(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_SET_REFERRER
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_SET_REFERRER
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_set_referrer(void*_Nonnull ptr, RustBuffer code, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_I32
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_I32
-void ffi_hyperliquid_swift_rust_future_poll_i32(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_SET_REFERRER_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_SET_REFERRER_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_set_referrer_async(void*_Nonnull ptr, RustBuffer code
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_I32
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_I32
-void ffi_hyperliquid_swift_rust_future_cancel_i32(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_SIGN_MESSAGE
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_SIGN_MESSAGE
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_sign_message(void*_Nonnull ptr, RustBuffer message, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_I32
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_I32
-void ffi_hyperliquid_swift_rust_future_free_i32(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_SIGN_ORDER
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_SIGN_ORDER
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_sign_order(void*_Nonnull ptr, RustBuffer order, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_I32
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_I32
-int32_t ffi_hyperliquid_swift_rust_future_complete_i32(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_SIGN_ORDER_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_SIGN_ORDER_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_sign_order_async(void*_Nonnull ptr, RustBuffer order
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_U64
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_U64
-void ffi_hyperliquid_swift_rust_future_poll_u64(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_SPOT_TRANSFER
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_SPOT_TRANSFER
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_spot_transfer(void*_Nonnull ptr, RustBuffer token, double amount, RustBuffer destination, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_U64
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_U64
-void ffi_hyperliquid_swift_rust_future_cancel_u64(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_SPOT_TRANSFER_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_SPOT_TRANSFER_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_spot_transfer_async(void*_Nonnull ptr, RustBuffer token, double amount, RustBuffer destination
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_U64
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_U64
-void ffi_hyperliquid_swift_rust_future_free_u64(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_UPDATE_ISOLATED_MARGIN
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_UPDATE_ISOLATED_MARGIN
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_update_isolated_margin(void*_Nonnull ptr, RustBuffer asset, double amount, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_U64
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_U64
-uint64_t ffi_hyperliquid_swift_rust_future_complete_u64(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_UPDATE_ISOLATED_MARGIN_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_UPDATE_ISOLATED_MARGIN_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_update_isolated_margin_async(void*_Nonnull ptr, RustBuffer asset, double amount
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_I64
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_I64
-void ffi_hyperliquid_swift_rust_future_poll_i64(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_UPDATE_LEVERAGE
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_UPDATE_LEVERAGE
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_update_leverage(void*_Nonnull ptr, RustBuffer asset, uint32_t leverage, int8_t is_cross, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_I64
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_I64
-void ffi_hyperliquid_swift_rust_future_cancel_i64(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_UPDATE_LEVERAGE_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_UPDATE_LEVERAGE_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_update_leverage_async(void*_Nonnull ptr, RustBuffer asset, uint32_t leverage, int8_t is_cross
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_I64
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_I64
-void ffi_hyperliquid_swift_rust_future_free_i64(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_USD_TRANSFER
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_USD_TRANSFER
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_usd_transfer(void*_Nonnull ptr, double amount, RustBuffer destination, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_I64
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_I64
-int64_t ffi_hyperliquid_swift_rust_future_complete_i64(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_USD_TRANSFER_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_USD_TRANSFER_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_usd_transfer_async(void*_Nonnull ptr, double amount, RustBuffer destination
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_F32
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_F32
-void ffi_hyperliquid_swift_rust_future_poll_f32(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_VALIDATE_ORDER
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_VALIDATE_ORDER
+void uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_validate_order(void*_Nonnull ptr, RustBuffer order, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_F32
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_F32
-void ffi_hyperliquid_swift_rust_future_cancel_f32(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_VALIDATE_ORDER_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_VALIDATE_ORDER_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_validate_order_async(void*_Nonnull ptr, RustBuffer order
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_F32
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_F32
-void ffi_hyperliquid_swift_rust_future_free_f32(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_VERIFY_ACCOUNT_EXISTS
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_VERIFY_ACCOUNT_EXISTS
+int8_t uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_verify_account_exists(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_F32
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_F32
-float ffi_hyperliquid_swift_rust_future_complete_f32(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_VERIFY_ACCOUNT_EXISTS_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_VERIFY_ACCOUNT_EXISTS_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_verify_account_exists_async(void*_Nonnull ptr
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_F64
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_F64
-void ffi_hyperliquid_swift_rust_future_poll_f64(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_WAIT_FOR_ORDER
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_WAIT_FOR_ORDER
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_wait_for_order(void*_Nonnull ptr, RustBuffer response, uint64_t timeout_ms, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_F64
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_F64
-void ffi_hyperliquid_swift_rust_future_cancel_f64(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_WAIT_FOR_ORDER_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_WAIT_FOR_ORDER_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_wait_for_order_async(void*_Nonnull ptr, RustBuffer response, uint64_t timeout_ms
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_F64
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_F64
-void ffi_hyperliquid_swift_rust_future_free_f64(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_WITHDRAW_FROM_BRIDGE
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_WITHDRAW_FROM_BRIDGE
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_withdraw_from_bridge(void*_Nonnull ptr, double amount, RustBuffer destination, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_F64
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_F64
-double ffi_hyperliquid_swift_rust_future_complete_f64(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_WITHDRAW_FROM_BRIDGE_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDEXCHANGE_WITHDRAW_FROM_BRIDGE_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_withdraw_from_bridge_async(void*_Nonnull ptr, double amount, RustBuffer destination
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_POINTER
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_POINTER
-void ffi_hyperliquid_swift_rust_future_poll_pointer(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_CLONE_HYPERLIQUIDINFO
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_CLONE_HYPERLIQUIDINFO
+void*_Nonnull uniffi_hyperliquid_swift_fn_clone_hyperliquidinfo(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_POINTER
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_POINTER
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_FREE_HYPERLIQUIDINFO
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_FREE_HYPERLIQUIDINFO
+void uniffi_hyperliquid_swift_fn_free_hyperliquidinfo(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_CANCELLABLE
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_CANCELLABLE
+void*_Nonnull uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_cancellable(void*_Nonnull ptr, void*_Nonnull token, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_ALL_MIDS
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_ALL_MIDS
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_all_mids(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_ALL_MIDS_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_ALL_MIDS_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_all_mids_async(void*_Nonnull ptr
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_ALL_MIDS_F64
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_ALL_MIDS_F64
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_all_mids_f64(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_ALL_MIDS_F64_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_ALL_MIDS_F64_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_all_mids_f64_async(void*_Nonnull ptr
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_ASSET_CONTEXTS
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_ASSET_CONTEXTS
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_asset_contexts(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_ASSET_CONTEXTS_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_ASSET_CONTEXTS_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_asset_contexts_async(void*_Nonnull ptr
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_ASSET_NAMES
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_ASSET_NAMES
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_asset_names(void*_Nonnull ptr, int8_t include_spot, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_ASSET_NAMES_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_ASSET_NAMES_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_asset_names_async(void*_Nonnull ptr, int8_t include_spot
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_BASE_URL
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_BASE_URL
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_base_url(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_CANDLES
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_CANDLES
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_candles(void*_Nonnull ptr, RustBuffer asset, RustBuffer interval, uint64_t start_time, uint64_t end_time, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_CANDLES_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_CANDLES_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_candles_async(void*_Nonnull ptr, RustBuffer asset, RustBuffer interval, uint64_t start_time, uint64_t end_time
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_CLEARINGHOUSE_STATE
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_CLEARINGHOUSE_STATE
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_clearinghouse_state(void*_Nonnull ptr, RustBuffer address, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_CLEARINGHOUSE_STATE_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_CLEARINGHOUSE_STATE_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_clearinghouse_state_async(void*_Nonnull ptr, RustBuffer address
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_FRONTEND_OPEN_ORDERS
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_FRONTEND_OPEN_ORDERS
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_frontend_open_orders(void*_Nonnull ptr, RustBuffer address, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_FRONTEND_OPEN_ORDERS_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_FRONTEND_OPEN_ORDERS_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_frontend_open_orders_async(void*_Nonnull ptr, RustBuffer address
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_FUNDING_HISTORY
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_FUNDING_HISTORY
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_funding_history(void*_Nonnull ptr, RustBuffer asset, uint64_t start_time, RustBuffer end_time, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_FUNDING_HISTORY_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_FUNDING_HISTORY_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_funding_history_async(void*_Nonnull ptr, RustBuffer asset, uint64_t start_time, RustBuffer end_time
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_HISTORICAL_ORDERS
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_HISTORICAL_ORDERS
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_historical_orders(void*_Nonnull ptr, RustBuffer address, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_HISTORICAL_ORDERS_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_HISTORICAL_ORDERS_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_historical_orders_async(void*_Nonnull ptr, RustBuffer address
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_L2_BOOK
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_L2_BOOK
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_l2_book(void*_Nonnull ptr, RustBuffer asset, RustBuffer n_levels, RustBuffer mantissa, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_L2_BOOK_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_L2_BOOK_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_l2_book_async(void*_Nonnull ptr, RustBuffer asset, RustBuffer n_levels, RustBuffer mantissa
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_LATEST_CANDLES
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_LATEST_CANDLES
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_latest_candles(void*_Nonnull ptr, RustBuffer asset, RustBuffer interval, uint32_t count, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_LATEST_CANDLES_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_LATEST_CANDLES_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_latest_candles_async(void*_Nonnull ptr, RustBuffer asset, RustBuffer interval, uint32_t count
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_LEDGER_UPDATES
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_LEDGER_UPDATES
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_ledger_updates(void*_Nonnull ptr, RustBuffer address, uint64_t start_time, RustBuffer end_time, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_LEDGER_UPDATES_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_LEDGER_UPDATES_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_ledger_updates_async(void*_Nonnull ptr, RustBuffer address, uint64_t start_time, RustBuffer end_time
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_MAX_BUILDER_FEE
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_MAX_BUILDER_FEE
+uint32_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_max_builder_fee(void*_Nonnull ptr, RustBuffer address, RustBuffer builder, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_MAX_BUILDER_FEE_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_MAX_BUILDER_FEE_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_max_builder_fee_async(void*_Nonnull ptr, RustBuffer address, RustBuffer builder
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_META
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_META
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_meta(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_META_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_META_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_meta_async(void*_Nonnull ptr
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_META_WITH_CONTEXTS
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_META_WITH_CONTEXTS
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_meta_with_contexts(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_META_WITH_CONTEXTS_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_META_WITH_CONTEXTS_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_meta_with_contexts_async(void*_Nonnull ptr
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_MID
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_MID
+double uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_mid(void*_Nonnull ptr, RustBuffer asset, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_MID_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_MID_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_mid_async(void*_Nonnull ptr, RustBuffer asset
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_OPEN_ORDERS
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_OPEN_ORDERS
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_open_orders(void*_Nonnull ptr, RustBuffer address, RustBuffer asset, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_OPEN_ORDERS_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_OPEN_ORDERS_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_open_orders_async(void*_Nonnull ptr, RustBuffer address, RustBuffer asset
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_ORDER_STATUS
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_ORDER_STATUS
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_order_status(void*_Nonnull ptr, RustBuffer address, uint64_t oid, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_ORDER_STATUS_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_ORDER_STATUS_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_order_status_async(void*_Nonnull ptr, RustBuffer address, uint64_t oid
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_PERP_DEPLOY_AUCTION
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_PERP_DEPLOY_AUCTION
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_perp_deploy_auction(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_PERP_DEPLOY_AUCTION_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_PERP_DEPLOY_AUCTION_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_perp_deploy_auction_async(void*_Nonnull ptr
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_PERP_STATE
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_PERP_STATE
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_perp_state(void*_Nonnull ptr, RustBuffer address, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_PERP_STATE_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_PERP_STATE_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_perp_state_async(void*_Nonnull ptr, RustBuffer address
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_PREDICTED_FUNDINGS
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_PREDICTED_FUNDINGS
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_predicted_fundings(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_PREDICTED_FUNDINGS_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_PREDICTED_FUNDINGS_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_predicted_fundings_async(void*_Nonnull ptr
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_RATE_LIMIT
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_RATE_LIMIT
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_rate_limit(void*_Nonnull ptr, RustBuffer address, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_RATE_LIMIT_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_RATE_LIMIT_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_rate_limit_async(void*_Nonnull ptr, RustBuffer address
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_REFERRAL_STATE
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_REFERRAL_STATE
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_referral_state(void*_Nonnull ptr, RustBuffer address, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_REFERRAL_STATE_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_REFERRAL_STATE_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_referral_state_async(void*_Nonnull ptr, RustBuffer address
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_SERVER_TIME
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_SERVER_TIME
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_server_time(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_SERVER_TIME_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_SERVER_TIME_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_server_time_async(void*_Nonnull ptr
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_SPOT_BALANCES
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_SPOT_BALANCES
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_spot_balances(void*_Nonnull ptr, RustBuffer address, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_SPOT_BALANCES_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_SPOT_BALANCES_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_spot_balances_async(void*_Nonnull ptr, RustBuffer address
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_SPOT_META
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_SPOT_META
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_spot_meta(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_SPOT_META_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_SPOT_META_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_spot_meta_async(void*_Nonnull ptr
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_SPOT_STATE
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_SPOT_STATE
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_spot_state(void*_Nonnull ptr, RustBuffer address, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_SPOT_STATE_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_SPOT_STATE_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_spot_state_async(void*_Nonnull ptr, RustBuffer address
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_SUB_ACCOUNTS
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_SUB_ACCOUNTS
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_sub_accounts(void*_Nonnull ptr, RustBuffer master_address, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_SUB_ACCOUNTS_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_SUB_ACCOUNTS_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_sub_accounts_async(void*_Nonnull ptr, RustBuffer master_address
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_USER_BALANCES
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_USER_BALANCES
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_user_balances(void*_Nonnull ptr, RustBuffer address, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_USER_BALANCES_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_USER_BALANCES_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_user_balances_async(void*_Nonnull ptr, RustBuffer address
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_USER_FILLS
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_USER_FILLS
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_user_fills(void*_Nonnull ptr, RustBuffer address, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_USER_FILLS_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_USER_FILLS_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_user_fills_async(void*_Nonnull ptr, RustBuffer address
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_USER_FILLS_BY_TIME
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_USER_FILLS_BY_TIME
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_user_fills_by_time(void*_Nonnull ptr, RustBuffer address, uint64_t start_time, RustBuffer end_time, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_USER_FILLS_BY_TIME_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_USER_FILLS_BY_TIME_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_user_fills_by_time_async(void*_Nonnull ptr, RustBuffer address, uint64_t start_time, RustBuffer end_time
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_USER_FUNDING
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_USER_FUNDING
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_user_funding(void*_Nonnull ptr, RustBuffer address, uint64_t start_time, RustBuffer end_time, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_USER_FUNDING_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_USER_FUNDING_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_user_funding_async(void*_Nonnull ptr, RustBuffer address, uint64_t start_time, RustBuffer end_time
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_USER_OVERVIEW
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_USER_OVERVIEW
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_user_overview(void*_Nonnull ptr, RustBuffer address, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_USER_OVERVIEW_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_USER_OVERVIEW_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_user_overview_async(void*_Nonnull ptr, RustBuffer address
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_USER_STATE
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_USER_STATE
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_user_state(void*_Nonnull ptr, RustBuffer address, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_USER_STATE_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_USER_STATE_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_user_state_async(void*_Nonnull ptr, RustBuffer address
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_USER_STATES
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_USER_STATES
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_user_states(void*_Nonnull ptr, RustBuffer addresses, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_USER_STATES_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_GET_USER_STATES_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_user_states_async(void*_Nonnull ptr, RustBuffer addresses
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_INFO_RAW
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_INFO_RAW
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_info_raw(void*_Nonnull ptr, RustBuffer request_json, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_INFO_RAW_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_INFO_RAW_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_info_raw_async(void*_Nonnull ptr, RustBuffer request_json
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_PING
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_PING
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_ping(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_PING_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_PING_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_ping_async(void*_Nonnull ptr
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_REFRESH_META
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_REFRESH_META
+void uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_refresh_meta(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_REFRESH_META_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_REFRESH_META_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_refresh_meta_async(void*_Nonnull ptr
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_ROUND_PRICE
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_ROUND_PRICE
+double uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_round_price(void*_Nonnull ptr, RustBuffer asset, double price, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_ROUND_PRICE_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_ROUND_PRICE_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_round_price_async(void*_Nonnull ptr, RustBuffer asset, double price
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_ROUND_SIZE
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_ROUND_SIZE
+double uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_round_size(void*_Nonnull ptr, RustBuffer asset, double size, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_ROUND_SIZE_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_ROUND_SIZE_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_round_size_async(void*_Nonnull ptr, RustBuffer asset, double size
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_SET_CONNECTION_STATUS_CALLBACK
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_SET_CONNECTION_STATUS_CALLBACK
+void uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_set_connection_status_callback(void*_Nonnull ptr, uint64_t callback, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_SHUTDOWN
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_SHUTDOWN
+void uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_shutdown(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_SIMULATE_POSITION
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_SIMULATE_POSITION
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_simulate_position(void*_Nonnull ptr, RustBuffer asset, RustBuffer side, double size, double entry_price, uint32_t leverage, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_SIMULATE_POSITION_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_SIMULATE_POSITION_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_simulate_position_async(void*_Nonnull ptr, RustBuffer asset, RustBuffer side, double size, double entry_price, uint32_t leverage
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_SUBMIT_SIGNED
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_SUBMIT_SIGNED
+RustBuffer uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_submit_signed(void*_Nonnull ptr, RustBuffer payload, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_SUBMIT_SIGNED_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_SUBMIT_SIGNED_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_submit_signed_async(void*_Nonnull ptr, RustBuffer payload
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_SUBSCRIBE_ALL_MIDS
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_SUBSCRIBE_ALL_MIDS
+uint32_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_subscribe_all_mids(void*_Nonnull ptr, uint64_t callback, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_SUBSCRIBE_L2_BOOK
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_SUBSCRIBE_L2_BOOK
+uint32_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_subscribe_l2_book(void*_Nonnull ptr, RustBuffer asset, uint64_t callback, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_SUBSCRIBE_USER_EVENTS
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_SUBSCRIBE_USER_EVENTS
+uint32_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_subscribe_user_events(void*_Nonnull ptr, RustBuffer address, uint64_t callback, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_UNSUBSCRIBE
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_UNSUBSCRIBE
+void uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_unsubscribe(void*_Nonnull ptr, uint32_t subscription_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_VERIFY_ACCOUNT_EXISTS
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_VERIFY_ACCOUNT_EXISTS
+int8_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_verify_account_exists(void*_Nonnull ptr, RustBuffer address, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_VERIFY_ACCOUNT_EXISTS_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_HYPERLIQUIDINFO_VERIFY_ACCOUNT_EXISTS_ASYNC
+uint64_t uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_verify_account_exists_async(void*_Nonnull ptr, RustBuffer address
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_CLONE_ORDERREQUESTBUILDER
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_CLONE_ORDERREQUESTBUILDER
+void*_Nonnull uniffi_hyperliquid_swift_fn_clone_orderrequestbuilder(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_FREE_ORDERREQUESTBUILDER
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_FREE_ORDERREQUESTBUILDER
+void uniffi_hyperliquid_swift_fn_free_orderrequestbuilder(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_CONSTRUCTOR_ORDERREQUESTBUILDER_NEW
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_CONSTRUCTOR_ORDERREQUESTBUILDER_NEW
+void*_Nonnull uniffi_hyperliquid_swift_fn_constructor_orderrequestbuilder_new(RustBuffer asset, RustBuffer side, double size, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_ORDERREQUESTBUILDER_BUILD
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_ORDERREQUESTBUILDER_BUILD
+RustBuffer uniffi_hyperliquid_swift_fn_method_orderrequestbuilder_build(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_ORDERREQUESTBUILDER_CLOID
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_ORDERREQUESTBUILDER_CLOID
+void*_Nonnull uniffi_hyperliquid_swift_fn_method_orderrequestbuilder_cloid(void*_Nonnull ptr, RustBuffer cloid, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_ORDERREQUESTBUILDER_LIMIT_PRICE
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_ORDERREQUESTBUILDER_LIMIT_PRICE
+void*_Nonnull uniffi_hyperliquid_swift_fn_method_orderrequestbuilder_limit_price(void*_Nonnull ptr, double price, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_ORDERREQUESTBUILDER_MARKET
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_ORDERREQUESTBUILDER_MARKET
+void*_Nonnull uniffi_hyperliquid_swift_fn_method_orderrequestbuilder_market(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_ORDERREQUESTBUILDER_REDUCE_ONLY
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_ORDERREQUESTBUILDER_REDUCE_ONLY
+void*_Nonnull uniffi_hyperliquid_swift_fn_method_orderrequestbuilder_reduce_only(void*_Nonnull ptr, int8_t reduce_only, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_ORDERREQUESTBUILDER_SLIPPAGE
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_ORDERREQUESTBUILDER_SLIPPAGE
+void*_Nonnull uniffi_hyperliquid_swift_fn_method_orderrequestbuilder_slippage(void*_Nonnull ptr, double slippage, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_ORDERREQUESTBUILDER_TIF
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_ORDERREQUESTBUILDER_TIF
+void*_Nonnull uniffi_hyperliquid_swift_fn_method_orderrequestbuilder_tif(void*_Nonnull ptr, RustBuffer tif, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_ORDERREQUESTBUILDER_TRIGGER
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_METHOD_ORDERREQUESTBUILDER_TRIGGER
+void*_Nonnull uniffi_hyperliquid_swift_fn_method_orderrequestbuilder_trigger(void*_Nonnull ptr, double trigger_price, int8_t is_market, RustBuffer tpsl, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_INIT_CALLBACK_VTABLE_CLOCKDRIFTCALLBACK
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_INIT_CALLBACK_VTABLE_CLOCKDRIFTCALLBACK
+void uniffi_hyperliquid_swift_fn_init_callback_vtable_clockdriftcallback(UniffiVTableCallbackInterfaceClockDriftCallback* _Nonnull vtable
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_INIT_CALLBACK_VTABLE_CONNECTIONSTATUSCALLBACK
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_INIT_CALLBACK_VTABLE_CONNECTIONSTATUSCALLBACK
+void uniffi_hyperliquid_swift_fn_init_callback_vtable_connectionstatuscallback(UniffiVTableCallbackInterfaceConnectionStatusCallback* _Nonnull vtable
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_INIT_CALLBACK_VTABLE_L2BOOKCALLBACK
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_INIT_CALLBACK_VTABLE_L2BOOKCALLBACK
+void uniffi_hyperliquid_swift_fn_init_callback_vtable_l2bookcallback(UniffiVTableCallbackInterfaceL2BookCallback* _Nonnull vtable
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_INIT_CALLBACK_VTABLE_LOGCALLBACK
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_INIT_CALLBACK_VTABLE_LOGCALLBACK
+void uniffi_hyperliquid_swift_fn_init_callback_vtable_logcallback(UniffiVTableCallbackInterfaceLogCallback* _Nonnull vtable
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_INIT_CALLBACK_VTABLE_MIDSCALLBACK
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_INIT_CALLBACK_VTABLE_MIDSCALLBACK
+void uniffi_hyperliquid_swift_fn_init_callback_vtable_midscallback(UniffiVTableCallbackInterfaceMidsCallback* _Nonnull vtable
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_INIT_CALLBACK_VTABLE_USEREVENTCALLBACK
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_INIT_CALLBACK_VTABLE_USEREVENTCALLBACK
+void uniffi_hyperliquid_swift_fn_init_callback_vtable_usereventcallback(UniffiVTableCallbackInterfaceUserEventCallback* _Nonnull vtable
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_FUNC_CREATE_EXCHANGE_CLIENT
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_FUNC_CREATE_EXCHANGE_CLIENT
+void*_Nonnull uniffi_hyperliquid_swift_fn_func_create_exchange_client(RustBuffer private_key, RustBuffer network, RustBuffer config, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_FUNC_CREATE_EXCHANGE_CLIENT_FROM_MNEMONIC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_FUNC_CREATE_EXCHANGE_CLIENT_FROM_MNEMONIC
+void*_Nonnull uniffi_hyperliquid_swift_fn_func_create_exchange_client_from_mnemonic(RustBuffer phrase, uint32_t derivation_index, RustBuffer network, RustBuffer config, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_FUNC_CREATE_INFO_CLIENT
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_FUNC_CREATE_INFO_CLIENT
+void*_Nonnull uniffi_hyperliquid_swift_fn_func_create_info_client(RustBuffer base_url, RustBuffer config, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_FUNC_SET_LOG_CALLBACK
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_FN_FUNC_SET_LOG_CALLBACK
+void uniffi_hyperliquid_swift_fn_func_set_log_callback(uint64_t callback, RustBuffer max_level, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUSTBUFFER_ALLOC
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUSTBUFFER_ALLOC
+RustBuffer ffi_hyperliquid_swift_rustbuffer_alloc(uint64_t size, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUSTBUFFER_FROM_BYTES
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUSTBUFFER_FROM_BYTES
+RustBuffer ffi_hyperliquid_swift_rustbuffer_from_bytes(ForeignBytes bytes, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUSTBUFFER_FREE
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUSTBUFFER_FREE
+void ffi_hyperliquid_swift_rustbuffer_free(RustBuffer buf, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUSTBUFFER_RESERVE
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUSTBUFFER_RESERVE
+RustBuffer ffi_hyperliquid_swift_rustbuffer_reserve(RustBuffer buf, uint64_t additional, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_U8
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_U8
+void ffi_hyperliquid_swift_rust_future_poll_u8(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_U8
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_U8
+void ffi_hyperliquid_swift_rust_future_cancel_u8(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_U8
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_U8
+void ffi_hyperliquid_swift_rust_future_free_u8(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_U8
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_U8
+uint8_t ffi_hyperliquid_swift_rust_future_complete_u8(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_I8
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_I8
+void ffi_hyperliquid_swift_rust_future_poll_i8(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_I8
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_I8
+void ffi_hyperliquid_swift_rust_future_cancel_i8(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_I8
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_I8
+void ffi_hyperliquid_swift_rust_future_free_i8(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_I8
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_I8
+int8_t ffi_hyperliquid_swift_rust_future_complete_i8(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_U16
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_U16
+void ffi_hyperliquid_swift_rust_future_poll_u16(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_U16
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_U16
+void ffi_hyperliquid_swift_rust_future_cancel_u16(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_U16
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_U16
+void ffi_hyperliquid_swift_rust_future_free_u16(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_U16
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_U16
+uint16_t ffi_hyperliquid_swift_rust_future_complete_u16(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_I16
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_I16
+void ffi_hyperliquid_swift_rust_future_poll_i16(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_I16
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_I16
+void ffi_hyperliquid_swift_rust_future_cancel_i16(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_I16
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_I16
+void ffi_hyperliquid_swift_rust_future_free_i16(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_I16
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_I16
+int16_t ffi_hyperliquid_swift_rust_future_complete_i16(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_U32
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_U32
+void ffi_hyperliquid_swift_rust_future_poll_u32(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_U32
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_U32
+void ffi_hyperliquid_swift_rust_future_cancel_u32(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_U32
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_U32
+void ffi_hyperliquid_swift_rust_future_free_u32(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_U32
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_U32
+uint32_t ffi_hyperliquid_swift_rust_future_complete_u32(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_I32
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_I32
+void ffi_hyperliquid_swift_rust_future_poll_i32(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_I32
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_I32
+void ffi_hyperliquid_swift_rust_future_cancel_i32(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_I32
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_I32
+void ffi_hyperliquid_swift_rust_future_free_i32(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_I32
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_I32
+int32_t ffi_hyperliquid_swift_rust_future_complete_i32(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_U64
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_U64
+void ffi_hyperliquid_swift_rust_future_poll_u64(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_U64
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_U64
+void ffi_hyperliquid_swift_rust_future_cancel_u64(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_U64
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_U64
+void ffi_hyperliquid_swift_rust_future_free_u64(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_U64
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_U64
+uint64_t ffi_hyperliquid_swift_rust_future_complete_u64(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_I64
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_I64
+void ffi_hyperliquid_swift_rust_future_poll_i64(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_I64
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_I64
+void ffi_hyperliquid_swift_rust_future_cancel_i64(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_I64
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_I64
+void ffi_hyperliquid_swift_rust_future_free_i64(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_I64
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_I64
+int64_t ffi_hyperliquid_swift_rust_future_complete_i64(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_F32
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_F32
+void ffi_hyperliquid_swift_rust_future_poll_f32(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_F32
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_F32
+void ffi_hyperliquid_swift_rust_future_cancel_f32(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_F32
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_F32
+void ffi_hyperliquid_swift_rust_future_free_f32(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_F32
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_F32
+float ffi_hyperliquid_swift_rust_future_complete_f32(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_F64
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_F64
+void ffi_hyperliquid_swift_rust_future_poll_f64(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_F64
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_F64
+void ffi_hyperliquid_swift_rust_future_cancel_f64(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_F64
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_F64
+void ffi_hyperliquid_swift_rust_future_free_f64(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_F64
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_F64
+double ffi_hyperliquid_swift_rust_future_complete_f64(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_POINTER
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_POINTER
+void ffi_hyperliquid_swift_rust_future_poll_pointer(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_POINTER
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_POINTER
 void ffi_hyperliquid_swift_rust_future_cancel_pointer(uint64_t handle
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_POINTER
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_POINTER
-void ffi_hyperliquid_swift_rust_future_free_pointer(uint64_t handle
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_POINTER
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_POINTER
+void ffi_hyperliquid_swift_rust_future_free_pointer(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_POINTER
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_POINTER
+void*_Nonnull ffi_hyperliquid_swift_rust_future_complete_pointer(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_RUST_BUFFER
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_RUST_BUFFER
+void ffi_hyperliquid_swift_rust_future_poll_rust_buffer(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_RUST_BUFFER
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_RUST_BUFFER
+void ffi_hyperliquid_swift_rust_future_cancel_rust_buffer(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_RUST_BUFFER
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_RUST_BUFFER
+void ffi_hyperliquid_swift_rust_future_free_rust_buffer(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_RUST_BUFFER
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_RUST_BUFFER
+RustBuffer ffi_hyperliquid_swift_rust_future_complete_rust_buffer(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_VOID
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_VOID
+void ffi_hyperliquid_swift_rust_future_poll_void(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_VOID
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_VOID
+void ffi_hyperliquid_swift_rust_future_cancel_void(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_VOID
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_VOID
+void ffi_hyperliquid_swift_rust_future_free_void(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_VOID
+#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_VOID
+void ffi_hyperliquid_swift_rust_future_complete_void(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_FUNC_CREATE_EXCHANGE_CLIENT
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_FUNC_CREATE_EXCHANGE_CLIENT
+uint16_t uniffi_hyperliquid_swift_checksum_func_create_exchange_client(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_FUNC_CREATE_EXCHANGE_CLIENT_FROM_MNEMONIC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_FUNC_CREATE_EXCHANGE_CLIENT_FROM_MNEMONIC
+uint16_t uniffi_hyperliquid_swift_checksum_func_create_exchange_client_from_mnemonic(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_FUNC_CREATE_INFO_CLIENT
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_FUNC_CREATE_INFO_CLIENT
+uint16_t uniffi_hyperliquid_swift_checksum_func_create_info_client(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_FUNC_SET_LOG_CALLBACK
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_FUNC_SET_LOG_CALLBACK
+uint16_t uniffi_hyperliquid_swift_checksum_func_set_log_callback(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_CANCELLATIONTOKEN_CANCEL
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_CANCELLATIONTOKEN_CANCEL
+uint16_t uniffi_hyperliquid_swift_checksum_method_cancellationtoken_cancel(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_CANCELLATIONTOKEN_IS_CANCELLED
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_CANCELLATIONTOKEN_IS_CANCELLED
+uint16_t uniffi_hyperliquid_swift_checksum_method_cancellationtoken_is_cancelled(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_APPROVE_AGENT
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_APPROVE_AGENT
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_approve_agent(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_APPROVE_AGENT_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_APPROVE_AGENT_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_approve_agent_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_APPROVE_BUILDER_FEE
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_APPROVE_BUILDER_FEE
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_approve_builder_fee(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_APPROVE_BUILDER_FEE_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_APPROVE_BUILDER_FEE_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_approve_builder_fee_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_ASSET_TO_INDEX
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_ASSET_TO_INDEX
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_asset_to_index(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_BULK_MODIFY
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_BULK_MODIFY
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_bulk_modify(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_BULK_MODIFY_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_BULK_MODIFY_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_bulk_modify_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_ALL_ORDERS
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_ALL_ORDERS
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_cancel_all_orders(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_ALL_ORDERS_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_ALL_ORDERS_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_cancel_all_orders_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_BY_CLOID
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_BY_CLOID
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_cancel_by_cloid(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_BY_CLOID_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_BY_CLOID_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_cancel_by_cloid_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_ORDER
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_ORDER
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_cancel_order(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_ORDER_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_ORDER_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_cancel_order_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_ORDERS
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_ORDERS
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_cancel_orders(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_ORDERS_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_ORDERS_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_cancel_orders_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_REPLACE
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_REPLACE
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_cancel_replace(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_REPLACE_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_REPLACE_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_cancel_replace_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_TWAP
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_TWAP
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_cancel_twap(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_TWAP_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_TWAP_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_cancel_twap_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_CANCELLABLE
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_CANCELLABLE
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_cancellable(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_CLASS_TRANSFER
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_CLASS_TRANSFER
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_class_transfer(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_CLASS_TRANSFER_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_CLASS_TRANSFER_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_class_transfer_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_CLOSE_POSITION
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_CLOSE_POSITION
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_close_position(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_CLOSE_POSITION_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_CLOSE_POSITION_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_close_position_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_GET_BASE_URL
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_GET_BASE_URL
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_get_base_url(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_GET_SERVER_TIME
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_GET_SERVER_TIME
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_get_server_time(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_GET_SERVER_TIME_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_GET_SERVER_TIME_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_get_server_time_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_GET_WALLET_ADDRESS
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_GET_WALLET_ADDRESS
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_get_wallet_address(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_INDEX_TO_ASSET
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_INDEX_TO_ASSET
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_index_to_asset(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_MODIFY_ORDER
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_MODIFY_ORDER
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_modify_order(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_MODIFY_ORDER_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_MODIFY_ORDER_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_modify_order_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_place_order(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_place_order_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER_OID
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER_OID
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_place_order_oid(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER_OID_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER_OID_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_place_order_oid_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER_RELATIVE
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER_RELATIVE
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_place_order_relative(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER_RELATIVE_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER_RELATIVE_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_place_order_relative_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER_WITH_MARGIN
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER_WITH_MARGIN
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_place_order_with_margin(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER_WITH_MARGIN_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER_WITH_MARGIN_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_place_order_with_margin_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER_WITH_TPSL
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER_WITH_TPSL
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_place_order_with_tpsl(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER_WITH_TPSL_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER_WITH_TPSL_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_place_order_with_tpsl_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDERS_BULK
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDERS_BULK
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_place_orders_bulk(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDERS_BULK_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDERS_BULK_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_place_orders_bulk_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_PLACE_TWAP
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_PLACE_TWAP
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_place_twap(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_PLACE_TWAP_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_PLACE_TWAP_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_place_twap_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_REFRESH_META
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_REFRESH_META
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_refresh_meta(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_REFRESH_META_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_REFRESH_META_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_refresh_meta_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_SCHEDULE_CANCEL
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_SCHEDULE_CANCEL
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_schedule_cancel(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_SCHEDULE_CANCEL_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_SCHEDULE_CANCEL_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_schedule_cancel_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_SET_CLOCK_DRIFT_CALLBACK
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_SET_CLOCK_DRIFT_CALLBACK
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_set_clock_drift_callback(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_SET_DEFAULT_SLIPPAGE
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_SET_DEFAULT_SLIPPAGE
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_set_default_slippage(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_SET_REFERRER
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_SET_REFERRER
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_set_referrer(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_SET_REFERRER_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_SET_REFERRER_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_set_referrer_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_SIGN_MESSAGE
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_SIGN_MESSAGE
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_sign_message(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_SIGN_ORDER
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_SIGN_ORDER
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_sign_order(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_SIGN_ORDER_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_SIGN_ORDER_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_sign_order_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_SPOT_TRANSFER
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_SPOT_TRANSFER
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_spot_transfer(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_SPOT_TRANSFER_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_SPOT_TRANSFER_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_spot_transfer_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_UPDATE_ISOLATED_MARGIN
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_UPDATE_ISOLATED_MARGIN
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_update_isolated_margin(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_UPDATE_ISOLATED_MARGIN_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_UPDATE_ISOLATED_MARGIN_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_update_isolated_margin_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_UPDATE_LEVERAGE
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_UPDATE_LEVERAGE
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_update_leverage(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_UPDATE_LEVERAGE_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_UPDATE_LEVERAGE_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_update_leverage_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_USD_TRANSFER
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_USD_TRANSFER
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_usd_transfer(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_USD_TRANSFER_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_USD_TRANSFER_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_usd_transfer_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_VALIDATE_ORDER
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_VALIDATE_ORDER
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_validate_order(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_VALIDATE_ORDER_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_VALIDATE_ORDER_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_validate_order_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_VERIFY_ACCOUNT_EXISTS
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_VERIFY_ACCOUNT_EXISTS
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_verify_account_exists(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_VERIFY_ACCOUNT_EXISTS_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_VERIFY_ACCOUNT_EXISTS_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_verify_account_exists_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_WAIT_FOR_ORDER
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_WAIT_FOR_ORDER
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_wait_for_order(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_WAIT_FOR_ORDER_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_WAIT_FOR_ORDER_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_wait_for_order_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_WITHDRAW_FROM_BRIDGE
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_WITHDRAW_FROM_BRIDGE
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_withdraw_from_bridge(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_WITHDRAW_FROM_BRIDGE_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_WITHDRAW_FROM_BRIDGE_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_withdraw_from_bridge_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_CANCELLABLE
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_CANCELLABLE
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_cancellable(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_ALL_MIDS
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_ALL_MIDS
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_all_mids(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_ALL_MIDS_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_ALL_MIDS_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_all_mids_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_ALL_MIDS_F64
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_ALL_MIDS_F64
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_all_mids_f64(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_ALL_MIDS_F64_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_ALL_MIDS_F64_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_all_mids_f64_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_ASSET_CONTEXTS
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_ASSET_CONTEXTS
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_asset_contexts(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_ASSET_CONTEXTS_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_ASSET_CONTEXTS_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_asset_contexts_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_ASSET_NAMES
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_ASSET_NAMES
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_asset_names(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_ASSET_NAMES_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_ASSET_NAMES_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_asset_names_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_BASE_URL
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_BASE_URL
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_base_url(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_CANDLES
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_CANDLES
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_candles(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_CANDLES_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_CANDLES_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_candles_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_CLEARINGHOUSE_STATE
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_CLEARINGHOUSE_STATE
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_clearinghouse_state(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_CLEARINGHOUSE_STATE_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_CLEARINGHOUSE_STATE_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_clearinghouse_state_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_FRONTEND_OPEN_ORDERS
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_FRONTEND_OPEN_ORDERS
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_frontend_open_orders(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_FRONTEND_OPEN_ORDERS_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_FRONTEND_OPEN_ORDERS_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_frontend_open_orders_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_FUNDING_HISTORY
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_FUNDING_HISTORY
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_funding_history(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_FUNDING_HISTORY_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_FUNDING_HISTORY_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_funding_history_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_HISTORICAL_ORDERS
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_HISTORICAL_ORDERS
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_historical_orders(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_HISTORICAL_ORDERS_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_HISTORICAL_ORDERS_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_historical_orders_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_L2_BOOK
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_L2_BOOK
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_l2_book(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_L2_BOOK_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_L2_BOOK_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_l2_book_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_LATEST_CANDLES
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_LATEST_CANDLES
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_latest_candles(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_LATEST_CANDLES_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_LATEST_CANDLES_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_latest_candles_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_LEDGER_UPDATES
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_LEDGER_UPDATES
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_ledger_updates(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_LEDGER_UPDATES_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_LEDGER_UPDATES_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_ledger_updates_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_MAX_BUILDER_FEE
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_MAX_BUILDER_FEE
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_max_builder_fee(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_MAX_BUILDER_FEE_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_MAX_BUILDER_FEE_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_max_builder_fee_async(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_POINTER
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_POINTER
-void*_Nonnull ffi_hyperliquid_swift_rust_future_complete_pointer(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_META
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_META
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_meta(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_RUST_BUFFER
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_RUST_BUFFER
-void ffi_hyperliquid_swift_rust_future_poll_rust_buffer(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_META_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_META_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_meta_async(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_RUST_BUFFER
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_RUST_BUFFER
-void ffi_hyperliquid_swift_rust_future_cancel_rust_buffer(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_META_WITH_CONTEXTS
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_META_WITH_CONTEXTS
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_meta_with_contexts(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_RUST_BUFFER
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_RUST_BUFFER
-void ffi_hyperliquid_swift_rust_future_free_rust_buffer(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_META_WITH_CONTEXTS_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_META_WITH_CONTEXTS_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_meta_with_contexts_async(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_RUST_BUFFER
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_RUST_BUFFER
-RustBuffer ffi_hyperliquid_swift_rust_future_complete_rust_buffer(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_MID
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_MID
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_mid(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_VOID
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_POLL_VOID
-void ffi_hyperliquid_swift_rust_future_poll_void(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_MID_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_MID_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_mid_async(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_VOID
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_CANCEL_VOID
-void ffi_hyperliquid_swift_rust_future_cancel_void(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_OPEN_ORDERS
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_OPEN_ORDERS
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_open_orders(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_VOID
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_FREE_VOID
-void ffi_hyperliquid_swift_rust_future_free_void(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_OPEN_ORDERS_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_OPEN_ORDERS_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_open_orders_async(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_VOID
-#define UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_RUST_FUTURE_COMPLETE_VOID
-void ffi_hyperliquid_swift_rust_future_complete_void(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_ORDER_STATUS
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_ORDER_STATUS
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_order_status(void
+    
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_FUNC_CREATE_EXCHANGE_CLIENT
-#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_FUNC_CREATE_EXCHANGE_CLIENT
-uint16_t uniffi_hyperliquid_swift_checksum_func_create_exchange_client(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_ORDER_STATUS_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_ORDER_STATUS_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_order_status_async(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_FUNC_CREATE_INFO_CLIENT
-#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_FUNC_CREATE_INFO_CLIENT
-uint16_t uniffi_hyperliquid_swift_checksum_func_create_info_client(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_PERP_DEPLOY_AUCTION
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_PERP_DEPLOY_AUCTION
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_perp_deploy_auction(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_ALL_ORDERS
-#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_ALL_ORDERS
-uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_cancel_all_orders(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_PERP_DEPLOY_AUCTION_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_PERP_DEPLOY_AUCTION_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_perp_deploy_auction_async(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_ALL_ORDERS_ASYNC
-#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_ALL_ORDERS_ASYNC
-uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_cancel_all_orders_async(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_PERP_STATE
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_PERP_STATE
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_perp_state(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_ORDER
-#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_ORDER
-uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_cancel_order(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_PERP_STATE_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_PERP_STATE_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_perp_state_async(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_ORDER_ASYNC
-#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_CANCEL_ORDER_ASYNC
-uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_cancel_order_async(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_PREDICTED_FUNDINGS
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_PREDICTED_FUNDINGS
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_predicted_fundings(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_GET_WALLET_ADDRESS
-#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_GET_WALLET_ADDRESS
-uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_get_wallet_address(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_PREDICTED_FUNDINGS_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_PREDICTED_FUNDINGS_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_predicted_fundings_async(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER
-#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER
-uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_place_order(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_RATE_LIMIT
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_RATE_LIMIT
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_rate_limit(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER_ASYNC
-#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDEXCHANGE_PLACE_ORDER_ASYNC
-uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_place_order_async(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_RATE_LIMIT_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_RATE_LIMIT_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_rate_limit_async(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_ALL_MIDS
-#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_ALL_MIDS
-uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_all_mids(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_REFERRAL_STATE
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_REFERRAL_STATE
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_referral_state(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_ALL_MIDS_ASYNC
-#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_ALL_MIDS_ASYNC
-uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_all_mids_async(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_REFERRAL_STATE_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_REFERRAL_STATE_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_referral_state_async(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_OPEN_ORDERS
-#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_OPEN_ORDERS
-uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_open_orders(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_SERVER_TIME
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_SERVER_TIME
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_server_time(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_OPEN_ORDERS_ASYNC
-#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_OPEN_ORDERS_ASYNC
-uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_open_orders_async(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_SERVER_TIME_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_SERVER_TIME_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_server_time_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_SPOT_BALANCES
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_SPOT_BALANCES
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_spot_balances(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_SPOT_BALANCES_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_SPOT_BALANCES_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_spot_balances_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_SPOT_META
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_SPOT_META
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_spot_meta(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_SPOT_META_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_SPOT_META_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_spot_meta_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_SPOT_STATE
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_SPOT_STATE
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_spot_state(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_SPOT_STATE_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_SPOT_STATE_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_spot_state_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_SUB_ACCOUNTS
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_SUB_ACCOUNTS
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_sub_accounts(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_SUB_ACCOUNTS_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_SUB_ACCOUNTS_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_sub_accounts_async(void
     
 );
 #endif
@@ -724,6 +2444,54 @@ uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_user_balan
 #define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_USER_BALANCES_ASYNC
 uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_user_balances_async(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_USER_FILLS
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_USER_FILLS
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_user_fills(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_USER_FILLS_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_USER_FILLS_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_user_fills_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_USER_FILLS_BY_TIME
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_USER_FILLS_BY_TIME
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_user_fills_by_time(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_USER_FILLS_BY_TIME_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_USER_FILLS_BY_TIME_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_user_fills_by_time_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_USER_FUNDING
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_USER_FUNDING
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_user_funding(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_USER_FUNDING_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_USER_FUNDING_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_user_funding_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_USER_OVERVIEW
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_USER_OVERVIEW
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_user_overview(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_USER_OVERVIEW_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_USER_OVERVIEW_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_user_overview_async(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_USER_STATE
@@ -736,6 +2504,252 @@ uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_user_state
 #define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_USER_STATE_ASYNC
 uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_user_state_async(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_USER_STATES
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_USER_STATES
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_user_states(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_USER_STATES_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_GET_USER_STATES_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_user_states_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_INFO_RAW
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_INFO_RAW
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_info_raw(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_INFO_RAW_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_INFO_RAW_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_info_raw_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_PING
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_PING
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_ping(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_PING_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_PING_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_ping_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_REFRESH_META
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_REFRESH_META
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_refresh_meta(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_REFRESH_META_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_REFRESH_META_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_refresh_meta_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_ROUND_PRICE
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_ROUND_PRICE
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_round_price(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_ROUND_PRICE_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_ROUND_PRICE_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_round_price_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_ROUND_SIZE
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_ROUND_SIZE
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_round_size(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_ROUND_SIZE_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_ROUND_SIZE_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_round_size_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_SET_CONNECTION_STATUS_CALLBACK
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_SET_CONNECTION_STATUS_CALLBACK
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_set_connection_status_callback(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_SHUTDOWN
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_SHUTDOWN
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_shutdown(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_SIMULATE_POSITION
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_SIMULATE_POSITION
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_simulate_position(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_SIMULATE_POSITION_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_SIMULATE_POSITION_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_simulate_position_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_SUBMIT_SIGNED
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_SUBMIT_SIGNED
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_submit_signed(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_SUBMIT_SIGNED_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_SUBMIT_SIGNED_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_submit_signed_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_SUBSCRIBE_ALL_MIDS
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_SUBSCRIBE_ALL_MIDS
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_subscribe_all_mids(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_SUBSCRIBE_L2_BOOK
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_SUBSCRIBE_L2_BOOK
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_subscribe_l2_book(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_SUBSCRIBE_USER_EVENTS
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_SUBSCRIBE_USER_EVENTS
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_subscribe_user_events(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_UNSUBSCRIBE
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_UNSUBSCRIBE
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_unsubscribe(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_VERIFY_ACCOUNT_EXISTS
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_VERIFY_ACCOUNT_EXISTS
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_verify_account_exists(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_VERIFY_ACCOUNT_EXISTS_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_HYPERLIQUIDINFO_VERIFY_ACCOUNT_EXISTS_ASYNC
+uint16_t uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_verify_account_exists_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_ORDERREQUESTBUILDER_BUILD
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_ORDERREQUESTBUILDER_BUILD
+uint16_t uniffi_hyperliquid_swift_checksum_method_orderrequestbuilder_build(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_ORDERREQUESTBUILDER_CLOID
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_ORDERREQUESTBUILDER_CLOID
+uint16_t uniffi_hyperliquid_swift_checksum_method_orderrequestbuilder_cloid(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_ORDERREQUESTBUILDER_LIMIT_PRICE
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_ORDERREQUESTBUILDER_LIMIT_PRICE
+uint16_t uniffi_hyperliquid_swift_checksum_method_orderrequestbuilder_limit_price(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_ORDERREQUESTBUILDER_MARKET
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_ORDERREQUESTBUILDER_MARKET
+uint16_t uniffi_hyperliquid_swift_checksum_method_orderrequestbuilder_market(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_ORDERREQUESTBUILDER_REDUCE_ONLY
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_ORDERREQUESTBUILDER_REDUCE_ONLY
+uint16_t uniffi_hyperliquid_swift_checksum_method_orderrequestbuilder_reduce_only(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_ORDERREQUESTBUILDER_SLIPPAGE
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_ORDERREQUESTBUILDER_SLIPPAGE
+uint16_t uniffi_hyperliquid_swift_checksum_method_orderrequestbuilder_slippage(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_ORDERREQUESTBUILDER_TIF
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_ORDERREQUESTBUILDER_TIF
+uint16_t uniffi_hyperliquid_swift_checksum_method_orderrequestbuilder_tif(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_ORDERREQUESTBUILDER_TRIGGER
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_ORDERREQUESTBUILDER_TRIGGER
+uint16_t uniffi_hyperliquid_swift_checksum_method_orderrequestbuilder_trigger(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_CONSTRUCTOR_CANCELLATIONTOKEN_NEW
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_CONSTRUCTOR_CANCELLATIONTOKEN_NEW
+uint16_t uniffi_hyperliquid_swift_checksum_constructor_cancellationtoken_new(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_CONSTRUCTOR_CONNECTIONPOOL_NEW
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_CONSTRUCTOR_CONNECTIONPOOL_NEW
+uint16_t uniffi_hyperliquid_swift_checksum_constructor_connectionpool_new(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_CONSTRUCTOR_ORDERREQUESTBUILDER_NEW
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_CONSTRUCTOR_ORDERREQUESTBUILDER_NEW
+uint16_t uniffi_hyperliquid_swift_checksum_constructor_orderrequestbuilder_new(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_CLOCKDRIFTCALLBACK_ON_CLOCK_DRIFT
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_CLOCKDRIFTCALLBACK_ON_CLOCK_DRIFT
+uint16_t uniffi_hyperliquid_swift_checksum_method_clockdriftcallback_on_clock_drift(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_CONNECTIONSTATUSCALLBACK_ON_STATUS
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_CONNECTIONSTATUSCALLBACK_ON_STATUS
+uint16_t uniffi_hyperliquid_swift_checksum_method_connectionstatuscallback_on_status(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_L2BOOKCALLBACK_ON_BOOK
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_L2BOOKCALLBACK_ON_BOOK
+uint16_t uniffi_hyperliquid_swift_checksum_method_l2bookcallback_on_book(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_LOGCALLBACK_ON_LOG
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_LOGCALLBACK_ON_LOG
+uint16_t uniffi_hyperliquid_swift_checksum_method_logcallback_on_log(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_MIDSCALLBACK_ON_MIDS
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_MIDSCALLBACK_ON_MIDS
+uint16_t uniffi_hyperliquid_swift_checksum_method_midscallback_on_mids(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_USEREVENTCALLBACK_ON_EVENT
+#define UNIFFI_FFIDEF_UNIFFI_HYPERLIQUID_SWIFT_CHECKSUM_METHOD_USEREVENTCALLBACK_ON_EVENT
+uint16_t uniffi_hyperliquid_swift_checksum_method_usereventcallback_on_event(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_FFI_HYPERLIQUID_SWIFT_UNIFFI_CONTRACT_VERSION
@@ -396,6 +396,22 @@ fileprivate class UniffiHandleMap<T>: @unchecked Sendable {
 // Public interface members begin here.
 
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterUInt32: FfiConverterPrimitive {
+    typealias FfiType = UInt32
+    typealias SwiftType = UInt32
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UInt32 {
+        return try lift(readInt(&buf))
+    }
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -412,6 +428,22 @@ fileprivate struct FfiConverterUInt64: FfiConverterPrimitive {
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterInt64: FfiConverterPrimitive {
+    typealias FfiType = Int64
+    typealias SwiftType = Int64
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Int64 {
+        return try lift(readInt(&buf))
+    }
+
+    public static func write(_ value: Int64, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -496,26 +528,16 @@ fileprivate struct FfiConverterString: FfiConverter {
 
 
 
-public protocol HyperliquidExchangeProtocol : AnyObject {
+public protocol CancellationTokenProtocol : AnyObject {
     
-    func cancelAllOrders(asset: String?) throws  -> String
+    func cancel() 
     
-    func cancelAllOrdersAsync(asset: String?) async throws  -> String
-    
-    func cancelOrder(cancel: CancelRequest) throws  -> String
-    
-    func cancelOrderAsync(cancel: CancelRequest) async throws  -> String
-    
-    func getWalletAddress()  -> String
-    
-    func placeOrder(order: OrderRequest) throws  -> String
-    
-    func placeOrderAsync(order: OrderRequest) async throws  -> String
+    func isCancelled()  -> Bool
     
 }
 
-open class HyperliquidExchange:
-    HyperliquidExchangeProtocol {
+open class CancellationToken:
+    CancellationTokenProtocol {
     fileprivate let pointer: UnsafeMutableRawPointer!
 
     /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
@@ -549,123 +571,61 @@ open class HyperliquidExchange:
     @_documentation(visibility: private)
 #endif
     public func uniffiClonePointer() -> UnsafeMutableRawPointer {
-        return try! rustCall { uniffi_hyperliquid_swift_fn_clone_hyperliquidexchange(self.pointer, $0) }
+        return try! rustCall { uniffi_hyperliquid_swift_fn_clone_cancellationtoken(self.pointer, $0) }
     }
-    // No primary constructor declared for this class.
+public convenience init() {
+    let pointer =
+        try! rustCall() {
+    uniffi_hyperliquid_swift_fn_constructor_cancellationtoken_new($0
+    )
+}
+    self.init(unsafeFromRawPointer: pointer)
+}
 
     deinit {
         guard let pointer = pointer else {
             return
         }
 
-        try! rustCall { uniffi_hyperliquid_swift_fn_free_hyperliquidexchange(pointer, $0) }
+        try! rustCall { uniffi_hyperliquid_swift_fn_free_cancellationtoken(pointer, $0) }
     }
 
     
 
     
-open func cancelAllOrders(asset: String?)throws  -> String {
-    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
-    uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_cancel_all_orders(self.uniffiClonePointer(),
-        FfiConverterOptionString.lower(asset),$0
-    )
-})
-}
-    
-open func cancelAllOrdersAsync(asset: String?)async throws  -> String {
-    return
-        try  await uniffiRustCallAsync(
-            rustFutureFunc: {
-                uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_cancel_all_orders_async(
-                    self.uniffiClonePointer(),
-                    FfiConverterOptionString.lower(asset)
-                )
-            },
-            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
-            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
-            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
-            liftFunc: FfiConverterString.lift,
-            errorHandler: FfiConverterTypeHyperliquidError.lift
-        )
-}
-    
-open func cancelOrder(cancel: CancelRequest)throws  -> String {
-    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
-    uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_cancel_order(self.uniffiClonePointer(),
-        FfiConverterTypeCancelRequest.lower(cancel),$0
+open func cancel() {try! rustCall() {
+    uniffi_hyperliquid_swift_fn_method_cancellationtoken_cancel(self.uniffiClonePointer(),$0
     )
-})
-}
-    
-open func cancelOrderAsync(cancel: CancelRequest)async throws  -> String {
-    return
-        try  await uniffiRustCallAsync(
-            rustFutureFunc: {
-                uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_cancel_order_async(
-                    self.uniffiClonePointer(),
-                    FfiConverterTypeCancelRequest.lower(cancel)
-                )
-            },
-            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
-            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
-            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
-            liftFunc: FfiConverterString.lift,
-            errorHandler: FfiConverterTypeHyperliquidError.lift
-        )
 }
-    
-open func getWalletAddress() -> String {
-    return try!  FfiConverterString.lift(try! rustCall() {
-    uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_get_wallet_address(self.uniffiClonePointer(),$0
-    )
-})
 }
     
-open func placeOrder(order: OrderRequest)throws  -> String {
-    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
-    uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_place_order(self.uniffiClonePointer(),
-        FfiConverterTypeOrderRequest.lower(order),$0
+open func isCancelled() -> Bool {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_hyperliquid_swift_fn_method_cancellationtoken_is_cancelled(self.uniffiClonePointer(),$0
     )
 })
 }
     
-open func placeOrderAsync(order: OrderRequest)async throws  -> String {
-    return
-        try  await uniffiRustCallAsync(
-            rustFutureFunc: {
-                uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_place_order_async(
-                    self.uniffiClonePointer(),
-                    FfiConverterTypeOrderRequest.lower(order)
-                )
-            },
-            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
-            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
-            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
-            liftFunc: FfiConverterString.lift,
-            errorHandler: FfiConverterTypeHyperliquidError.lift
-        )
-}
-    
 
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeHyperliquidExchange: FfiConverter {
+public struct FfiConverterTypeCancellationToken: FfiConverter {
 
     typealias FfiType = UnsafeMutableRawPointer
-    typealias SwiftType = HyperliquidExchange
+    typealias SwiftType = CancellationToken
 
-    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> HyperliquidExchange {
-        return HyperliquidExchange(unsafeFromRawPointer: pointer)
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> CancellationToken {
+        return CancellationToken(unsafeFromRawPointer: pointer)
     }
 
-    public static func lower(_ value: HyperliquidExchange) -> UnsafeMutableRawPointer {
+    public static func lower(_ value: CancellationToken) -> UnsafeMutableRawPointer {
         return value.uniffiClonePointer()
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HyperliquidExchange {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CancellationToken {
         let v: UInt64 = try readInt(&buf)
         // The Rust code won't compile if a pointer won't fit in a UInt64.
         // We have to go via `UInt` because that's the thing that's the size of a pointer.
@@ -676,7 +636,7 @@ public struct FfiConverterTypeHyperliquidExchange: FfiConverter {
         return try lift(ptr!)
     }
 
-    public static func write(_ value: HyperliquidExchange, into buf: inout [UInt8]) {
+    public static func write(_ value: CancellationToken, into buf: inout [UInt8]) {
         // This fiddling is because `Int` is the thing that's the same size as a pointer.
         // The Rust code won't compile if a pointer won't fit in a `UInt64`.
         writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
@@ -689,42 +649,26 @@ public struct FfiConverterTypeHyperliquidExchange: FfiConverter {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeHyperliquidExchange_lift(_ pointer: UnsafeMutableRawPointer) throws -> HyperliquidExchange {
-    return try FfiConverterTypeHyperliquidExchange.lift(pointer)
+public func FfiConverterTypeCancellationToken_lift(_ pointer: UnsafeMutableRawPointer) throws -> CancellationToken {
+    return try FfiConverterTypeCancellationToken.lift(pointer)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeHyperliquidExchange_lower(_ value: HyperliquidExchange) -> UnsafeMutableRawPointer {
-    return FfiConverterTypeHyperliquidExchange.lower(value)
+public func FfiConverterTypeCancellationToken_lower(_ value: CancellationToken) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeCancellationToken.lower(value)
 }
 
 
 
 
-public protocol HyperliquidInfoProtocol : AnyObject {
-    
-    func getAllMids() throws  -> [String: String]
-    
-    func getAllMidsAsync() async throws  -> [String: String]
-    
-    func getOpenOrders(address: String) throws  -> [OpenOrder]
-    
-    func getOpenOrdersAsync(address: String) async throws  -> [OpenOrder]
-    
-    func getUserBalances(address: String) throws  -> [UserBalance]
-    
-    func getUserBalancesAsync(address: String) async throws  -> [UserBalance]
-    
-    func getUserState(address: String) throws  -> UserState
-    
-    func getUserStateAsync(address: String) async throws  -> UserState
+public protocol ConnectionPoolProtocol : AnyObject {
     
 }
 
-open class HyperliquidInfo:
-    HyperliquidInfoProtocol {
+open class ConnectionPool:
+    ConnectionPoolProtocol {
     fileprivate let pointer: UnsafeMutableRawPointer!
 
     /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
@@ -758,140 +702,48 @@ open class HyperliquidInfo:
     @_documentation(visibility: private)
 #endif
     public func uniffiClonePointer() -> UnsafeMutableRawPointer {
-        return try! rustCall { uniffi_hyperliquid_swift_fn_clone_hyperliquidinfo(self.pointer, $0) }
+        return try! rustCall { uniffi_hyperliquid_swift_fn_clone_connectionpool(self.pointer, $0) }
     }
-    // No primary constructor declared for this class.
+public convenience init() {
+    let pointer =
+        try! rustCall() {
+    uniffi_hyperliquid_swift_fn_constructor_connectionpool_new($0
+    )
+}
+    self.init(unsafeFromRawPointer: pointer)
+}
 
     deinit {
         guard let pointer = pointer else {
             return
         }
 
-        try! rustCall { uniffi_hyperliquid_swift_fn_free_hyperliquidinfo(pointer, $0) }
+        try! rustCall { uniffi_hyperliquid_swift_fn_free_connectionpool(pointer, $0) }
     }
 
     
 
     
-open func getAllMids()throws  -> [String: String] {
-    return try  FfiConverterDictionaryStringString.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
-    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_all_mids(self.uniffiClonePointer(),$0
-    )
-})
-}
-    
-open func getAllMidsAsync()async throws  -> [String: String] {
-    return
-        try  await uniffiRustCallAsync(
-            rustFutureFunc: {
-                uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_all_mids_async(
-                    self.uniffiClonePointer()
-                    
-                )
-            },
-            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
-            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
-            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
-            liftFunc: FfiConverterDictionaryStringString.lift,
-            errorHandler: FfiConverterTypeHyperliquidError.lift
-        )
-}
-    
-open func getOpenOrders(address: String)throws  -> [OpenOrder] {
-    return try  FfiConverterSequenceTypeOpenOrder.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
-    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_open_orders(self.uniffiClonePointer(),
-        FfiConverterString.lower(address),$0
-    )
-})
-}
-    
-open func getOpenOrdersAsync(address: String)async throws  -> [OpenOrder] {
-    return
-        try  await uniffiRustCallAsync(
-            rustFutureFunc: {
-                uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_open_orders_async(
-                    self.uniffiClonePointer(),
-                    FfiConverterString.lower(address)
-                )
-            },
-            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
-            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
-            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
-            liftFunc: FfiConverterSequenceTypeOpenOrder.lift,
-            errorHandler: FfiConverterTypeHyperliquidError.lift
-        )
-}
-    
-open func getUserBalances(address: String)throws  -> [UserBalance] {
-    return try  FfiConverterSequenceTypeUserBalance.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
-    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_user_balances(self.uniffiClonePointer(),
-        FfiConverterString.lower(address),$0
-    )
-})
-}
-    
-open func getUserBalancesAsync(address: String)async throws  -> [UserBalance] {
-    return
-        try  await uniffiRustCallAsync(
-            rustFutureFunc: {
-                uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_user_balances_async(
-                    self.uniffiClonePointer(),
-                    FfiConverterString.lower(address)
-                )
-            },
-            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
-            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
-            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
-            liftFunc: FfiConverterSequenceTypeUserBalance.lift,
-            errorHandler: FfiConverterTypeHyperliquidError.lift
-        )
-}
-    
-open func getUserState(address: String)throws  -> UserState {
-    return try  FfiConverterTypeUserState.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
-    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_user_state(self.uniffiClonePointer(),
-        FfiConverterString.lower(address),$0
-    )
-})
-}
-    
-open func getUserStateAsync(address: String)async throws  -> UserState {
-    return
-        try  await uniffiRustCallAsync(
-            rustFutureFunc: {
-                uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_user_state_async(
-                    self.uniffiClonePointer(),
-                    FfiConverterString.lower(address)
-                )
-            },
-            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
-            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
-            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
-            liftFunc: FfiConverterTypeUserState.lift,
-            errorHandler: FfiConverterTypeHyperliquidError.lift
-        )
-}
-    
 
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeHyperliquidInfo: FfiConverter {
+public struct FfiConverterTypeConnectionPool: FfiConverter {
 
     typealias FfiType = UnsafeMutableRawPointer
-    typealias SwiftType = HyperliquidInfo
+    typealias SwiftType = ConnectionPool
 
-    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> HyperliquidInfo {
-        return HyperliquidInfo(unsafeFromRawPointer: pointer)
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> ConnectionPool {
+        return ConnectionPool(unsafeFromRawPointer: pointer)
     }
 
-    public static func lower(_ value: HyperliquidInfo) -> UnsafeMutableRawPointer {
+    public static func lower(_ value: ConnectionPool) -> UnsafeMutableRawPointer {
         return value.uniffiClonePointer()
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HyperliquidInfo {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ConnectionPool {
         let v: UInt64 = try readInt(&buf)
         // The Rust code won't compile if a pointer won't fit in a UInt64.
         // We have to go via `UInt` because that's the thing that's the size of a pointer.
@@ -902,7 +754,7 @@ public struct FfiConverterTypeHyperliquidInfo: FfiConverter {
         return try lift(ptr!)
     }
 
-    public static func write(_ value: HyperliquidInfo, into buf: inout [UInt8]) {
+    public static func write(_ value: ConnectionPool, into buf: inout [UInt8]) {
         // This fiddling is because `Int` is the thing that's the same size as a pointer.
         // The Rust code won't compile if a pointer won't fit in a `UInt64`.
         writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
@@ -915,789 +767,9659 @@ public struct FfiConverterTypeHyperliquidInfo: FfiConverter {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeHyperliquidInfo_lift(_ pointer: UnsafeMutableRawPointer) throws -> HyperliquidInfo {
-    return try FfiConverterTypeHyperliquidInfo.lift(pointer)
+public func FfiConverterTypeConnectionPool_lift(_ pointer: UnsafeMutableRawPointer) throws -> ConnectionPool {
+    return try FfiConverterTypeConnectionPool.lift(pointer)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeHyperliquidInfo_lower(_ value: HyperliquidInfo) -> UnsafeMutableRawPointer {
-    return FfiConverterTypeHyperliquidInfo.lower(value)
+public func FfiConverterTypeConnectionPool_lower(_ value: ConnectionPool) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeConnectionPool.lower(value)
 }
 
 
-public struct CancelRequest {
-    public var asset: String
-    public var oid: UInt64
 
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(asset: String, oid: UInt64) {
-        self.asset = asset
-        self.oid = oid
-    }
-}
-
-
-
-extension CancelRequest: Equatable, Hashable {
-    public static func ==(lhs: CancelRequest, rhs: CancelRequest) -> Bool {
-        if lhs.asset != rhs.asset {
-            return false
-        }
-        if lhs.oid != rhs.oid {
-            return false
-        }
-        return true
-    }
 
-    public func hash(into hasher: inout Hasher) {
-        hasher.combine(asset)
-        hasher.combine(oid)
-    }
+public protocol HyperliquidExchangeProtocol : AnyObject {
+    
+    func approveAgent(agentAddress: String, name: String?) throws  -> ExchangeStatus
+    
+    func approveAgentAsync(agentAddress: String, name: String?) async throws  -> ExchangeStatus
+    
+    func approveBuilderFee(builder: String, maxFeeRate: String) throws  -> ExchangeStatus
+    
+    func approveBuilderFeeAsync(builder: String, maxFeeRate: String) async throws  -> ExchangeStatus
+    
+    func assetToIndex(name: String) throws  -> UInt32
+    
+    func bulkModify(modifies: [ModifyRequest]) throws  -> [OrderResponse]
+    
+    func bulkModifyAsync(modifies: [ModifyRequest]) async throws  -> [OrderResponse]
+    
+    func cancelAllOrders(asset: String?) throws  -> [CancelResponse]
+    
+    func cancelAllOrdersAsync(asset: String?) async throws  -> [CancelResponse]
+    
+    func cancelByCloid(asset: String, cloid: String) throws  -> CancelResponse
+    
+    func cancelByCloidAsync(asset: String, cloid: String) async throws  -> CancelResponse
+    
+    func cancelOrder(cancel: CancelRequest) throws  -> CancelResponse
+    
+    func cancelOrderAsync(cancel: CancelRequest) async throws  -> CancelResponse
+    
+    func cancelOrders(cancels: [CancelRequest]) throws  -> [CancelResponse]
+    
+    func cancelOrdersAsync(cancels: [CancelRequest]) async throws  -> [CancelResponse]
+    
+    func cancelReplace(oid: UInt64, newOrder: OrderRequest) throws  -> OrderResponse
+    
+    func cancelReplaceAsync(oid: UInt64, newOrder: OrderRequest) async throws  -> OrderResponse
+    
+    func cancelTwap(asset: String, twapId: UInt64) throws  -> ExchangeStatus
+    
+    func cancelTwapAsync(asset: String, twapId: UInt64) async throws  -> ExchangeStatus
+    
+    func cancellable(token: CancellationToken)  -> HyperliquidExchange
+    
+    func classTransfer(amount: Double, toPerp: Bool) throws  -> ExchangeStatus
+    
+    func classTransferAsync(amount: Double, toPerp: Bool) async throws  -> ExchangeStatus
+    
+    func closePosition(asset: String, slippage: Double?) throws  -> OrderResponse
+    
+    func closePositionAsync(asset: String, slippage: Double?) async throws  -> OrderResponse
+    
+    func getBaseUrl()  -> BaseUrl
+    
+    func getServerTime() throws  -> UInt64
+    
+    func getServerTimeAsync() async throws  -> UInt64
+    
+    func getWalletAddress()  -> String
+    
+    func indexToAsset(index: UInt32) throws  -> String
+    
+    func modifyOrder(oid: UInt64, newOrder: OrderRequest) throws  -> OrderResponse
+    
+    func modifyOrderAsync(oid: UInt64, newOrder: OrderRequest) async throws  -> OrderResponse
+    
+    func placeOrder(order: OrderRequest) throws  -> OrderResponse
+    
+    func placeOrderAsync(order: OrderRequest) async throws  -> OrderResponse
+    
+    func placeOrderOid(order: OrderRequest) throws  -> UInt64?
+    
+    func placeOrderOidAsync(order: OrderRequest) async throws  -> UInt64?
+    
+    func placeOrderRelative(asset: String, side: OrderSide, size: Double, offsetBps: Double, tif: TimeInForce) throws  -> OrderResponse
+    
+    func placeOrderRelativeAsync(asset: String, side: OrderSide, size: Double, offsetBps: Double, tif: TimeInForce) async throws  -> OrderResponse
+    
+    func placeOrderWithMargin(order: OrderRequest, marginMode: MarginMode, leverage: UInt32) throws  -> OrderResponse
+    
+    func placeOrderWithMarginAsync(order: OrderRequest, marginMode: MarginMode, leverage: UInt32) async throws  -> OrderResponse
+    
+    func placeOrderWithTpsl(entry: OrderRequest, takeProfit: TriggerSpec?, stopLoss: TriggerSpec?) throws  -> [OrderResponse]
+    
+    func placeOrderWithTpslAsync(entry: OrderRequest, takeProfit: TriggerSpec?, stopLoss: TriggerSpec?) async throws  -> [OrderResponse]
+    
+    func placeOrdersBulk(orders: [OrderRequest], grouping: OrderGrouping) throws  -> [OrderResponse]
+    
+    func placeOrdersBulkAsync(orders: [OrderRequest], grouping: OrderGrouping) async throws  -> [OrderResponse]
+    
+    func placeTwap(asset: String, side: OrderSide, size: Double, durationMinutes: UInt32, reduceOnly: Bool) throws  -> UInt64
+    
+    func placeTwapAsync(asset: String, side: OrderSide, size: Double, durationMinutes: UInt32, reduceOnly: Bool) async throws  -> UInt64
+    
+    func refreshMeta() throws 
+    
+    func refreshMetaAsync() async throws 
+    
+    func scheduleCancel(timeMs: UInt64?) throws  -> ExchangeStatus
+    
+    func scheduleCancelAsync(timeMs: UInt64?) async throws  -> ExchangeStatus
+    
+    func setClockDriftCallback(callback: ClockDriftCallback) 
+    
+    func setDefaultSlippage(fraction: Double) throws 
+    
+    func setReferrer(code: String) throws  -> ExchangeStatus
+    
+    func setReferrerAsync(code: String) async throws  -> ExchangeStatus
+    
+    func signMessage(message: String) throws  -> String
+    
+    func signOrder(order: OrderRequest) throws  -> String
+    
+    func signOrderAsync(order: OrderRequest) async throws  -> String
+    
+    func spotTransfer(token: String, amount: Double, destination: String) throws  -> ExchangeStatus
+    
+    func spotTransferAsync(token: String, amount: Double, destination: String) async throws  -> ExchangeStatus
+    
+    func updateIsolatedMargin(asset: String, amount: Double) throws  -> ExchangeStatus
+    
+    func updateIsolatedMarginAsync(asset: String, amount: Double) async throws  -> ExchangeStatus
+    
+    func updateLeverage(asset: String, leverage: UInt32, isCross: Bool) throws  -> ExchangeStatus
+    
+    func updateLeverageAsync(asset: String, leverage: UInt32, isCross: Bool) async throws  -> ExchangeStatus
+    
+    func usdTransfer(amount: Double, destination: String) throws  -> ExchangeStatus
+    
+    func usdTransferAsync(amount: Double, destination: String) async throws  -> ExchangeStatus
+    
+    func validateOrder(order: OrderRequest) throws 
+    
+    func validateOrderAsync(order: OrderRequest) async throws 
+    
+    func verifyAccountExists() throws  -> Bool
+    
+    func verifyAccountExistsAsync() async throws  -> Bool
+    
+    func waitForOrder(response: OrderResponse, timeoutMs: UInt64) throws  -> OrderResponse
+    
+    func waitForOrderAsync(response: OrderResponse, timeoutMs: UInt64) async throws  -> OrderResponse
+    
+    func withdrawFromBridge(amount: Double, destination: String) throws  -> ExchangeStatus
+    
+    func withdrawFromBridgeAsync(amount: Double, destination: String) async throws  -> ExchangeStatus
+    
 }
 
+open class HyperliquidExchange:
+    HyperliquidExchangeProtocol {
+    fileprivate let pointer: UnsafeMutableRawPointer!
 
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
 #if swift(>=5.8)
-@_documentation(visibility: private)
+    @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeCancelRequest: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CancelRequest {
-        return
-            try CancelRequest(
-                asset: FfiConverterString.read(from: &buf), 
-                oid: FfiConverterUInt64.read(from: &buf)
-        )
+    public struct NoPointer {
+        public init() {}
     }
 
-    public static func write(_ value: CancelRequest, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.asset, into: &buf)
-        FfiConverterUInt64.write(value.oid, into: &buf)
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
     }
-}
-
 
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
 #if swift(>=5.8)
-@_documentation(visibility: private)
+    @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeCancelRequest_lift(_ buf: RustBuffer) throws -> CancelRequest {
-    return try FfiConverterTypeCancelRequest.lift(buf)
-}
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
 
 #if swift(>=5.8)
-@_documentation(visibility: private)
+    @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeCancelRequest_lower(_ value: CancelRequest) -> RustBuffer {
-    return FfiConverterTypeCancelRequest.lower(value)
-}
-
-
-public struct OpenOrder {
-    public var asset: String
-    public var isBuy: Bool
-    public var size: Double
-    public var price: Double
-    public var oid: UInt64
-    public var timestamp: UInt64
-
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(asset: String, isBuy: Bool, size: Double, price: Double, oid: UInt64, timestamp: UInt64) {
-        self.asset = asset
-        self.isBuy = isBuy
-        self.size = size
-        self.price = price
-        self.oid = oid
-        self.timestamp = timestamp
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_hyperliquid_swift_fn_clone_hyperliquidexchange(self.pointer, $0) }
     }
-}
-
-
+    // No primary constructor declared for this class.
 
-extension OpenOrder: Equatable, Hashable {
-    public static func ==(lhs: OpenOrder, rhs: OpenOrder) -> Bool {
-        if lhs.asset != rhs.asset {
-            return false
-        }
-        if lhs.isBuy != rhs.isBuy {
-            return false
-        }
-        if lhs.size != rhs.size {
-            return false
-        }
-        if lhs.price != rhs.price {
-            return false
-        }
-        if lhs.oid != rhs.oid {
-            return false
-        }
-        if lhs.timestamp != rhs.timestamp {
-            return false
+    deinit {
+        guard let pointer = pointer else {
+            return
         }
-        return true
-    }
 
-    public func hash(into hasher: inout Hasher) {
-        hasher.combine(asset)
-        hasher.combine(isBuy)
-        hasher.combine(size)
-        hasher.combine(price)
-        hasher.combine(oid)
-        hasher.combine(timestamp)
+        try! rustCall { uniffi_hyperliquid_swift_fn_free_hyperliquidexchange(pointer, $0) }
     }
-}
 
+    
 
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public struct FfiConverterTypeOpenOrder: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> OpenOrder {
-        return
-            try OpenOrder(
-                asset: FfiConverterString.read(from: &buf), 
-                isBuy: FfiConverterBool.read(from: &buf), 
-                size: FfiConverterDouble.read(from: &buf), 
-                price: FfiConverterDouble.read(from: &buf), 
-                oid: FfiConverterUInt64.read(from: &buf), 
-                timestamp: FfiConverterUInt64.read(from: &buf)
+    
+open func approveAgent(agentAddress: String, name: String?)throws  -> ExchangeStatus {
+    return try  FfiConverterTypeExchangeStatus.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_approve_agent(self.uniffiClonePointer(),
+        FfiConverterString.lower(agentAddress),
+        FfiConverterOptionString.lower(name),$0
+    )
+})
+}
+    
+open func approveAgentAsync(agentAddress: String, name: String?)async throws  -> ExchangeStatus {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_approve_agent_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(agentAddress),FfiConverterOptionString.lower(name)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeExchangeStatus.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
         )
-    }
-
-    public static func write(_ value: OpenOrder, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.asset, into: &buf)
-        FfiConverterBool.write(value.isBuy, into: &buf)
-        FfiConverterDouble.write(value.size, into: &buf)
-        FfiConverterDouble.write(value.price, into: &buf)
-        FfiConverterUInt64.write(value.oid, into: &buf)
-        FfiConverterUInt64.write(value.timestamp, into: &buf)
-    }
 }
-
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public func FfiConverterTypeOpenOrder_lift(_ buf: RustBuffer) throws -> OpenOrder {
-    return try FfiConverterTypeOpenOrder.lift(buf)
+    
+open func approveBuilderFee(builder: String, maxFeeRate: String)throws  -> ExchangeStatus {
+    return try  FfiConverterTypeExchangeStatus.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_approve_builder_fee(self.uniffiClonePointer(),
+        FfiConverterString.lower(builder),
+        FfiConverterString.lower(maxFeeRate),$0
+    )
+})
 }
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public func FfiConverterTypeOpenOrder_lower(_ value: OpenOrder) -> RustBuffer {
-    return FfiConverterTypeOpenOrder.lower(value)
+    
+open func approveBuilderFeeAsync(builder: String, maxFeeRate: String)async throws  -> ExchangeStatus {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_approve_builder_fee_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(builder),FfiConverterString.lower(maxFeeRate)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeExchangeStatus.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
 }
-
-
-public struct OrderRequest {
-    public var asset: String
-    public var isBuy: Bool
-    public var size: Double
-    public var price: Double
-    public var reduceOnly: Bool
-
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(asset: String, isBuy: Bool, size: Double, price: Double, reduceOnly: Bool) {
-        self.asset = asset
-        self.isBuy = isBuy
-        self.size = size
-        self.price = price
-        self.reduceOnly = reduceOnly
-    }
+    
+open func assetToIndex(name: String)throws  -> UInt32 {
+    return try  FfiConverterUInt32.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_asset_to_index(self.uniffiClonePointer(),
+        FfiConverterString.lower(name),$0
+    )
+})
 }
-
-
-
-extension OrderRequest: Equatable, Hashable {
-    public static func ==(lhs: OrderRequest, rhs: OrderRequest) -> Bool {
-        if lhs.asset != rhs.asset {
-            return false
-        }
-        if lhs.isBuy != rhs.isBuy {
-            return false
-        }
-        if lhs.size != rhs.size {
-            return false
-        }
-        if lhs.price != rhs.price {
-            return false
-        }
-        if lhs.reduceOnly != rhs.reduceOnly {
-            return false
-        }
-        return true
-    }
-
-    public func hash(into hasher: inout Hasher) {
-        hasher.combine(asset)
-        hasher.combine(isBuy)
-        hasher.combine(size)
-        hasher.combine(price)
-        hasher.combine(reduceOnly)
-    }
+    
+open func bulkModify(modifies: [ModifyRequest])throws  -> [OrderResponse] {
+    return try  FfiConverterSequenceTypeOrderResponse.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_bulk_modify(self.uniffiClonePointer(),
+        FfiConverterSequenceTypeModifyRequest.lower(modifies),$0
+    )
+})
 }
-
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public struct FfiConverterTypeOrderRequest: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> OrderRequest {
-        return
-            try OrderRequest(
-                asset: FfiConverterString.read(from: &buf), 
-                isBuy: FfiConverterBool.read(from: &buf), 
-                size: FfiConverterDouble.read(from: &buf), 
-                price: FfiConverterDouble.read(from: &buf), 
-                reduceOnly: FfiConverterBool.read(from: &buf)
+    
+open func bulkModifyAsync(modifies: [ModifyRequest])async throws  -> [OrderResponse] {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_bulk_modify_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterSequenceTypeModifyRequest.lower(modifies)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceTypeOrderResponse.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
         )
-    }
-
-    public static func write(_ value: OrderRequest, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.asset, into: &buf)
-        FfiConverterBool.write(value.isBuy, into: &buf)
-        FfiConverterDouble.write(value.size, into: &buf)
-        FfiConverterDouble.write(value.price, into: &buf)
-        FfiConverterBool.write(value.reduceOnly, into: &buf)
-    }
 }
-
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public func FfiConverterTypeOrderRequest_lift(_ buf: RustBuffer) throws -> OrderRequest {
-    return try FfiConverterTypeOrderRequest.lift(buf)
+    
+open func cancelAllOrders(asset: String?)throws  -> [CancelResponse] {
+    return try  FfiConverterSequenceTypeCancelResponse.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_cancel_all_orders(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(asset),$0
+    )
+})
 }
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public func FfiConverterTypeOrderRequest_lower(_ value: OrderRequest) -> RustBuffer {
-    return FfiConverterTypeOrderRequest.lower(value)
+    
+open func cancelAllOrdersAsync(asset: String?)async throws  -> [CancelResponse] {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_cancel_all_orders_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterOptionString.lower(asset)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceTypeCancelResponse.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
 }
-
-
-public struct UserBalance {
-    public var token: String
-    public var hold: Double
-    public var total: Double
-
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(token: String, hold: Double, total: Double) {
-        self.token = token
-        self.hold = hold
-        self.total = total
-    }
+    
+open func cancelByCloid(asset: String, cloid: String)throws  -> CancelResponse {
+    return try  FfiConverterTypeCancelResponse.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_cancel_by_cloid(self.uniffiClonePointer(),
+        FfiConverterString.lower(asset),
+        FfiConverterString.lower(cloid),$0
+    )
+})
 }
-
-
-
-extension UserBalance: Equatable, Hashable {
-    public static func ==(lhs: UserBalance, rhs: UserBalance) -> Bool {
-        if lhs.token != rhs.token {
-            return false
-        }
-        if lhs.hold != rhs.hold {
-            return false
-        }
-        if lhs.total != rhs.total {
-            return false
-        }
-        return true
-    }
-
-    public func hash(into hasher: inout Hasher) {
-        hasher.combine(token)
-        hasher.combine(hold)
-        hasher.combine(total)
-    }
+    
+open func cancelByCloidAsync(asset: String, cloid: String)async throws  -> CancelResponse {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_cancel_by_cloid_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(asset),FfiConverterString.lower(cloid)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeCancelResponse.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
 }
-
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public struct FfiConverterTypeUserBalance: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UserBalance {
-        return
-            try UserBalance(
-                token: FfiConverterString.read(from: &buf), 
-                hold: FfiConverterDouble.read(from: &buf), 
-                total: FfiConverterDouble.read(from: &buf)
+    
+open func cancelOrder(cancel: CancelRequest)throws  -> CancelResponse {
+    return try  FfiConverterTypeCancelResponse.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_cancel_order(self.uniffiClonePointer(),
+        FfiConverterTypeCancelRequest.lower(cancel),$0
+    )
+})
+}
+    
+open func cancelOrderAsync(cancel: CancelRequest)async throws  -> CancelResponse {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_cancel_order_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterTypeCancelRequest.lower(cancel)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeCancelResponse.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
         )
-    }
-
-    public static func write(_ value: UserBalance, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.token, into: &buf)
-        FfiConverterDouble.write(value.hold, into: &buf)
-        FfiConverterDouble.write(value.total, into: &buf)
-    }
 }
-
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public func FfiConverterTypeUserBalance_lift(_ buf: RustBuffer) throws -> UserBalance {
-    return try FfiConverterTypeUserBalance.lift(buf)
+    
+open func cancelOrders(cancels: [CancelRequest])throws  -> [CancelResponse] {
+    return try  FfiConverterSequenceTypeCancelResponse.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_cancel_orders(self.uniffiClonePointer(),
+        FfiConverterSequenceTypeCancelRequest.lower(cancels),$0
+    )
+})
 }
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public func FfiConverterTypeUserBalance_lower(_ value: UserBalance) -> RustBuffer {
-    return FfiConverterTypeUserBalance.lower(value)
+    
+open func cancelOrdersAsync(cancels: [CancelRequest])async throws  -> [CancelResponse] {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_cancel_orders_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterSequenceTypeCancelRequest.lower(cancels)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceTypeCancelResponse.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
 }
-
-
-public struct UserState {
-    public var address: String
-    public var marginSummaryEquity: Double
-    public var marginSummaryAccountValue: Double
-    public var marginSummaryTotalMarginUsed: Double
-
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(address: String, marginSummaryEquity: Double, marginSummaryAccountValue: Double, marginSummaryTotalMarginUsed: Double) {
-        self.address = address
-        self.marginSummaryEquity = marginSummaryEquity
-        self.marginSummaryAccountValue = marginSummaryAccountValue
-        self.marginSummaryTotalMarginUsed = marginSummaryTotalMarginUsed
-    }
+    
+open func cancelReplace(oid: UInt64, newOrder: OrderRequest)throws  -> OrderResponse {
+    return try  FfiConverterTypeOrderResponse.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_cancel_replace(self.uniffiClonePointer(),
+        FfiConverterUInt64.lower(oid),
+        FfiConverterTypeOrderRequest.lower(newOrder),$0
+    )
+})
 }
-
-
-
-extension UserState: Equatable, Hashable {
-    public static func ==(lhs: UserState, rhs: UserState) -> Bool {
-        if lhs.address != rhs.address {
-            return false
-        }
-        if lhs.marginSummaryEquity != rhs.marginSummaryEquity {
-            return false
-        }
-        if lhs.marginSummaryAccountValue != rhs.marginSummaryAccountValue {
-            return false
-        }
-        if lhs.marginSummaryTotalMarginUsed != rhs.marginSummaryTotalMarginUsed {
-            return false
-        }
-        return true
-    }
-
-    public func hash(into hasher: inout Hasher) {
-        hasher.combine(address)
-        hasher.combine(marginSummaryEquity)
-        hasher.combine(marginSummaryAccountValue)
-        hasher.combine(marginSummaryTotalMarginUsed)
-    }
-}
-
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public struct FfiConverterTypeUserState: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UserState {
-        return
-            try UserState(
-                address: FfiConverterString.read(from: &buf), 
-                marginSummaryEquity: FfiConverterDouble.read(from: &buf), 
-                marginSummaryAccountValue: FfiConverterDouble.read(from: &buf), 
-                marginSummaryTotalMarginUsed: FfiConverterDouble.read(from: &buf)
+    
+open func cancelReplaceAsync(oid: UInt64, newOrder: OrderRequest)async throws  -> OrderResponse {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_cancel_replace_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterUInt64.lower(oid),FfiConverterTypeOrderRequest.lower(newOrder)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeOrderResponse.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
         )
-    }
-
-    public static func write(_ value: UserState, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.address, into: &buf)
-        FfiConverterDouble.write(value.marginSummaryEquity, into: &buf)
-        FfiConverterDouble.write(value.marginSummaryAccountValue, into: &buf)
-        FfiConverterDouble.write(value.marginSummaryTotalMarginUsed, into: &buf)
-    }
 }
-
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public func FfiConverterTypeUserState_lift(_ buf: RustBuffer) throws -> UserState {
-    return try FfiConverterTypeUserState.lift(buf)
+    
+open func cancelTwap(asset: String, twapId: UInt64)throws  -> ExchangeStatus {
+    return try  FfiConverterTypeExchangeStatus.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_cancel_twap(self.uniffiClonePointer(),
+        FfiConverterString.lower(asset),
+        FfiConverterUInt64.lower(twapId),$0
+    )
+})
 }
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public func FfiConverterTypeUserState_lower(_ value: UserState) -> RustBuffer {
-    return FfiConverterTypeUserState.lower(value)
+    
+open func cancelTwapAsync(asset: String, twapId: UInt64)async throws  -> ExchangeStatus {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_cancel_twap_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(asset),FfiConverterUInt64.lower(twapId)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeExchangeStatus.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
 }
-
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
-
-public enum BaseUrl {
     
-    case mainnet
-    case testnet
+open func cancellable(token: CancellationToken) -> HyperliquidExchange {
+    return try!  FfiConverterTypeHyperliquidExchange.lift(try! rustCall() {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_cancellable(self.uniffiClonePointer(),
+        FfiConverterTypeCancellationToken.lower(token),$0
+    )
+})
 }
-
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public struct FfiConverterTypeBaseUrl: FfiConverterRustBuffer {
-    typealias SwiftType = BaseUrl
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> BaseUrl {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-        
-        case 1: return .mainnet
-        
-        case 2: return .testnet
-        
-        default: throw UniffiInternalError.unexpectedEnumCase
-        }
-    }
-
-    public static func write(_ value: BaseUrl, into buf: inout [UInt8]) {
-        switch value {
-        
-        
-        case .mainnet:
-            writeInt(&buf, Int32(1))
-        
-        
-        case .testnet:
-            writeInt(&buf, Int32(2))
-        
-        }
-    }
+    
+open func classTransfer(amount: Double, toPerp: Bool)throws  -> ExchangeStatus {
+    return try  FfiConverterTypeExchangeStatus.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_class_transfer(self.uniffiClonePointer(),
+        FfiConverterDouble.lower(amount),
+        FfiConverterBool.lower(toPerp),$0
+    )
+})
 }
-
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public func FfiConverterTypeBaseUrl_lift(_ buf: RustBuffer) throws -> BaseUrl {
-    return try FfiConverterTypeBaseUrl.lift(buf)
+    
+open func classTransferAsync(amount: Double, toPerp: Bool)async throws  -> ExchangeStatus {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_class_transfer_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterDouble.lower(amount),FfiConverterBool.lower(toPerp)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeExchangeStatus.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
 }
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public func FfiConverterTypeBaseUrl_lower(_ value: BaseUrl) -> RustBuffer {
-    return FfiConverterTypeBaseUrl.lower(value)
+    
+open func closePosition(asset: String, slippage: Double? = nil)throws  -> OrderResponse {
+    return try  FfiConverterTypeOrderResponse.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_close_position(self.uniffiClonePointer(),
+        FfiConverterString.lower(asset),
+        FfiConverterOptionDouble.lower(slippage),$0
+    )
+})
 }
-
-
-
-extension BaseUrl: Equatable, Hashable {}
-
-
-
-
-public enum HyperliquidError {
-
     
+open func closePositionAsync(asset: String, slippage: Double? = nil)async throws  -> OrderResponse {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_close_position_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(asset),FfiConverterOptionDouble.lower(slippage)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeOrderResponse.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
     
-    case InvalidPrivateKey(message: String
+open func getBaseUrl() -> BaseUrl {
+    return try!  FfiConverterTypeBaseUrl.lift(try! rustCall() {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_get_base_url(self.uniffiClonePointer(),$0
     )
-    case NetworkError(message: String
+})
+}
+    
+open func getServerTime()throws  -> UInt64 {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_get_server_time(self.uniffiClonePointer(),$0
     )
-    case ApiError(message: String
+})
+}
+    
+open func getServerTimeAsync()async throws  -> UInt64 {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_get_server_time_async(
+                    self.uniffiClonePointer()
+                    
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_u64,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_u64,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_u64,
+            liftFunc: FfiConverterUInt64.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func getWalletAddress() -> String {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_get_wallet_address(self.uniffiClonePointer(),$0
     )
-    case InvalidInput(message: String
+})
+}
+    
+open func indexToAsset(index: UInt32)throws  -> String {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_index_to_asset(self.uniffiClonePointer(),
+        FfiConverterUInt32.lower(index),$0
     )
+})
 }
-
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public struct FfiConverterTypeHyperliquidError: FfiConverterRustBuffer {
-    typealias SwiftType = HyperliquidError
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HyperliquidError {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-
-        
-
-        
-        case 1: return .InvalidPrivateKey(
-            message: try FfiConverterString.read(from: &buf)
-            )
-        case 2: return .NetworkError(
-            message: try FfiConverterString.read(from: &buf)
-            )
-        case 3: return .ApiError(
-            message: try FfiConverterString.read(from: &buf)
-            )
-        case 4: return .InvalidInput(
-            message: try FfiConverterString.read(from: &buf)
-            )
-
-         default: throw UniffiInternalError.unexpectedEnumCase
-        }
-    }
-
-    public static func write(_ value: HyperliquidError, into buf: inout [UInt8]) {
-        switch value {
-
-        
-
-        
-        
-        case let .InvalidPrivateKey(message):
-            writeInt(&buf, Int32(1))
-            FfiConverterString.write(message, into: &buf)
-            
-        
-        case let .NetworkError(message):
-            writeInt(&buf, Int32(2))
-            FfiConverterString.write(message, into: &buf)
-            
-        
-        case let .ApiError(message):
-            writeInt(&buf, Int32(3))
-            FfiConverterString.write(message, into: &buf)
-            
-        
-        case let .InvalidInput(message):
-            writeInt(&buf, Int32(4))
-            FfiConverterString.write(message, into: &buf)
-            
-        }
-    }
+    
+open func modifyOrder(oid: UInt64, newOrder: OrderRequest)throws  -> OrderResponse {
+    return try  FfiConverterTypeOrderResponse.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_modify_order(self.uniffiClonePointer(),
+        FfiConverterUInt64.lower(oid),
+        FfiConverterTypeOrderRequest.lower(newOrder),$0
+    )
+})
+}
+    
+open func modifyOrderAsync(oid: UInt64, newOrder: OrderRequest)async throws  -> OrderResponse {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_modify_order_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterUInt64.lower(oid),FfiConverterTypeOrderRequest.lower(newOrder)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeOrderResponse.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func placeOrder(order: OrderRequest)throws  -> OrderResponse {
+    return try  FfiConverterTypeOrderResponse.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_place_order(self.uniffiClonePointer(),
+        FfiConverterTypeOrderRequest.lower(order),$0
+    )
+})
+}
+    
+open func placeOrderAsync(order: OrderRequest)async throws  -> OrderResponse {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_place_order_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterTypeOrderRequest.lower(order)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeOrderResponse.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func placeOrderOid(order: OrderRequest)throws  -> UInt64? {
+    return try  FfiConverterOptionUInt64.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_place_order_oid(self.uniffiClonePointer(),
+        FfiConverterTypeOrderRequest.lower(order),$0
+    )
+})
+}
+    
+open func placeOrderOidAsync(order: OrderRequest)async throws  -> UInt64? {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_place_order_oid_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterTypeOrderRequest.lower(order)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterOptionUInt64.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func placeOrderRelative(asset: String, side: OrderSide, size: Double, offsetBps: Double, tif: TimeInForce = .gtc)throws  -> OrderResponse {
+    return try  FfiConverterTypeOrderResponse.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_place_order_relative(self.uniffiClonePointer(),
+        FfiConverterString.lower(asset),
+        FfiConverterTypeOrderSide.lower(side),
+        FfiConverterDouble.lower(size),
+        FfiConverterDouble.lower(offsetBps),
+        FfiConverterTypeTimeInForce.lower(tif),$0
+    )
+})
+}
+    
+open func placeOrderRelativeAsync(asset: String, side: OrderSide, size: Double, offsetBps: Double, tif: TimeInForce = .gtc)async throws  -> OrderResponse {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_place_order_relative_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(asset),FfiConverterTypeOrderSide.lower(side),FfiConverterDouble.lower(size),FfiConverterDouble.lower(offsetBps),FfiConverterTypeTimeInForce.lower(tif)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeOrderResponse.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func placeOrderWithMargin(order: OrderRequest, marginMode: MarginMode, leverage: UInt32)throws  -> OrderResponse {
+    return try  FfiConverterTypeOrderResponse.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_place_order_with_margin(self.uniffiClonePointer(),
+        FfiConverterTypeOrderRequest.lower(order),
+        FfiConverterTypeMarginMode.lower(marginMode),
+        FfiConverterUInt32.lower(leverage),$0
+    )
+})
+}
+    
+open func placeOrderWithMarginAsync(order: OrderRequest, marginMode: MarginMode, leverage: UInt32)async throws  -> OrderResponse {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_place_order_with_margin_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterTypeOrderRequest.lower(order),FfiConverterTypeMarginMode.lower(marginMode),FfiConverterUInt32.lower(leverage)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeOrderResponse.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func placeOrderWithTpsl(entry: OrderRequest, takeProfit: TriggerSpec?, stopLoss: TriggerSpec?)throws  -> [OrderResponse] {
+    return try  FfiConverterSequenceTypeOrderResponse.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_place_order_with_tpsl(self.uniffiClonePointer(),
+        FfiConverterTypeOrderRequest.lower(entry),
+        FfiConverterOptionTypeTriggerSpec.lower(takeProfit),
+        FfiConverterOptionTypeTriggerSpec.lower(stopLoss),$0
+    )
+})
+}
+    
+open func placeOrderWithTpslAsync(entry: OrderRequest, takeProfit: TriggerSpec?, stopLoss: TriggerSpec?)async throws  -> [OrderResponse] {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_place_order_with_tpsl_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterTypeOrderRequest.lower(entry),FfiConverterOptionTypeTriggerSpec.lower(takeProfit),FfiConverterOptionTypeTriggerSpec.lower(stopLoss)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceTypeOrderResponse.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func placeOrdersBulk(orders: [OrderRequest], grouping: OrderGrouping = .na)throws  -> [OrderResponse] {
+    return try  FfiConverterSequenceTypeOrderResponse.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_place_orders_bulk(self.uniffiClonePointer(),
+        FfiConverterSequenceTypeOrderRequest.lower(orders),
+        FfiConverterTypeOrderGrouping.lower(grouping),$0
+    )
+})
+}
+    
+open func placeOrdersBulkAsync(orders: [OrderRequest], grouping: OrderGrouping = .na)async throws  -> [OrderResponse] {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_place_orders_bulk_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterSequenceTypeOrderRequest.lower(orders),FfiConverterTypeOrderGrouping.lower(grouping)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceTypeOrderResponse.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func placeTwap(asset: String, side: OrderSide, size: Double, durationMinutes: UInt32, reduceOnly: Bool)throws  -> UInt64 {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_place_twap(self.uniffiClonePointer(),
+        FfiConverterString.lower(asset),
+        FfiConverterTypeOrderSide.lower(side),
+        FfiConverterDouble.lower(size),
+        FfiConverterUInt32.lower(durationMinutes),
+        FfiConverterBool.lower(reduceOnly),$0
+    )
+})
+}
+    
+open func placeTwapAsync(asset: String, side: OrderSide, size: Double, durationMinutes: UInt32, reduceOnly: Bool)async throws  -> UInt64 {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_place_twap_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(asset),FfiConverterTypeOrderSide.lower(side),FfiConverterDouble.lower(size),FfiConverterUInt32.lower(durationMinutes),FfiConverterBool.lower(reduceOnly)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_u64,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_u64,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_u64,
+            liftFunc: FfiConverterUInt64.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func refreshMeta()throws  {try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_refresh_meta(self.uniffiClonePointer(),$0
+    )
+}
+}
+    
+open func refreshMetaAsync()async throws  {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_refresh_meta_async(
+                    self.uniffiClonePointer()
+                    
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_void,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_void,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_void,
+            liftFunc: { $0 },
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func scheduleCancel(timeMs: UInt64?)throws  -> ExchangeStatus {
+    return try  FfiConverterTypeExchangeStatus.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_schedule_cancel(self.uniffiClonePointer(),
+        FfiConverterOptionUInt64.lower(timeMs),$0
+    )
+})
+}
+    
+open func scheduleCancelAsync(timeMs: UInt64?)async throws  -> ExchangeStatus {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_schedule_cancel_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterOptionUInt64.lower(timeMs)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeExchangeStatus.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func setClockDriftCallback(callback: ClockDriftCallback) {try! rustCall() {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_set_clock_drift_callback(self.uniffiClonePointer(),
+        FfiConverterCallbackInterfaceClockDriftCallback.lower(callback),$0
+    )
+}
+}
+    
+open func setDefaultSlippage(fraction: Double)throws  {try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_set_default_slippage(self.uniffiClonePointer(),
+        FfiConverterDouble.lower(fraction),$0
+    )
+}
+}
+    
+open func setReferrer(code: String)throws  -> ExchangeStatus {
+    return try  FfiConverterTypeExchangeStatus.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_set_referrer(self.uniffiClonePointer(),
+        FfiConverterString.lower(code),$0
+    )
+})
+}
+    
+open func setReferrerAsync(code: String)async throws  -> ExchangeStatus {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_set_referrer_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(code)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeExchangeStatus.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func signMessage(message: String)throws  -> String {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_sign_message(self.uniffiClonePointer(),
+        FfiConverterString.lower(message),$0
+    )
+})
+}
+    
+open func signOrder(order: OrderRequest)throws  -> String {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_sign_order(self.uniffiClonePointer(),
+        FfiConverterTypeOrderRequest.lower(order),$0
+    )
+})
+}
+    
+open func signOrderAsync(order: OrderRequest)async throws  -> String {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_sign_order_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterTypeOrderRequest.lower(order)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterString.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func spotTransfer(token: String, amount: Double, destination: String)throws  -> ExchangeStatus {
+    return try  FfiConverterTypeExchangeStatus.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_spot_transfer(self.uniffiClonePointer(),
+        FfiConverterString.lower(token),
+        FfiConverterDouble.lower(amount),
+        FfiConverterString.lower(destination),$0
+    )
+})
+}
+    
+open func spotTransferAsync(token: String, amount: Double, destination: String)async throws  -> ExchangeStatus {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_spot_transfer_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(token),FfiConverterDouble.lower(amount),FfiConverterString.lower(destination)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeExchangeStatus.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func updateIsolatedMargin(asset: String, amount: Double)throws  -> ExchangeStatus {
+    return try  FfiConverterTypeExchangeStatus.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_update_isolated_margin(self.uniffiClonePointer(),
+        FfiConverterString.lower(asset),
+        FfiConverterDouble.lower(amount),$0
+    )
+})
+}
+    
+open func updateIsolatedMarginAsync(asset: String, amount: Double)async throws  -> ExchangeStatus {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_update_isolated_margin_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(asset),FfiConverterDouble.lower(amount)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeExchangeStatus.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func updateLeverage(asset: String, leverage: UInt32, isCross: Bool)throws  -> ExchangeStatus {
+    return try  FfiConverterTypeExchangeStatus.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_update_leverage(self.uniffiClonePointer(),
+        FfiConverterString.lower(asset),
+        FfiConverterUInt32.lower(leverage),
+        FfiConverterBool.lower(isCross),$0
+    )
+})
+}
+    
+open func updateLeverageAsync(asset: String, leverage: UInt32, isCross: Bool)async throws  -> ExchangeStatus {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_update_leverage_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(asset),FfiConverterUInt32.lower(leverage),FfiConverterBool.lower(isCross)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeExchangeStatus.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func usdTransfer(amount: Double, destination: String)throws  -> ExchangeStatus {
+    return try  FfiConverterTypeExchangeStatus.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_usd_transfer(self.uniffiClonePointer(),
+        FfiConverterDouble.lower(amount),
+        FfiConverterString.lower(destination),$0
+    )
+})
+}
+    
+open func usdTransferAsync(amount: Double, destination: String)async throws  -> ExchangeStatus {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_usd_transfer_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterDouble.lower(amount),FfiConverterString.lower(destination)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeExchangeStatus.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func validateOrder(order: OrderRequest)throws  {try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_validate_order(self.uniffiClonePointer(),
+        FfiConverterTypeOrderRequest.lower(order),$0
+    )
+}
+}
+    
+open func validateOrderAsync(order: OrderRequest)async throws  {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_validate_order_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterTypeOrderRequest.lower(order)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_void,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_void,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_void,
+            liftFunc: { $0 },
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func verifyAccountExists()throws  -> Bool {
+    return try  FfiConverterBool.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_verify_account_exists(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func verifyAccountExistsAsync()async throws  -> Bool {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_verify_account_exists_async(
+                    self.uniffiClonePointer()
+                    
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_i8,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_i8,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_i8,
+            liftFunc: FfiConverterBool.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func waitForOrder(response: OrderResponse, timeoutMs: UInt64)throws  -> OrderResponse {
+    return try  FfiConverterTypeOrderResponse.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_wait_for_order(self.uniffiClonePointer(),
+        FfiConverterTypeOrderResponse.lower(response),
+        FfiConverterUInt64.lower(timeoutMs),$0
+    )
+})
+}
+    
+open func waitForOrderAsync(response: OrderResponse, timeoutMs: UInt64)async throws  -> OrderResponse {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_wait_for_order_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterTypeOrderResponse.lower(response),FfiConverterUInt64.lower(timeoutMs)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeOrderResponse.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func withdrawFromBridge(amount: Double, destination: String)throws  -> ExchangeStatus {
+    return try  FfiConverterTypeExchangeStatus.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_withdraw_from_bridge(self.uniffiClonePointer(),
+        FfiConverterDouble.lower(amount),
+        FfiConverterString.lower(destination),$0
+    )
+})
+}
+    
+open func withdrawFromBridgeAsync(amount: Double, destination: String)async throws  -> ExchangeStatus {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidexchange_withdraw_from_bridge_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterDouble.lower(amount),FfiConverterString.lower(destination)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeExchangeStatus.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeHyperliquidExchange: FfiConverter {
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = HyperliquidExchange
+
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> HyperliquidExchange {
+        return HyperliquidExchange(unsafeFromRawPointer: pointer)
+    }
+
+    public static func lower(_ value: HyperliquidExchange) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HyperliquidExchange {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
+        }
+        return try lift(ptr!)
+    }
+
+    public static func write(_ value: HyperliquidExchange, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
+    }
+}
+
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHyperliquidExchange_lift(_ pointer: UnsafeMutableRawPointer) throws -> HyperliquidExchange {
+    return try FfiConverterTypeHyperliquidExchange.lift(pointer)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHyperliquidExchange_lower(_ value: HyperliquidExchange) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeHyperliquidExchange.lower(value)
+}
+
+
+
+
+public protocol HyperliquidInfoProtocol : AnyObject {
+    
+    func cancellable(token: CancellationToken)  -> HyperliquidInfo
+    
+    func getAllMids() throws  -> [String: String]
+    
+    func getAllMidsAsync() async throws  -> [String: String]
+    
+    func getAllMidsF64() throws  -> [String: Double]
+    
+    func getAllMidsF64Async() async throws  -> [String: Double]
+    
+    func getAssetContexts() throws  -> [AssetContext]
+    
+    func getAssetContextsAsync() async throws  -> [AssetContext]
+    
+    func getAssetNames(includeSpot: Bool) throws  -> [String]
+    
+    func getAssetNamesAsync(includeSpot: Bool) async throws  -> [String]
+    
+    func getBaseUrl()  -> BaseUrl
+    
+    func getCandles(asset: String, interval: String, startTime: UInt64, endTime: UInt64) throws  -> [Candle]
+    
+    func getCandlesAsync(asset: String, interval: String, startTime: UInt64, endTime: UInt64) async throws  -> [Candle]
+    
+    func getClearinghouseState(address: String) throws  -> UserState
+    
+    func getClearinghouseStateAsync(address: String) async throws  -> UserState
+    
+    func getFrontendOpenOrders(address: String) throws  -> [FrontendOrder]
+    
+    func getFrontendOpenOrdersAsync(address: String) async throws  -> [FrontendOrder]
+    
+    func getFundingHistory(asset: String, startTime: UInt64, endTime: UInt64?) throws  -> [FundingEntry]
+    
+    func getFundingHistoryAsync(asset: String, startTime: UInt64, endTime: UInt64?) async throws  -> [FundingEntry]
+    
+    func getHistoricalOrders(address: String) throws  -> [HistoricalOrder]
+    
+    func getHistoricalOrdersAsync(address: String) async throws  -> [HistoricalOrder]
+    
+    func getL2Book(asset: String, nLevels: UInt32?, mantissa: UInt32?) throws  -> L2Book
+    
+    func getL2BookAsync(asset: String, nLevels: UInt32?, mantissa: UInt32?) async throws  -> L2Book
+    
+    func getLatestCandles(asset: String, interval: String, count: UInt32) throws  -> [Candle]
+    
+    func getLatestCandlesAsync(asset: String, interval: String, count: UInt32) async throws  -> [Candle]
+    
+    func getLedgerUpdates(address: String, startTime: UInt64, endTime: UInt64?) throws  -> [LedgerUpdate]
+    
+    func getLedgerUpdatesAsync(address: String, startTime: UInt64, endTime: UInt64?) async throws  -> [LedgerUpdate]
+    
+    func getMaxBuilderFee(address: String, builder: String) throws  -> UInt32
+    
+    func getMaxBuilderFeeAsync(address: String, builder: String) async throws  -> UInt32
+    
+    func getMeta() throws  -> [AssetMeta]
+    
+    func getMetaAsync() async throws  -> [AssetMeta]
+    
+    func getMetaWithContexts() throws  -> [AssetMetaWithContext]
+    
+    func getMetaWithContextsAsync() async throws  -> [AssetMetaWithContext]
+    
+    func getMid(asset: String) throws  -> Double
+    
+    func getMidAsync(asset: String) async throws  -> Double
+    
+    func getOpenOrders(address: String, asset: String?) throws  -> [OpenOrder]
+    
+    func getOpenOrdersAsync(address: String, asset: String?) async throws  -> [OpenOrder]
+    
+    func getOrderStatus(address: String, oid: UInt64) throws  -> OrderStatus
+    
+    func getOrderStatusAsync(address: String, oid: UInt64) async throws  -> OrderStatus
+    
+    func getPerpDeployAuction() throws  -> DeployAuctionStatus
+    
+    func getPerpDeployAuctionAsync() async throws  -> DeployAuctionStatus
+    
+    func getPerpState(address: String) throws  -> UserState
+    
+    func getPerpStateAsync(address: String) async throws  -> UserState
+    
+    func getPredictedFundings() throws  -> [PredictedFunding]
+    
+    func getPredictedFundingsAsync() async throws  -> [PredictedFunding]
+    
+    func getRateLimit(address: String) throws  -> RateLimitStatus
+    
+    func getRateLimitAsync(address: String) async throws  -> RateLimitStatus
+    
+    func getReferralState(address: String) throws  -> ReferralState
+    
+    func getReferralStateAsync(address: String) async throws  -> ReferralState
+    
+    func getServerTime() throws  -> UInt64
+    
+    func getServerTimeAsync() async throws  -> UInt64
+    
+    func getSpotBalances(address: String) throws  -> [UserBalance]
+    
+    func getSpotBalancesAsync(address: String) async throws  -> [UserBalance]
+    
+    func getSpotMeta() throws  -> [SpotAssetMeta]
+    
+    func getSpotMetaAsync() async throws  -> [SpotAssetMeta]
+    
+    func getSpotState(address: String) throws  -> SpotState
+    
+    func getSpotStateAsync(address: String) async throws  -> SpotState
+    
+    func getSubAccounts(masterAddress: String) throws  -> [SubAccount]
+    
+    func getSubAccountsAsync(masterAddress: String) async throws  -> [SubAccount]
+    
+    func getUserBalances(address: String) throws  -> [UserBalance]
+    
+    func getUserBalancesAsync(address: String) async throws  -> [UserBalance]
+    
+    func getUserFills(address: String) throws  -> [Fill]
+    
+    func getUserFillsAsync(address: String) async throws  -> [Fill]
+    
+    func getUserFillsByTime(address: String, startTime: UInt64, endTime: UInt64?) throws  -> [Fill]
+    
+    func getUserFillsByTimeAsync(address: String, startTime: UInt64, endTime: UInt64?) async throws  -> [Fill]
+    
+    func getUserFunding(address: String, startTime: UInt64, endTime: UInt64?) throws  -> [UserFunding]
+    
+    func getUserFundingAsync(address: String, startTime: UInt64, endTime: UInt64?) async throws  -> [UserFunding]
+    
+    func getUserOverview(address: String) throws  -> UserOverview
+    
+    func getUserOverviewAsync(address: String) async throws  -> UserOverview
+    
+    /**
+     * Deprecated: use get_clearinghouse_state, which returns the same perp
+     * account state.
+     */
+    func getUserState(address: String) throws  -> UserState
+    
+    /**
+     * Deprecated: use get_clearinghouse_state_async.
+     */
+    func getUserStateAsync(address: String) async throws  -> UserState
+    
+    func getUserStates(addresses: [String]) throws  -> [UserStateResult]
+    
+    func getUserStatesAsync(addresses: [String]) async throws  -> [UserStateResult]
+    
+    func infoRaw(requestJson: String) throws  -> String
+    
+    func infoRawAsync(requestJson: String) async throws  -> String
+    
+    func ping() throws  -> UInt64
+    
+    func pingAsync() async throws  -> UInt64
+    
+    func refreshMeta() throws 
+    
+    func refreshMetaAsync() async throws 
+    
+    func roundPrice(asset: String, price: Double) throws  -> Double
+    
+    func roundPriceAsync(asset: String, price: Double) async throws  -> Double
+    
+    func roundSize(asset: String, size: Double) throws  -> Double
+    
+    func roundSizeAsync(asset: String, size: Double) async throws  -> Double
+    
+    func setConnectionStatusCallback(callback: ConnectionStatusCallback) 
+    
+    func shutdown() throws 
+    
+    func simulatePosition(asset: String, side: OrderSide, size: Double, entryPrice: Double, leverage: UInt32) throws  -> PositionSimulation
+    
+    func simulatePositionAsync(asset: String, side: OrderSide, size: Double, entryPrice: Double, leverage: UInt32) async throws  -> PositionSimulation
+    
+    func submitSigned(payload: String) throws  -> OrderResponse
+    
+    func submitSignedAsync(payload: String) async throws  -> OrderResponse
+    
+    func subscribeAllMids(callback: MidsCallback) throws  -> UInt32
+    
+    func subscribeL2Book(asset: String, callback: L2BookCallback) throws  -> UInt32
+    
+    func subscribeUserEvents(address: String, callback: UserEventCallback) throws  -> UInt32
+    
+    func unsubscribe(subscriptionId: UInt32) throws 
+    
+    func verifyAccountExists(address: String) throws  -> Bool
+    
+    func verifyAccountExistsAsync(address: String) async throws  -> Bool
+    
+}
+
+open class HyperliquidInfo:
+    HyperliquidInfoProtocol {
+    fileprivate let pointer: UnsafeMutableRawPointer!
+
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoPointer {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_hyperliquid_swift_fn_clone_hyperliquidinfo(self.pointer, $0) }
+    }
+    // No primary constructor declared for this class.
+
+    deinit {
+        guard let pointer = pointer else {
+            return
+        }
+
+        try! rustCall { uniffi_hyperliquid_swift_fn_free_hyperliquidinfo(pointer, $0) }
+    }
+
+    
+
+    
+open func cancellable(token: CancellationToken) -> HyperliquidInfo {
+    return try!  FfiConverterTypeHyperliquidInfo.lift(try! rustCall() {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_cancellable(self.uniffiClonePointer(),
+        FfiConverterTypeCancellationToken.lower(token),$0
+    )
+})
+}
+    
+open func getAllMids()throws  -> [String: String] {
+    return try  FfiConverterDictionaryStringString.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_all_mids(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func getAllMidsAsync()async throws  -> [String: String] {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_all_mids_async(
+                    self.uniffiClonePointer()
+                    
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterDictionaryStringString.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func getAllMidsF64()throws  -> [String: Double] {
+    return try  FfiConverterDictionaryStringDouble.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_all_mids_f64(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func getAllMidsF64Async()async throws  -> [String: Double] {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_all_mids_f64_async(
+                    self.uniffiClonePointer()
+                    
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterDictionaryStringDouble.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func getAssetContexts()throws  -> [AssetContext] {
+    return try  FfiConverterSequenceTypeAssetContext.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_asset_contexts(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func getAssetContextsAsync()async throws  -> [AssetContext] {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_asset_contexts_async(
+                    self.uniffiClonePointer()
+                    
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceTypeAssetContext.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func getAssetNames(includeSpot: Bool = false)throws  -> [String] {
+    return try  FfiConverterSequenceString.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_asset_names(self.uniffiClonePointer(),
+        FfiConverterBool.lower(includeSpot),$0
+    )
+})
+}
+    
+open func getAssetNamesAsync(includeSpot: Bool = false)async throws  -> [String] {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_asset_names_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterBool.lower(includeSpot)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceString.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func getBaseUrl() -> BaseUrl {
+    return try!  FfiConverterTypeBaseUrl.lift(try! rustCall() {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_base_url(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func getCandles(asset: String, interval: String, startTime: UInt64, endTime: UInt64)throws  -> [Candle] {
+    return try  FfiConverterSequenceTypeCandle.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_candles(self.uniffiClonePointer(),
+        FfiConverterString.lower(asset),
+        FfiConverterString.lower(interval),
+        FfiConverterUInt64.lower(startTime),
+        FfiConverterUInt64.lower(endTime),$0
+    )
+})
+}
+    
+open func getCandlesAsync(asset: String, interval: String, startTime: UInt64, endTime: UInt64)async throws  -> [Candle] {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_candles_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(asset),FfiConverterString.lower(interval),FfiConverterUInt64.lower(startTime),FfiConverterUInt64.lower(endTime)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceTypeCandle.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func getClearinghouseState(address: String)throws  -> UserState {
+    return try  FfiConverterTypeUserState.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_clearinghouse_state(self.uniffiClonePointer(),
+        FfiConverterString.lower(address),$0
+    )
+})
+}
+    
+open func getClearinghouseStateAsync(address: String)async throws  -> UserState {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_clearinghouse_state_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(address)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeUserState.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func getFrontendOpenOrders(address: String)throws  -> [FrontendOrder] {
+    return try  FfiConverterSequenceTypeFrontendOrder.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_frontend_open_orders(self.uniffiClonePointer(),
+        FfiConverterString.lower(address),$0
+    )
+})
+}
+    
+open func getFrontendOpenOrdersAsync(address: String)async throws  -> [FrontendOrder] {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_frontend_open_orders_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(address)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceTypeFrontendOrder.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func getFundingHistory(asset: String, startTime: UInt64, endTime: UInt64?)throws  -> [FundingEntry] {
+    return try  FfiConverterSequenceTypeFundingEntry.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_funding_history(self.uniffiClonePointer(),
+        FfiConverterString.lower(asset),
+        FfiConverterUInt64.lower(startTime),
+        FfiConverterOptionUInt64.lower(endTime),$0
+    )
+})
+}
+    
+open func getFundingHistoryAsync(asset: String, startTime: UInt64, endTime: UInt64?)async throws  -> [FundingEntry] {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_funding_history_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(asset),FfiConverterUInt64.lower(startTime),FfiConverterOptionUInt64.lower(endTime)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceTypeFundingEntry.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func getHistoricalOrders(address: String)throws  -> [HistoricalOrder] {
+    return try  FfiConverterSequenceTypeHistoricalOrder.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_historical_orders(self.uniffiClonePointer(),
+        FfiConverterString.lower(address),$0
+    )
+})
+}
+    
+open func getHistoricalOrdersAsync(address: String)async throws  -> [HistoricalOrder] {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_historical_orders_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(address)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceTypeHistoricalOrder.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func getL2Book(asset: String, nLevels: UInt32? = nil, mantissa: UInt32? = nil)throws  -> L2Book {
+    return try  FfiConverterTypeL2Book.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_l2_book(self.uniffiClonePointer(),
+        FfiConverterString.lower(asset),
+        FfiConverterOptionUInt32.lower(nLevels),
+        FfiConverterOptionUInt32.lower(mantissa),$0
+    )
+})
+}
+    
+open func getL2BookAsync(asset: String, nLevels: UInt32? = nil, mantissa: UInt32? = nil)async throws  -> L2Book {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_l2_book_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(asset),FfiConverterOptionUInt32.lower(nLevels),FfiConverterOptionUInt32.lower(mantissa)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeL2Book.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func getLatestCandles(asset: String, interval: String, count: UInt32)throws  -> [Candle] {
+    return try  FfiConverterSequenceTypeCandle.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_latest_candles(self.uniffiClonePointer(),
+        FfiConverterString.lower(asset),
+        FfiConverterString.lower(interval),
+        FfiConverterUInt32.lower(count),$0
+    )
+})
+}
+    
+open func getLatestCandlesAsync(asset: String, interval: String, count: UInt32)async throws  -> [Candle] {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_latest_candles_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(asset),FfiConverterString.lower(interval),FfiConverterUInt32.lower(count)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceTypeCandle.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func getLedgerUpdates(address: String, startTime: UInt64, endTime: UInt64?)throws  -> [LedgerUpdate] {
+    return try  FfiConverterSequenceTypeLedgerUpdate.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_ledger_updates(self.uniffiClonePointer(),
+        FfiConverterString.lower(address),
+        FfiConverterUInt64.lower(startTime),
+        FfiConverterOptionUInt64.lower(endTime),$0
+    )
+})
+}
+    
+open func getLedgerUpdatesAsync(address: String, startTime: UInt64, endTime: UInt64?)async throws  -> [LedgerUpdate] {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_ledger_updates_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(address),FfiConverterUInt64.lower(startTime),FfiConverterOptionUInt64.lower(endTime)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceTypeLedgerUpdate.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func getMaxBuilderFee(address: String, builder: String)throws  -> UInt32 {
+    return try  FfiConverterUInt32.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_max_builder_fee(self.uniffiClonePointer(),
+        FfiConverterString.lower(address),
+        FfiConverterString.lower(builder),$0
+    )
+})
+}
+    
+open func getMaxBuilderFeeAsync(address: String, builder: String)async throws  -> UInt32 {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_max_builder_fee_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(address),FfiConverterString.lower(builder)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_u32,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_u32,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_u32,
+            liftFunc: FfiConverterUInt32.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func getMeta()throws  -> [AssetMeta] {
+    return try  FfiConverterSequenceTypeAssetMeta.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_meta(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func getMetaAsync()async throws  -> [AssetMeta] {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_meta_async(
+                    self.uniffiClonePointer()
+                    
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceTypeAssetMeta.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func getMetaWithContexts()throws  -> [AssetMetaWithContext] {
+    return try  FfiConverterSequenceTypeAssetMetaWithContext.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_meta_with_contexts(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func getMetaWithContextsAsync()async throws  -> [AssetMetaWithContext] {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_meta_with_contexts_async(
+                    self.uniffiClonePointer()
+                    
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceTypeAssetMetaWithContext.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func getMid(asset: String)throws  -> Double {
+    return try  FfiConverterDouble.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_mid(self.uniffiClonePointer(),
+        FfiConverterString.lower(asset),$0
+    )
+})
+}
+    
+open func getMidAsync(asset: String)async throws  -> Double {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_mid_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(asset)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_f64,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_f64,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_f64,
+            liftFunc: FfiConverterDouble.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func getOpenOrders(address: String, asset: String? = nil)throws  -> [OpenOrder] {
+    return try  FfiConverterSequenceTypeOpenOrder.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_open_orders(self.uniffiClonePointer(),
+        FfiConverterString.lower(address),
+        FfiConverterOptionString.lower(asset),$0
+    )
+})
+}
+    
+open func getOpenOrdersAsync(address: String, asset: String? = nil)async throws  -> [OpenOrder] {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_open_orders_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(address),FfiConverterOptionString.lower(asset)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceTypeOpenOrder.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func getOrderStatus(address: String, oid: UInt64)throws  -> OrderStatus {
+    return try  FfiConverterTypeOrderStatus.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_order_status(self.uniffiClonePointer(),
+        FfiConverterString.lower(address),
+        FfiConverterUInt64.lower(oid),$0
+    )
+})
+}
+    
+open func getOrderStatusAsync(address: String, oid: UInt64)async throws  -> OrderStatus {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_order_status_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(address),FfiConverterUInt64.lower(oid)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeOrderStatus.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func getPerpDeployAuction()throws  -> DeployAuctionStatus {
+    return try  FfiConverterTypeDeployAuctionStatus.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_perp_deploy_auction(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func getPerpDeployAuctionAsync()async throws  -> DeployAuctionStatus {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_perp_deploy_auction_async(
+                    self.uniffiClonePointer()
+                    
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeDeployAuctionStatus.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func getPerpState(address: String)throws  -> UserState {
+    return try  FfiConverterTypeUserState.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_perp_state(self.uniffiClonePointer(),
+        FfiConverterString.lower(address),$0
+    )
+})
+}
+    
+open func getPerpStateAsync(address: String)async throws  -> UserState {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_perp_state_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(address)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeUserState.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func getPredictedFundings()throws  -> [PredictedFunding] {
+    return try  FfiConverterSequenceTypePredictedFunding.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_predicted_fundings(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func getPredictedFundingsAsync()async throws  -> [PredictedFunding] {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_predicted_fundings_async(
+                    self.uniffiClonePointer()
+                    
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceTypePredictedFunding.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func getRateLimit(address: String)throws  -> RateLimitStatus {
+    return try  FfiConverterTypeRateLimitStatus.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_rate_limit(self.uniffiClonePointer(),
+        FfiConverterString.lower(address),$0
+    )
+})
+}
+    
+open func getRateLimitAsync(address: String)async throws  -> RateLimitStatus {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_rate_limit_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(address)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeRateLimitStatus.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func getReferralState(address: String)throws  -> ReferralState {
+    return try  FfiConverterTypeReferralState.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_referral_state(self.uniffiClonePointer(),
+        FfiConverterString.lower(address),$0
+    )
+})
+}
+    
+open func getReferralStateAsync(address: String)async throws  -> ReferralState {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_referral_state_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(address)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeReferralState.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func getServerTime()throws  -> UInt64 {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_server_time(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func getServerTimeAsync()async throws  -> UInt64 {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_server_time_async(
+                    self.uniffiClonePointer()
+                    
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_u64,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_u64,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_u64,
+            liftFunc: FfiConverterUInt64.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func getSpotBalances(address: String)throws  -> [UserBalance] {
+    return try  FfiConverterSequenceTypeUserBalance.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_spot_balances(self.uniffiClonePointer(),
+        FfiConverterString.lower(address),$0
+    )
+})
+}
+    
+open func getSpotBalancesAsync(address: String)async throws  -> [UserBalance] {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_spot_balances_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(address)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceTypeUserBalance.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func getSpotMeta()throws  -> [SpotAssetMeta] {
+    return try  FfiConverterSequenceTypeSpotAssetMeta.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_spot_meta(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func getSpotMetaAsync()async throws  -> [SpotAssetMeta] {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_spot_meta_async(
+                    self.uniffiClonePointer()
+                    
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceTypeSpotAssetMeta.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func getSpotState(address: String)throws  -> SpotState {
+    return try  FfiConverterTypeSpotState.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_spot_state(self.uniffiClonePointer(),
+        FfiConverterString.lower(address),$0
+    )
+})
+}
+    
+open func getSpotStateAsync(address: String)async throws  -> SpotState {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_spot_state_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(address)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeSpotState.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func getSubAccounts(masterAddress: String)throws  -> [SubAccount] {
+    return try  FfiConverterSequenceTypeSubAccount.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_sub_accounts(self.uniffiClonePointer(),
+        FfiConverterString.lower(masterAddress),$0
+    )
+})
+}
+    
+open func getSubAccountsAsync(masterAddress: String)async throws  -> [SubAccount] {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_sub_accounts_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(masterAddress)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceTypeSubAccount.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func getUserBalances(address: String)throws  -> [UserBalance] {
+    return try  FfiConverterSequenceTypeUserBalance.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_user_balances(self.uniffiClonePointer(),
+        FfiConverterString.lower(address),$0
+    )
+})
+}
+    
+open func getUserBalancesAsync(address: String)async throws  -> [UserBalance] {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_user_balances_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(address)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceTypeUserBalance.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func getUserFills(address: String)throws  -> [Fill] {
+    return try  FfiConverterSequenceTypeFill.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_user_fills(self.uniffiClonePointer(),
+        FfiConverterString.lower(address),$0
+    )
+})
+}
+    
+open func getUserFillsAsync(address: String)async throws  -> [Fill] {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_user_fills_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(address)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceTypeFill.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func getUserFillsByTime(address: String, startTime: UInt64, endTime: UInt64?)throws  -> [Fill] {
+    return try  FfiConverterSequenceTypeFill.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_user_fills_by_time(self.uniffiClonePointer(),
+        FfiConverterString.lower(address),
+        FfiConverterUInt64.lower(startTime),
+        FfiConverterOptionUInt64.lower(endTime),$0
+    )
+})
+}
+    
+open func getUserFillsByTimeAsync(address: String, startTime: UInt64, endTime: UInt64?)async throws  -> [Fill] {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_user_fills_by_time_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(address),FfiConverterUInt64.lower(startTime),FfiConverterOptionUInt64.lower(endTime)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceTypeFill.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func getUserFunding(address: String, startTime: UInt64, endTime: UInt64?)throws  -> [UserFunding] {
+    return try  FfiConverterSequenceTypeUserFunding.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_user_funding(self.uniffiClonePointer(),
+        FfiConverterString.lower(address),
+        FfiConverterUInt64.lower(startTime),
+        FfiConverterOptionUInt64.lower(endTime),$0
+    )
+})
+}
+    
+open func getUserFundingAsync(address: String, startTime: UInt64, endTime: UInt64?)async throws  -> [UserFunding] {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_user_funding_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(address),FfiConverterUInt64.lower(startTime),FfiConverterOptionUInt64.lower(endTime)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceTypeUserFunding.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func getUserOverview(address: String)throws  -> UserOverview {
+    return try  FfiConverterTypeUserOverview.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_user_overview(self.uniffiClonePointer(),
+        FfiConverterString.lower(address),$0
+    )
+})
+}
+    
+open func getUserOverviewAsync(address: String)async throws  -> UserOverview {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_user_overview_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(address)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeUserOverview.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+    /**
+     * Deprecated: use get_clearinghouse_state, which returns the same perp
+     * account state.
+     */
+open func getUserState(address: String)throws  -> UserState {
+    return try  FfiConverterTypeUserState.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_user_state(self.uniffiClonePointer(),
+        FfiConverterString.lower(address),$0
+    )
+})
+}
+    
+    /**
+     * Deprecated: use get_clearinghouse_state_async.
+     */
+open func getUserStateAsync(address: String)async throws  -> UserState {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_user_state_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(address)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeUserState.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func getUserStates(addresses: [String])throws  -> [UserStateResult] {
+    return try  FfiConverterSequenceTypeUserStateResult.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_user_states(self.uniffiClonePointer(),
+        FfiConverterSequenceString.lower(addresses),$0
+    )
+})
+}
+    
+open func getUserStatesAsync(addresses: [String])async throws  -> [UserStateResult] {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_get_user_states_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterSequenceString.lower(addresses)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterSequenceTypeUserStateResult.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func infoRaw(requestJson: String)throws  -> String {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_info_raw(self.uniffiClonePointer(),
+        FfiConverterString.lower(requestJson),$0
+    )
+})
+}
+    
+open func infoRawAsync(requestJson: String)async throws  -> String {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_info_raw_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(requestJson)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterString.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func ping()throws  -> UInt64 {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_ping(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func pingAsync()async throws  -> UInt64 {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_ping_async(
+                    self.uniffiClonePointer()
+                    
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_u64,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_u64,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_u64,
+            liftFunc: FfiConverterUInt64.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func refreshMeta()throws  {try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_refresh_meta(self.uniffiClonePointer(),$0
+    )
+}
+}
+    
+open func refreshMetaAsync()async throws  {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_refresh_meta_async(
+                    self.uniffiClonePointer()
+                    
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_void,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_void,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_void,
+            liftFunc: { $0 },
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func roundPrice(asset: String, price: Double)throws  -> Double {
+    return try  FfiConverterDouble.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_round_price(self.uniffiClonePointer(),
+        FfiConverterString.lower(asset),
+        FfiConverterDouble.lower(price),$0
+    )
+})
+}
+    
+open func roundPriceAsync(asset: String, price: Double)async throws  -> Double {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_round_price_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(asset),FfiConverterDouble.lower(price)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_f64,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_f64,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_f64,
+            liftFunc: FfiConverterDouble.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func roundSize(asset: String, size: Double)throws  -> Double {
+    return try  FfiConverterDouble.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_round_size(self.uniffiClonePointer(),
+        FfiConverterString.lower(asset),
+        FfiConverterDouble.lower(size),$0
+    )
+})
+}
+    
+open func roundSizeAsync(asset: String, size: Double)async throws  -> Double {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_round_size_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(asset),FfiConverterDouble.lower(size)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_f64,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_f64,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_f64,
+            liftFunc: FfiConverterDouble.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func setConnectionStatusCallback(callback: ConnectionStatusCallback) {try! rustCall() {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_set_connection_status_callback(self.uniffiClonePointer(),
+        FfiConverterCallbackInterfaceConnectionStatusCallback.lower(callback),$0
+    )
+}
+}
+    
+open func shutdown()throws  {try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_shutdown(self.uniffiClonePointer(),$0
+    )
+}
+}
+    
+open func simulatePosition(asset: String, side: OrderSide, size: Double, entryPrice: Double, leverage: UInt32)throws  -> PositionSimulation {
+    return try  FfiConverterTypePositionSimulation.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_simulate_position(self.uniffiClonePointer(),
+        FfiConverterString.lower(asset),
+        FfiConverterTypeOrderSide.lower(side),
+        FfiConverterDouble.lower(size),
+        FfiConverterDouble.lower(entryPrice),
+        FfiConverterUInt32.lower(leverage),$0
+    )
+})
+}
+    
+open func simulatePositionAsync(asset: String, side: OrderSide, size: Double, entryPrice: Double, leverage: UInt32)async throws  -> PositionSimulation {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_simulate_position_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(asset),FfiConverterTypeOrderSide.lower(side),FfiConverterDouble.lower(size),FfiConverterDouble.lower(entryPrice),FfiConverterUInt32.lower(leverage)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypePositionSimulation.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func submitSigned(payload: String)throws  -> OrderResponse {
+    return try  FfiConverterTypeOrderResponse.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_submit_signed(self.uniffiClonePointer(),
+        FfiConverterString.lower(payload),$0
+    )
+})
+}
+    
+open func submitSignedAsync(payload: String)async throws  -> OrderResponse {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_submit_signed_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(payload)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_rust_buffer,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_rust_buffer,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeOrderResponse.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+open func subscribeAllMids(callback: MidsCallback)throws  -> UInt32 {
+    return try  FfiConverterUInt32.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_subscribe_all_mids(self.uniffiClonePointer(),
+        FfiConverterCallbackInterfaceMidsCallback.lower(callback),$0
+    )
+})
+}
+    
+open func subscribeL2Book(asset: String, callback: L2BookCallback)throws  -> UInt32 {
+    return try  FfiConverterUInt32.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_subscribe_l2_book(self.uniffiClonePointer(),
+        FfiConverterString.lower(asset),
+        FfiConverterCallbackInterfaceL2BookCallback.lower(callback),$0
+    )
+})
+}
+    
+open func subscribeUserEvents(address: String, callback: UserEventCallback)throws  -> UInt32 {
+    return try  FfiConverterUInt32.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_subscribe_user_events(self.uniffiClonePointer(),
+        FfiConverterString.lower(address),
+        FfiConverterCallbackInterfaceUserEventCallback.lower(callback),$0
+    )
+})
+}
+    
+open func unsubscribe(subscriptionId: UInt32)throws  {try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_unsubscribe(self.uniffiClonePointer(),
+        FfiConverterUInt32.lower(subscriptionId),$0
+    )
+}
+}
+    
+open func verifyAccountExists(address: String)throws  -> Bool {
+    return try  FfiConverterBool.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_verify_account_exists(self.uniffiClonePointer(),
+        FfiConverterString.lower(address),$0
+    )
+})
+}
+    
+open func verifyAccountExistsAsync(address: String)async throws  -> Bool {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_hyperliquid_swift_fn_method_hyperliquidinfo_verify_account_exists_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(address)
+                )
+            },
+            pollFunc: ffi_hyperliquid_swift_rust_future_poll_i8,
+            completeFunc: ffi_hyperliquid_swift_rust_future_complete_i8,
+            freeFunc: ffi_hyperliquid_swift_rust_future_free_i8,
+            liftFunc: FfiConverterBool.lift,
+            errorHandler: FfiConverterTypeHyperliquidError.lift
+        )
+}
+    
+
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeHyperliquidInfo: FfiConverter {
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = HyperliquidInfo
+
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> HyperliquidInfo {
+        return HyperliquidInfo(unsafeFromRawPointer: pointer)
+    }
+
+    public static func lower(_ value: HyperliquidInfo) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HyperliquidInfo {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
+        }
+        return try lift(ptr!)
+    }
+
+    public static func write(_ value: HyperliquidInfo, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
+    }
+}
+
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHyperliquidInfo_lift(_ pointer: UnsafeMutableRawPointer) throws -> HyperliquidInfo {
+    return try FfiConverterTypeHyperliquidInfo.lift(pointer)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHyperliquidInfo_lower(_ value: HyperliquidInfo) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeHyperliquidInfo.lower(value)
+}
+
+
+
+
+public protocol OrderRequestBuilderProtocol : AnyObject {
+    
+    func build() throws  -> OrderRequest
+    
+    func cloid(cloid: String)  -> OrderRequestBuilder
+    
+    func limitPrice(price: Double)  -> OrderRequestBuilder
+    
+    func market()  -> OrderRequestBuilder
+    
+    func reduceOnly(reduceOnly: Bool)  -> OrderRequestBuilder
+    
+    func slippage(slippage: Double)  -> OrderRequestBuilder
+    
+    func tif(tif: TimeInForce)  -> OrderRequestBuilder
+    
+    func trigger(triggerPrice: Double, isMarket: Bool, tpsl: TpSl)  -> OrderRequestBuilder
+    
+}
+
+open class OrderRequestBuilder:
+    OrderRequestBuilderProtocol {
+    fileprivate let pointer: UnsafeMutableRawPointer!
+
+    /// Used to instantiate a [FFIObject] without an actual pointer, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoPointer {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+    required public init(unsafeFromRawPointer pointer: UnsafeMutableRawPointer) {
+        self.pointer = pointer
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noPointer: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing [Pointer] the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noPointer: NoPointer) {
+        self.pointer = nil
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiClonePointer() -> UnsafeMutableRawPointer {
+        return try! rustCall { uniffi_hyperliquid_swift_fn_clone_orderrequestbuilder(self.pointer, $0) }
+    }
+public convenience init(asset: String, side: OrderSide, size: Double) {
+    let pointer =
+        try! rustCall() {
+    uniffi_hyperliquid_swift_fn_constructor_orderrequestbuilder_new(
+        FfiConverterString.lower(asset),
+        FfiConverterTypeOrderSide.lower(side),
+        FfiConverterDouble.lower(size),$0
+    )
+}
+    self.init(unsafeFromRawPointer: pointer)
+}
+
+    deinit {
+        guard let pointer = pointer else {
+            return
+        }
+
+        try! rustCall { uniffi_hyperliquid_swift_fn_free_orderrequestbuilder(pointer, $0) }
+    }
+
+    
+
+    
+open func build()throws  -> OrderRequest {
+    return try  FfiConverterTypeOrderRequest.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_method_orderrequestbuilder_build(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func cloid(cloid: String) -> OrderRequestBuilder {
+    return try!  FfiConverterTypeOrderRequestBuilder.lift(try! rustCall() {
+    uniffi_hyperliquid_swift_fn_method_orderrequestbuilder_cloid(self.uniffiClonePointer(),
+        FfiConverterString.lower(cloid),$0
+    )
+})
+}
+    
+open func limitPrice(price: Double) -> OrderRequestBuilder {
+    return try!  FfiConverterTypeOrderRequestBuilder.lift(try! rustCall() {
+    uniffi_hyperliquid_swift_fn_method_orderrequestbuilder_limit_price(self.uniffiClonePointer(),
+        FfiConverterDouble.lower(price),$0
+    )
+})
+}
+    
+open func market() -> OrderRequestBuilder {
+    return try!  FfiConverterTypeOrderRequestBuilder.lift(try! rustCall() {
+    uniffi_hyperliquid_swift_fn_method_orderrequestbuilder_market(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func reduceOnly(reduceOnly: Bool) -> OrderRequestBuilder {
+    return try!  FfiConverterTypeOrderRequestBuilder.lift(try! rustCall() {
+    uniffi_hyperliquid_swift_fn_method_orderrequestbuilder_reduce_only(self.uniffiClonePointer(),
+        FfiConverterBool.lower(reduceOnly),$0
+    )
+})
+}
+    
+open func slippage(slippage: Double) -> OrderRequestBuilder {
+    return try!  FfiConverterTypeOrderRequestBuilder.lift(try! rustCall() {
+    uniffi_hyperliquid_swift_fn_method_orderrequestbuilder_slippage(self.uniffiClonePointer(),
+        FfiConverterDouble.lower(slippage),$0
+    )
+})
+}
+    
+open func tif(tif: TimeInForce) -> OrderRequestBuilder {
+    return try!  FfiConverterTypeOrderRequestBuilder.lift(try! rustCall() {
+    uniffi_hyperliquid_swift_fn_method_orderrequestbuilder_tif(self.uniffiClonePointer(),
+        FfiConverterTypeTimeInForce.lower(tif),$0
+    )
+})
+}
+    
+open func trigger(triggerPrice: Double, isMarket: Bool, tpsl: TpSl) -> OrderRequestBuilder {
+    return try!  FfiConverterTypeOrderRequestBuilder.lift(try! rustCall() {
+    uniffi_hyperliquid_swift_fn_method_orderrequestbuilder_trigger(self.uniffiClonePointer(),
+        FfiConverterDouble.lower(triggerPrice),
+        FfiConverterBool.lower(isMarket),
+        FfiConverterTypeTpSl.lower(tpsl),$0
+    )
+})
+}
+    
+
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeOrderRequestBuilder: FfiConverter {
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = OrderRequestBuilder
+
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> OrderRequestBuilder {
+        return OrderRequestBuilder(unsafeFromRawPointer: pointer)
+    }
+
+    public static func lower(_ value: OrderRequestBuilder) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> OrderRequestBuilder {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
+        }
+        return try lift(ptr!)
+    }
+
+    public static func write(_ value: OrderRequestBuilder, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
+    }
+}
+
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOrderRequestBuilder_lift(_ pointer: UnsafeMutableRawPointer) throws -> OrderRequestBuilder {
+    return try FfiConverterTypeOrderRequestBuilder.lift(pointer)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOrderRequestBuilder_lower(_ value: OrderRequestBuilder) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeOrderRequestBuilder.lower(value)
+}
+
+
+public struct AssetContext {
+    public var asset: String
+    public var markPrice: Double
+    public var oraclePrice: Double
+    public var midPrice: Double?
+    public var openInterest: Double
+    public var funding: Double
+    public var dayVolume: Double
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(asset: String, markPrice: Double, oraclePrice: Double, midPrice: Double?, openInterest: Double, funding: Double, dayVolume: Double) {
+        self.asset = asset
+        self.markPrice = markPrice
+        self.oraclePrice = oraclePrice
+        self.midPrice = midPrice
+        self.openInterest = openInterest
+        self.funding = funding
+        self.dayVolume = dayVolume
+    }
+}
+
+
+
+extension AssetContext: Equatable, Hashable {
+    public static func ==(lhs: AssetContext, rhs: AssetContext) -> Bool {
+        if lhs.asset != rhs.asset {
+            return false
+        }
+        if lhs.markPrice != rhs.markPrice {
+            return false
+        }
+        if lhs.oraclePrice != rhs.oraclePrice {
+            return false
+        }
+        if lhs.midPrice != rhs.midPrice {
+            return false
+        }
+        if lhs.openInterest != rhs.openInterest {
+            return false
+        }
+        if lhs.funding != rhs.funding {
+            return false
+        }
+        if lhs.dayVolume != rhs.dayVolume {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(asset)
+        hasher.combine(markPrice)
+        hasher.combine(oraclePrice)
+        hasher.combine(midPrice)
+        hasher.combine(openInterest)
+        hasher.combine(funding)
+        hasher.combine(dayVolume)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeAssetContext: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> AssetContext {
+        return
+            try AssetContext(
+                asset: FfiConverterString.read(from: &buf), 
+                markPrice: FfiConverterDouble.read(from: &buf), 
+                oraclePrice: FfiConverterDouble.read(from: &buf), 
+                midPrice: FfiConverterOptionDouble.read(from: &buf), 
+                openInterest: FfiConverterDouble.read(from: &buf), 
+                funding: FfiConverterDouble.read(from: &buf), 
+                dayVolume: FfiConverterDouble.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: AssetContext, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.asset, into: &buf)
+        FfiConverterDouble.write(value.markPrice, into: &buf)
+        FfiConverterDouble.write(value.oraclePrice, into: &buf)
+        FfiConverterOptionDouble.write(value.midPrice, into: &buf)
+        FfiConverterDouble.write(value.openInterest, into: &buf)
+        FfiConverterDouble.write(value.funding, into: &buf)
+        FfiConverterDouble.write(value.dayVolume, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeAssetContext_lift(_ buf: RustBuffer) throws -> AssetContext {
+    return try FfiConverterTypeAssetContext.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeAssetContext_lower(_ value: AssetContext) -> RustBuffer {
+    return FfiConverterTypeAssetContext.lower(value)
+}
+
+
+public struct AssetMeta {
+    public var name: String
+    public var szDecimals: UInt32
+    public var maxLeverage: UInt32
+    public var onlyIsolated: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(name: String, szDecimals: UInt32, maxLeverage: UInt32, onlyIsolated: Bool) {
+        self.name = name
+        self.szDecimals = szDecimals
+        self.maxLeverage = maxLeverage
+        self.onlyIsolated = onlyIsolated
+    }
+}
+
+
+
+extension AssetMeta: Equatable, Hashable {
+    public static func ==(lhs: AssetMeta, rhs: AssetMeta) -> Bool {
+        if lhs.name != rhs.name {
+            return false
+        }
+        if lhs.szDecimals != rhs.szDecimals {
+            return false
+        }
+        if lhs.maxLeverage != rhs.maxLeverage {
+            return false
+        }
+        if lhs.onlyIsolated != rhs.onlyIsolated {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(name)
+        hasher.combine(szDecimals)
+        hasher.combine(maxLeverage)
+        hasher.combine(onlyIsolated)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeAssetMeta: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> AssetMeta {
+        return
+            try AssetMeta(
+                name: FfiConverterString.read(from: &buf), 
+                szDecimals: FfiConverterUInt32.read(from: &buf), 
+                maxLeverage: FfiConverterUInt32.read(from: &buf), 
+                onlyIsolated: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: AssetMeta, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterUInt32.write(value.szDecimals, into: &buf)
+        FfiConverterUInt32.write(value.maxLeverage, into: &buf)
+        FfiConverterBool.write(value.onlyIsolated, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeAssetMeta_lift(_ buf: RustBuffer) throws -> AssetMeta {
+    return try FfiConverterTypeAssetMeta.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeAssetMeta_lower(_ value: AssetMeta) -> RustBuffer {
+    return FfiConverterTypeAssetMeta.lower(value)
+}
+
+
+public struct AssetMetaWithContext {
+    public var meta: AssetMeta
+    public var context: AssetContext
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(meta: AssetMeta, context: AssetContext) {
+        self.meta = meta
+        self.context = context
+    }
+}
+
+
+
+extension AssetMetaWithContext: Equatable, Hashable {
+    public static func ==(lhs: AssetMetaWithContext, rhs: AssetMetaWithContext) -> Bool {
+        if lhs.meta != rhs.meta {
+            return false
+        }
+        if lhs.context != rhs.context {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(meta)
+        hasher.combine(context)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeAssetMetaWithContext: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> AssetMetaWithContext {
+        return
+            try AssetMetaWithContext(
+                meta: FfiConverterTypeAssetMeta.read(from: &buf), 
+                context: FfiConverterTypeAssetContext.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: AssetMetaWithContext, into buf: inout [UInt8]) {
+        FfiConverterTypeAssetMeta.write(value.meta, into: &buf)
+        FfiConverterTypeAssetContext.write(value.context, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeAssetMetaWithContext_lift(_ buf: RustBuffer) throws -> AssetMetaWithContext {
+    return try FfiConverterTypeAssetMetaWithContext.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeAssetMetaWithContext_lower(_ value: AssetMetaWithContext) -> RustBuffer {
+    return FfiConverterTypeAssetMetaWithContext.lower(value)
+}
+
+
+public struct BookLevel {
+    public var price: Double
+    public var size: Double
+    public var numOrders: UInt32
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(price: Double, size: Double, numOrders: UInt32) {
+        self.price = price
+        self.size = size
+        self.numOrders = numOrders
+    }
+}
+
+
+
+extension BookLevel: Equatable, Hashable {
+    public static func ==(lhs: BookLevel, rhs: BookLevel) -> Bool {
+        if lhs.price != rhs.price {
+            return false
+        }
+        if lhs.size != rhs.size {
+            return false
+        }
+        if lhs.numOrders != rhs.numOrders {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(price)
+        hasher.combine(size)
+        hasher.combine(numOrders)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeBookLevel: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> BookLevel {
+        return
+            try BookLevel(
+                price: FfiConverterDouble.read(from: &buf), 
+                size: FfiConverterDouble.read(from: &buf), 
+                numOrders: FfiConverterUInt32.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: BookLevel, into buf: inout [UInt8]) {
+        FfiConverterDouble.write(value.price, into: &buf)
+        FfiConverterDouble.write(value.size, into: &buf)
+        FfiConverterUInt32.write(value.numOrders, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeBookLevel_lift(_ buf: RustBuffer) throws -> BookLevel {
+    return try FfiConverterTypeBookLevel.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeBookLevel_lower(_ value: BookLevel) -> RustBuffer {
+    return FfiConverterTypeBookLevel.lower(value)
+}
+
+
+public struct BuilderInfo {
+    public var address: String
+    public var feeTenthsBps: UInt32
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(address: String, feeTenthsBps: UInt32) {
+        self.address = address
+        self.feeTenthsBps = feeTenthsBps
+    }
+}
+
+
+
+extension BuilderInfo: Equatable, Hashable {
+    public static func ==(lhs: BuilderInfo, rhs: BuilderInfo) -> Bool {
+        if lhs.address != rhs.address {
+            return false
+        }
+        if lhs.feeTenthsBps != rhs.feeTenthsBps {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(address)
+        hasher.combine(feeTenthsBps)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeBuilderInfo: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> BuilderInfo {
+        return
+            try BuilderInfo(
+                address: FfiConverterString.read(from: &buf), 
+                feeTenthsBps: FfiConverterUInt32.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: BuilderInfo, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.address, into: &buf)
+        FfiConverterUInt32.write(value.feeTenthsBps, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeBuilderInfo_lift(_ buf: RustBuffer) throws -> BuilderInfo {
+    return try FfiConverterTypeBuilderInfo.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeBuilderInfo_lower(_ value: BuilderInfo) -> RustBuffer {
+    return FfiConverterTypeBuilderInfo.lower(value)
+}
+
+
+public struct CancelRequest {
+    public var asset: String
+    public var oid: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(asset: String, oid: UInt64) {
+        self.asset = asset
+        self.oid = oid
+    }
+}
+
+
+
+extension CancelRequest: Equatable, Hashable {
+    public static func ==(lhs: CancelRequest, rhs: CancelRequest) -> Bool {
+        if lhs.asset != rhs.asset {
+            return false
+        }
+        if lhs.oid != rhs.oid {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(asset)
+        hasher.combine(oid)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeCancelRequest: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CancelRequest {
+        return
+            try CancelRequest(
+                asset: FfiConverterString.read(from: &buf), 
+                oid: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: CancelRequest, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.asset, into: &buf)
+        FfiConverterUInt64.write(value.oid, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCancelRequest_lift(_ buf: RustBuffer) throws -> CancelRequest {
+    return try FfiConverterTypeCancelRequest.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCancelRequest_lower(_ value: CancelRequest) -> RustBuffer {
+    return FfiConverterTypeCancelRequest.lower(value)
+}
+
+
+public struct CancelResponse {
+    public var status: String
+    public var exchangeStatus: ExchangeStatus
+    public var error: String?
+    public var raw: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(status: String, exchangeStatus: ExchangeStatus, error: String?, raw: String?) {
+        self.status = status
+        self.exchangeStatus = exchangeStatus
+        self.error = error
+        self.raw = raw
+    }
+}
+
+
+
+extension CancelResponse: Equatable, Hashable {
+    public static func ==(lhs: CancelResponse, rhs: CancelResponse) -> Bool {
+        if lhs.status != rhs.status {
+            return false
+        }
+        if lhs.exchangeStatus != rhs.exchangeStatus {
+            return false
+        }
+        if lhs.error != rhs.error {
+            return false
+        }
+        if lhs.raw != rhs.raw {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(status)
+        hasher.combine(exchangeStatus)
+        hasher.combine(error)
+        hasher.combine(raw)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeCancelResponse: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CancelResponse {
+        return
+            try CancelResponse(
+                status: FfiConverterString.read(from: &buf), 
+                exchangeStatus: FfiConverterTypeExchangeStatus.read(from: &buf), 
+                error: FfiConverterOptionString.read(from: &buf), 
+                raw: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: CancelResponse, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.status, into: &buf)
+        FfiConverterTypeExchangeStatus.write(value.exchangeStatus, into: &buf)
+        FfiConverterOptionString.write(value.error, into: &buf)
+        FfiConverterOptionString.write(value.raw, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCancelResponse_lift(_ buf: RustBuffer) throws -> CancelResponse {
+    return try FfiConverterTypeCancelResponse.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCancelResponse_lower(_ value: CancelResponse) -> RustBuffer {
+    return FfiConverterTypeCancelResponse.lower(value)
+}
+
+
+public struct Candle {
+    public var timeOpen: UInt64
+    public var timeClose: UInt64
+    public var `open`: Double
+    public var high: Double
+    public var low: Double
+    public var close: Double
+    public var volume: Double
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(timeOpen: UInt64, timeClose: UInt64, `open`: Double, high: Double, low: Double, close: Double, volume: Double) {
+        self.timeOpen = timeOpen
+        self.timeClose = timeClose
+        self.`open` = `open`
+        self.high = high
+        self.low = low
+        self.close = close
+        self.volume = volume
+    }
+}
+
+
+
+extension Candle: Equatable, Hashable {
+    public static func ==(lhs: Candle, rhs: Candle) -> Bool {
+        if lhs.timeOpen != rhs.timeOpen {
+            return false
+        }
+        if lhs.timeClose != rhs.timeClose {
+            return false
+        }
+        if lhs.`open` != rhs.`open` {
+            return false
+        }
+        if lhs.high != rhs.high {
+            return false
+        }
+        if lhs.low != rhs.low {
+            return false
+        }
+        if lhs.close != rhs.close {
+            return false
+        }
+        if lhs.volume != rhs.volume {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(timeOpen)
+        hasher.combine(timeClose)
+        hasher.combine(`open`)
+        hasher.combine(high)
+        hasher.combine(low)
+        hasher.combine(close)
+        hasher.combine(volume)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeCandle: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Candle {
+        return
+            try Candle(
+                timeOpen: FfiConverterUInt64.read(from: &buf), 
+                timeClose: FfiConverterUInt64.read(from: &buf), 
+                open: FfiConverterDouble.read(from: &buf), 
+                high: FfiConverterDouble.read(from: &buf), 
+                low: FfiConverterDouble.read(from: &buf), 
+                close: FfiConverterDouble.read(from: &buf), 
+                volume: FfiConverterDouble.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Candle, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.timeOpen, into: &buf)
+        FfiConverterUInt64.write(value.timeClose, into: &buf)
+        FfiConverterDouble.write(value.`open`, into: &buf)
+        FfiConverterDouble.write(value.high, into: &buf)
+        FfiConverterDouble.write(value.low, into: &buf)
+        FfiConverterDouble.write(value.close, into: &buf)
+        FfiConverterDouble.write(value.volume, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCandle_lift(_ buf: RustBuffer) throws -> Candle {
+    return try FfiConverterTypeCandle.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCandle_lower(_ value: Candle) -> RustBuffer {
+    return FfiConverterTypeCandle.lower(value)
+}
+
+
+public struct DeployAuctionStatus {
+    public var startTime: UInt64
+    public var durationSeconds: UInt64
+    public var startGas: Double
+    public var currentGas: Double?
+    public var endGas: Double?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(startTime: UInt64, durationSeconds: UInt64, startGas: Double, currentGas: Double?, endGas: Double?) {
+        self.startTime = startTime
+        self.durationSeconds = durationSeconds
+        self.startGas = startGas
+        self.currentGas = currentGas
+        self.endGas = endGas
+    }
+}
+
+
+
+extension DeployAuctionStatus: Equatable, Hashable {
+    public static func ==(lhs: DeployAuctionStatus, rhs: DeployAuctionStatus) -> Bool {
+        if lhs.startTime != rhs.startTime {
+            return false
+        }
+        if lhs.durationSeconds != rhs.durationSeconds {
+            return false
+        }
+        if lhs.startGas != rhs.startGas {
+            return false
+        }
+        if lhs.currentGas != rhs.currentGas {
+            return false
+        }
+        if lhs.endGas != rhs.endGas {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(startTime)
+        hasher.combine(durationSeconds)
+        hasher.combine(startGas)
+        hasher.combine(currentGas)
+        hasher.combine(endGas)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeDeployAuctionStatus: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DeployAuctionStatus {
+        return
+            try DeployAuctionStatus(
+                startTime: FfiConverterUInt64.read(from: &buf), 
+                durationSeconds: FfiConverterUInt64.read(from: &buf), 
+                startGas: FfiConverterDouble.read(from: &buf), 
+                currentGas: FfiConverterOptionDouble.read(from: &buf), 
+                endGas: FfiConverterOptionDouble.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: DeployAuctionStatus, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.startTime, into: &buf)
+        FfiConverterUInt64.write(value.durationSeconds, into: &buf)
+        FfiConverterDouble.write(value.startGas, into: &buf)
+        FfiConverterOptionDouble.write(value.currentGas, into: &buf)
+        FfiConverterOptionDouble.write(value.endGas, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDeployAuctionStatus_lift(_ buf: RustBuffer) throws -> DeployAuctionStatus {
+    return try FfiConverterTypeDeployAuctionStatus.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDeployAuctionStatus_lower(_ value: DeployAuctionStatus) -> RustBuffer {
+    return FfiConverterTypeDeployAuctionStatus.lower(value)
+}
+
+
+public struct ExchangeClientConfig {
+    public var timeoutMs: UInt64?
+    public var retry: RetryConfig?
+    public var rateLimit: RateLimitConfig?
+    public var vaultAddress: String?
+    public var builder: BuilderInfo?
+    public var dedupeWindowMs: UInt64?
+    public var pool: ConnectionPool?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(timeoutMs: UInt64? = nil, retry: RetryConfig? = nil, rateLimit: RateLimitConfig? = nil, vaultAddress: String? = nil, builder: BuilderInfo? = nil, dedupeWindowMs: UInt64? = nil, pool: ConnectionPool? = nil) {
+        self.timeoutMs = timeoutMs
+        self.retry = retry
+        self.rateLimit = rateLimit
+        self.vaultAddress = vaultAddress
+        self.builder = builder
+        self.dedupeWindowMs = dedupeWindowMs
+        self.pool = pool
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeExchangeClientConfig: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ExchangeClientConfig {
+        return
+            try ExchangeClientConfig(
+                timeoutMs: FfiConverterOptionUInt64.read(from: &buf), 
+                retry: FfiConverterOptionTypeRetryConfig.read(from: &buf), 
+                rateLimit: FfiConverterOptionTypeRateLimitConfig.read(from: &buf), 
+                vaultAddress: FfiConverterOptionString.read(from: &buf), 
+                builder: FfiConverterOptionTypeBuilderInfo.read(from: &buf), 
+                dedupeWindowMs: FfiConverterOptionUInt64.read(from: &buf), 
+                pool: FfiConverterOptionTypeConnectionPool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ExchangeClientConfig, into buf: inout [UInt8]) {
+        FfiConverterOptionUInt64.write(value.timeoutMs, into: &buf)
+        FfiConverterOptionTypeRetryConfig.write(value.retry, into: &buf)
+        FfiConverterOptionTypeRateLimitConfig.write(value.rateLimit, into: &buf)
+        FfiConverterOptionString.write(value.vaultAddress, into: &buf)
+        FfiConverterOptionTypeBuilderInfo.write(value.builder, into: &buf)
+        FfiConverterOptionUInt64.write(value.dedupeWindowMs, into: &buf)
+        FfiConverterOptionTypeConnectionPool.write(value.pool, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeExchangeClientConfig_lift(_ buf: RustBuffer) throws -> ExchangeClientConfig {
+    return try FfiConverterTypeExchangeClientConfig.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeExchangeClientConfig_lower(_ value: ExchangeClientConfig) -> RustBuffer {
+    return FfiConverterTypeExchangeClientConfig.lower(value)
+}
+
+
+public struct Fill {
+    public var asset: String
+    public var side: OrderSide
+    public var price: Double
+    public var size: Double
+    public var oid: UInt64
+    public var fee: Double
+    public var closedPnl: Double
+    public var timestamp: UInt64
+    public var hash: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(asset: String, side: OrderSide, price: Double, size: Double, oid: UInt64, fee: Double, closedPnl: Double, timestamp: UInt64, hash: String) {
+        self.asset = asset
+        self.side = side
+        self.price = price
+        self.size = size
+        self.oid = oid
+        self.fee = fee
+        self.closedPnl = closedPnl
+        self.timestamp = timestamp
+        self.hash = hash
+    }
+}
+
+
+
+extension Fill: Equatable, Hashable {
+    public static func ==(lhs: Fill, rhs: Fill) -> Bool {
+        if lhs.asset != rhs.asset {
+            return false
+        }
+        if lhs.side != rhs.side {
+            return false
+        }
+        if lhs.price != rhs.price {
+            return false
+        }
+        if lhs.size != rhs.size {
+            return false
+        }
+        if lhs.oid != rhs.oid {
+            return false
+        }
+        if lhs.fee != rhs.fee {
+            return false
+        }
+        if lhs.closedPnl != rhs.closedPnl {
+            return false
+        }
+        if lhs.timestamp != rhs.timestamp {
+            return false
+        }
+        if lhs.hash != rhs.hash {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(asset)
+        hasher.combine(side)
+        hasher.combine(price)
+        hasher.combine(size)
+        hasher.combine(oid)
+        hasher.combine(fee)
+        hasher.combine(closedPnl)
+        hasher.combine(timestamp)
+        hasher.combine(hash)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFill: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Fill {
+        return
+            try Fill(
+                asset: FfiConverterString.read(from: &buf), 
+                side: FfiConverterTypeOrderSide.read(from: &buf), 
+                price: FfiConverterDouble.read(from: &buf), 
+                size: FfiConverterDouble.read(from: &buf), 
+                oid: FfiConverterUInt64.read(from: &buf), 
+                fee: FfiConverterDouble.read(from: &buf), 
+                closedPnl: FfiConverterDouble.read(from: &buf), 
+                timestamp: FfiConverterUInt64.read(from: &buf), 
+                hash: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Fill, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.asset, into: &buf)
+        FfiConverterTypeOrderSide.write(value.side, into: &buf)
+        FfiConverterDouble.write(value.price, into: &buf)
+        FfiConverterDouble.write(value.size, into: &buf)
+        FfiConverterUInt64.write(value.oid, into: &buf)
+        FfiConverterDouble.write(value.fee, into: &buf)
+        FfiConverterDouble.write(value.closedPnl, into: &buf)
+        FfiConverterUInt64.write(value.timestamp, into: &buf)
+        FfiConverterString.write(value.hash, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFill_lift(_ buf: RustBuffer) throws -> Fill {
+    return try FfiConverterTypeFill.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFill_lower(_ value: Fill) -> RustBuffer {
+    return FfiConverterTypeFill.lower(value)
+}
+
+
+public struct FrontendOrder {
+    public var asset: String
+    public var side: OrderSide
+    public var size: Double
+    public var origSize: Double
+    public var price: Double
+    public var oid: UInt64
+    public var timestamp: UInt64
+    public var cloid: String?
+    public var orderType: String
+    public var isTrigger: Bool
+    public var triggerPrice: Double?
+    public var tpsl: TpSl?
+    public var isPositionTpsl: Bool
+    public var reduceOnly: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(asset: String, side: OrderSide, size: Double, origSize: Double, price: Double, oid: UInt64, timestamp: UInt64, cloid: String?, orderType: String, isTrigger: Bool, triggerPrice: Double?, tpsl: TpSl?, isPositionTpsl: Bool, reduceOnly: Bool) {
+        self.asset = asset
+        self.side = side
+        self.size = size
+        self.origSize = origSize
+        self.price = price
+        self.oid = oid
+        self.timestamp = timestamp
+        self.cloid = cloid
+        self.orderType = orderType
+        self.isTrigger = isTrigger
+        self.triggerPrice = triggerPrice
+        self.tpsl = tpsl
+        self.isPositionTpsl = isPositionTpsl
+        self.reduceOnly = reduceOnly
+    }
+}
+
+
+
+extension FrontendOrder: Equatable, Hashable {
+    public static func ==(lhs: FrontendOrder, rhs: FrontendOrder) -> Bool {
+        if lhs.asset != rhs.asset {
+            return false
+        }
+        if lhs.side != rhs.side {
+            return false
+        }
+        if lhs.size != rhs.size {
+            return false
+        }
+        if lhs.origSize != rhs.origSize {
+            return false
+        }
+        if lhs.price != rhs.price {
+            return false
+        }
+        if lhs.oid != rhs.oid {
+            return false
+        }
+        if lhs.timestamp != rhs.timestamp {
+            return false
+        }
+        if lhs.cloid != rhs.cloid {
+            return false
+        }
+        if lhs.orderType != rhs.orderType {
+            return false
+        }
+        if lhs.isTrigger != rhs.isTrigger {
+            return false
+        }
+        if lhs.triggerPrice != rhs.triggerPrice {
+            return false
+        }
+        if lhs.tpsl != rhs.tpsl {
+            return false
+        }
+        if lhs.isPositionTpsl != rhs.isPositionTpsl {
+            return false
+        }
+        if lhs.reduceOnly != rhs.reduceOnly {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(asset)
+        hasher.combine(side)
+        hasher.combine(size)
+        hasher.combine(origSize)
+        hasher.combine(price)
+        hasher.combine(oid)
+        hasher.combine(timestamp)
+        hasher.combine(cloid)
+        hasher.combine(orderType)
+        hasher.combine(isTrigger)
+        hasher.combine(triggerPrice)
+        hasher.combine(tpsl)
+        hasher.combine(isPositionTpsl)
+        hasher.combine(reduceOnly)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFrontendOrder: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FrontendOrder {
+        return
+            try FrontendOrder(
+                asset: FfiConverterString.read(from: &buf), 
+                side: FfiConverterTypeOrderSide.read(from: &buf), 
+                size: FfiConverterDouble.read(from: &buf), 
+                origSize: FfiConverterDouble.read(from: &buf), 
+                price: FfiConverterDouble.read(from: &buf), 
+                oid: FfiConverterUInt64.read(from: &buf), 
+                timestamp: FfiConverterUInt64.read(from: &buf), 
+                cloid: FfiConverterOptionString.read(from: &buf), 
+                orderType: FfiConverterString.read(from: &buf), 
+                isTrigger: FfiConverterBool.read(from: &buf), 
+                triggerPrice: FfiConverterOptionDouble.read(from: &buf), 
+                tpsl: FfiConverterOptionTypeTpSl.read(from: &buf), 
+                isPositionTpsl: FfiConverterBool.read(from: &buf), 
+                reduceOnly: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FrontendOrder, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.asset, into: &buf)
+        FfiConverterTypeOrderSide.write(value.side, into: &buf)
+        FfiConverterDouble.write(value.size, into: &buf)
+        FfiConverterDouble.write(value.origSize, into: &buf)
+        FfiConverterDouble.write(value.price, into: &buf)
+        FfiConverterUInt64.write(value.oid, into: &buf)
+        FfiConverterUInt64.write(value.timestamp, into: &buf)
+        FfiConverterOptionString.write(value.cloid, into: &buf)
+        FfiConverterString.write(value.orderType, into: &buf)
+        FfiConverterBool.write(value.isTrigger, into: &buf)
+        FfiConverterOptionDouble.write(value.triggerPrice, into: &buf)
+        FfiConverterOptionTypeTpSl.write(value.tpsl, into: &buf)
+        FfiConverterBool.write(value.isPositionTpsl, into: &buf)
+        FfiConverterBool.write(value.reduceOnly, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFrontendOrder_lift(_ buf: RustBuffer) throws -> FrontendOrder {
+    return try FfiConverterTypeFrontendOrder.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFrontendOrder_lower(_ value: FrontendOrder) -> RustBuffer {
+    return FfiConverterTypeFrontendOrder.lower(value)
+}
+
+
+public struct FundingEntry {
+    public var time: UInt64
+    public var fundingRate: Double
+    public var premium: Double
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(time: UInt64, fundingRate: Double, premium: Double) {
+        self.time = time
+        self.fundingRate = fundingRate
+        self.premium = premium
+    }
+}
+
+
+
+extension FundingEntry: Equatable, Hashable {
+    public static func ==(lhs: FundingEntry, rhs: FundingEntry) -> Bool {
+        if lhs.time != rhs.time {
+            return false
+        }
+        if lhs.fundingRate != rhs.fundingRate {
+            return false
+        }
+        if lhs.premium != rhs.premium {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(time)
+        hasher.combine(fundingRate)
+        hasher.combine(premium)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFundingEntry: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FundingEntry {
+        return
+            try FundingEntry(
+                time: FfiConverterUInt64.read(from: &buf), 
+                fundingRate: FfiConverterDouble.read(from: &buf), 
+                premium: FfiConverterDouble.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FundingEntry, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.time, into: &buf)
+        FfiConverterDouble.write(value.fundingRate, into: &buf)
+        FfiConverterDouble.write(value.premium, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFundingEntry_lift(_ buf: RustBuffer) throws -> FundingEntry {
+    return try FfiConverterTypeFundingEntry.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFundingEntry_lower(_ value: FundingEntry) -> RustBuffer {
+    return FfiConverterTypeFundingEntry.lower(value)
+}
+
+
+public struct HistoricalOrder {
+    public var order: OpenOrder
+    public var status: String
+    public var statusTimestamp: UInt64
+    public var originalSize: Double
+    public var filledSize: Double
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(order: OpenOrder, status: String, statusTimestamp: UInt64, originalSize: Double, filledSize: Double) {
+        self.order = order
+        self.status = status
+        self.statusTimestamp = statusTimestamp
+        self.originalSize = originalSize
+        self.filledSize = filledSize
+    }
+}
+
+
+
+extension HistoricalOrder: Equatable, Hashable {
+    public static func ==(lhs: HistoricalOrder, rhs: HistoricalOrder) -> Bool {
+        if lhs.order != rhs.order {
+            return false
+        }
+        if lhs.status != rhs.status {
+            return false
+        }
+        if lhs.statusTimestamp != rhs.statusTimestamp {
+            return false
+        }
+        if lhs.originalSize != rhs.originalSize {
+            return false
+        }
+        if lhs.filledSize != rhs.filledSize {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(order)
+        hasher.combine(status)
+        hasher.combine(statusTimestamp)
+        hasher.combine(originalSize)
+        hasher.combine(filledSize)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeHistoricalOrder: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HistoricalOrder {
+        return
+            try HistoricalOrder(
+                order: FfiConverterTypeOpenOrder.read(from: &buf), 
+                status: FfiConverterString.read(from: &buf), 
+                statusTimestamp: FfiConverterUInt64.read(from: &buf), 
+                originalSize: FfiConverterDouble.read(from: &buf), 
+                filledSize: FfiConverterDouble.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: HistoricalOrder, into buf: inout [UInt8]) {
+        FfiConverterTypeOpenOrder.write(value.order, into: &buf)
+        FfiConverterString.write(value.status, into: &buf)
+        FfiConverterUInt64.write(value.statusTimestamp, into: &buf)
+        FfiConverterDouble.write(value.originalSize, into: &buf)
+        FfiConverterDouble.write(value.filledSize, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHistoricalOrder_lift(_ buf: RustBuffer) throws -> HistoricalOrder {
+    return try FfiConverterTypeHistoricalOrder.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeHistoricalOrder_lower(_ value: HistoricalOrder) -> RustBuffer {
+    return FfiConverterTypeHistoricalOrder.lower(value)
+}
+
+
+public struct InfoClientConfig {
+    public var timeoutMs: UInt64?
+    public var retry: RetryConfig?
+    public var rateLimit: RateLimitConfig?
+    public var runtime: RuntimeFlavor
+    public var metaTtlMs: UInt64?
+    public var pool: ConnectionPool?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(timeoutMs: UInt64? = nil, retry: RetryConfig? = nil, rateLimit: RateLimitConfig? = nil, runtime: RuntimeFlavor = .multiThread, metaTtlMs: UInt64? = nil, pool: ConnectionPool? = nil) {
+        self.timeoutMs = timeoutMs
+        self.retry = retry
+        self.rateLimit = rateLimit
+        self.runtime = runtime
+        self.metaTtlMs = metaTtlMs
+        self.pool = pool
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeInfoClientConfig: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> InfoClientConfig {
+        return
+            try InfoClientConfig(
+                timeoutMs: FfiConverterOptionUInt64.read(from: &buf), 
+                retry: FfiConverterOptionTypeRetryConfig.read(from: &buf), 
+                rateLimit: FfiConverterOptionTypeRateLimitConfig.read(from: &buf), 
+                runtime: FfiConverterTypeRuntimeFlavor.read(from: &buf), 
+                metaTtlMs: FfiConverterOptionUInt64.read(from: &buf), 
+                pool: FfiConverterOptionTypeConnectionPool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: InfoClientConfig, into buf: inout [UInt8]) {
+        FfiConverterOptionUInt64.write(value.timeoutMs, into: &buf)
+        FfiConverterOptionTypeRetryConfig.write(value.retry, into: &buf)
+        FfiConverterOptionTypeRateLimitConfig.write(value.rateLimit, into: &buf)
+        FfiConverterTypeRuntimeFlavor.write(value.runtime, into: &buf)
+        FfiConverterOptionUInt64.write(value.metaTtlMs, into: &buf)
+        FfiConverterOptionTypeConnectionPool.write(value.pool, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeInfoClientConfig_lift(_ buf: RustBuffer) throws -> InfoClientConfig {
+    return try FfiConverterTypeInfoClientConfig.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeInfoClientConfig_lower(_ value: InfoClientConfig) -> RustBuffer {
+    return FfiConverterTypeInfoClientConfig.lower(value)
+}
+
+
+public struct L2Book {
+    public var asset: String
+    public var time: UInt64
+    public var bids: [BookLevel]
+    public var asks: [BookLevel]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(asset: String, time: UInt64, bids: [BookLevel], asks: [BookLevel]) {
+        self.asset = asset
+        self.time = time
+        self.bids = bids
+        self.asks = asks
+    }
+}
+
+
+
+extension L2Book: Equatable, Hashable {
+    public static func ==(lhs: L2Book, rhs: L2Book) -> Bool {
+        if lhs.asset != rhs.asset {
+            return false
+        }
+        if lhs.time != rhs.time {
+            return false
+        }
+        if lhs.bids != rhs.bids {
+            return false
+        }
+        if lhs.asks != rhs.asks {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(asset)
+        hasher.combine(time)
+        hasher.combine(bids)
+        hasher.combine(asks)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeL2Book: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> L2Book {
+        return
+            try L2Book(
+                asset: FfiConverterString.read(from: &buf), 
+                time: FfiConverterUInt64.read(from: &buf), 
+                bids: FfiConverterSequenceTypeBookLevel.read(from: &buf), 
+                asks: FfiConverterSequenceTypeBookLevel.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: L2Book, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.asset, into: &buf)
+        FfiConverterUInt64.write(value.time, into: &buf)
+        FfiConverterSequenceTypeBookLevel.write(value.bids, into: &buf)
+        FfiConverterSequenceTypeBookLevel.write(value.asks, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeL2Book_lift(_ buf: RustBuffer) throws -> L2Book {
+    return try FfiConverterTypeL2Book.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeL2Book_lower(_ value: L2Book) -> RustBuffer {
+    return FfiConverterTypeL2Book.lower(value)
+}
+
+
+public struct LedgerUpdate {
+    public var kind: String
+    public var token: String
+    public var amount: Double?
+    public var timestamp: UInt64
+    public var hash: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(kind: String, token: String, amount: Double?, timestamp: UInt64, hash: String) {
+        self.kind = kind
+        self.token = token
+        self.amount = amount
+        self.timestamp = timestamp
+        self.hash = hash
+    }
+}
+
+
+
+extension LedgerUpdate: Equatable, Hashable {
+    public static func ==(lhs: LedgerUpdate, rhs: LedgerUpdate) -> Bool {
+        if lhs.kind != rhs.kind {
+            return false
+        }
+        if lhs.token != rhs.token {
+            return false
+        }
+        if lhs.amount != rhs.amount {
+            return false
+        }
+        if lhs.timestamp != rhs.timestamp {
+            return false
+        }
+        if lhs.hash != rhs.hash {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(kind)
+        hasher.combine(token)
+        hasher.combine(amount)
+        hasher.combine(timestamp)
+        hasher.combine(hash)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeLedgerUpdate: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LedgerUpdate {
+        return
+            try LedgerUpdate(
+                kind: FfiConverterString.read(from: &buf), 
+                token: FfiConverterString.read(from: &buf), 
+                amount: FfiConverterOptionDouble.read(from: &buf), 
+                timestamp: FfiConverterUInt64.read(from: &buf), 
+                hash: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: LedgerUpdate, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.kind, into: &buf)
+        FfiConverterString.write(value.token, into: &buf)
+        FfiConverterOptionDouble.write(value.amount, into: &buf)
+        FfiConverterUInt64.write(value.timestamp, into: &buf)
+        FfiConverterString.write(value.hash, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeLedgerUpdate_lift(_ buf: RustBuffer) throws -> LedgerUpdate {
+    return try FfiConverterTypeLedgerUpdate.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeLedgerUpdate_lower(_ value: LedgerUpdate) -> RustBuffer {
+    return FfiConverterTypeLedgerUpdate.lower(value)
+}
+
+
+public struct MarginSummary {
+    public var accountValue: Double
+    public var totalNotionalPosition: Double
+    public var totalRawUsd: Double
+    public var totalMarginUsed: Double
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(accountValue: Double, totalNotionalPosition: Double, totalRawUsd: Double, totalMarginUsed: Double) {
+        self.accountValue = accountValue
+        self.totalNotionalPosition = totalNotionalPosition
+        self.totalRawUsd = totalRawUsd
+        self.totalMarginUsed = totalMarginUsed
+    }
+}
+
+
+
+extension MarginSummary: Equatable, Hashable {
+    public static func ==(lhs: MarginSummary, rhs: MarginSummary) -> Bool {
+        if lhs.accountValue != rhs.accountValue {
+            return false
+        }
+        if lhs.totalNotionalPosition != rhs.totalNotionalPosition {
+            return false
+        }
+        if lhs.totalRawUsd != rhs.totalRawUsd {
+            return false
+        }
+        if lhs.totalMarginUsed != rhs.totalMarginUsed {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(accountValue)
+        hasher.combine(totalNotionalPosition)
+        hasher.combine(totalRawUsd)
+        hasher.combine(totalMarginUsed)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeMarginSummary: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> MarginSummary {
+        return
+            try MarginSummary(
+                accountValue: FfiConverterDouble.read(from: &buf), 
+                totalNotionalPosition: FfiConverterDouble.read(from: &buf), 
+                totalRawUsd: FfiConverterDouble.read(from: &buf), 
+                totalMarginUsed: FfiConverterDouble.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: MarginSummary, into buf: inout [UInt8]) {
+        FfiConverterDouble.write(value.accountValue, into: &buf)
+        FfiConverterDouble.write(value.totalNotionalPosition, into: &buf)
+        FfiConverterDouble.write(value.totalRawUsd, into: &buf)
+        FfiConverterDouble.write(value.totalMarginUsed, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeMarginSummary_lift(_ buf: RustBuffer) throws -> MarginSummary {
+    return try FfiConverterTypeMarginSummary.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeMarginSummary_lower(_ value: MarginSummary) -> RustBuffer {
+    return FfiConverterTypeMarginSummary.lower(value)
+}
+
+
+public struct ModifyRequest {
+    public var oid: UInt64
+    public var order: OrderRequest
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(oid: UInt64, order: OrderRequest) {
+        self.oid = oid
+        self.order = order
+    }
+}
+
+
+
+extension ModifyRequest: Equatable, Hashable {
+    public static func ==(lhs: ModifyRequest, rhs: ModifyRequest) -> Bool {
+        if lhs.oid != rhs.oid {
+            return false
+        }
+        if lhs.order != rhs.order {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(oid)
+        hasher.combine(order)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeModifyRequest: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ModifyRequest {
+        return
+            try ModifyRequest(
+                oid: FfiConverterUInt64.read(from: &buf), 
+                order: FfiConverterTypeOrderRequest.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ModifyRequest, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.oid, into: &buf)
+        FfiConverterTypeOrderRequest.write(value.order, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeModifyRequest_lift(_ buf: RustBuffer) throws -> ModifyRequest {
+    return try FfiConverterTypeModifyRequest.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeModifyRequest_lower(_ value: ModifyRequest) -> RustBuffer {
+    return FfiConverterTypeModifyRequest.lower(value)
+}
+
+
+public struct OpenOrder {
+    public var asset: String
+    public var side: OrderSide
+    public var size: Double
+    public var price: Double
+    public var oid: UInt64
+    public var timestamp: UInt64
+    public var cloid: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(asset: String, side: OrderSide, size: Double, price: Double, oid: UInt64, timestamp: UInt64, cloid: String?) {
+        self.asset = asset
+        self.side = side
+        self.size = size
+        self.price = price
+        self.oid = oid
+        self.timestamp = timestamp
+        self.cloid = cloid
+    }
+}
+
+
+
+extension OpenOrder: Equatable, Hashable {
+    public static func ==(lhs: OpenOrder, rhs: OpenOrder) -> Bool {
+        if lhs.asset != rhs.asset {
+            return false
+        }
+        if lhs.side != rhs.side {
+            return false
+        }
+        if lhs.size != rhs.size {
+            return false
+        }
+        if lhs.price != rhs.price {
+            return false
+        }
+        if lhs.oid != rhs.oid {
+            return false
+        }
+        if lhs.timestamp != rhs.timestamp {
+            return false
+        }
+        if lhs.cloid != rhs.cloid {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(asset)
+        hasher.combine(side)
+        hasher.combine(size)
+        hasher.combine(price)
+        hasher.combine(oid)
+        hasher.combine(timestamp)
+        hasher.combine(cloid)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeOpenOrder: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> OpenOrder {
+        return
+            try OpenOrder(
+                asset: FfiConverterString.read(from: &buf), 
+                side: FfiConverterTypeOrderSide.read(from: &buf), 
+                size: FfiConverterDouble.read(from: &buf), 
+                price: FfiConverterDouble.read(from: &buf), 
+                oid: FfiConverterUInt64.read(from: &buf), 
+                timestamp: FfiConverterUInt64.read(from: &buf), 
+                cloid: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: OpenOrder, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.asset, into: &buf)
+        FfiConverterTypeOrderSide.write(value.side, into: &buf)
+        FfiConverterDouble.write(value.size, into: &buf)
+        FfiConverterDouble.write(value.price, into: &buf)
+        FfiConverterUInt64.write(value.oid, into: &buf)
+        FfiConverterUInt64.write(value.timestamp, into: &buf)
+        FfiConverterOptionString.write(value.cloid, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOpenOrder_lift(_ buf: RustBuffer) throws -> OpenOrder {
+    return try FfiConverterTypeOpenOrder.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOpenOrder_lower(_ value: OpenOrder) -> RustBuffer {
+    return FfiConverterTypeOpenOrder.lower(value)
+}
+
+
+public struct OrderRequest {
+    public var asset: String
+    public var side: OrderSide
+    public var size: Double
+    public var price: Double
+    public var reduceOnly: Bool
+    public var orderType: OrderType
+    public var tif: TimeInForce
+    public var slippage: Double?
+    public var cloid: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(asset: String, side: OrderSide, size: Double, price: Double, reduceOnly: Bool, orderType: OrderType, tif: TimeInForce = .gtc, slippage: Double? = nil, cloid: String? = nil) {
+        self.asset = asset
+        self.side = side
+        self.size = size
+        self.price = price
+        self.reduceOnly = reduceOnly
+        self.orderType = orderType
+        self.tif = tif
+        self.slippage = slippage
+        self.cloid = cloid
+    }
+}
+
+
+
+extension OrderRequest: Equatable, Hashable {
+    public static func ==(lhs: OrderRequest, rhs: OrderRequest) -> Bool {
+        if lhs.asset != rhs.asset {
+            return false
+        }
+        if lhs.side != rhs.side {
+            return false
+        }
+        if lhs.size != rhs.size {
+            return false
+        }
+        if lhs.price != rhs.price {
+            return false
+        }
+        if lhs.reduceOnly != rhs.reduceOnly {
+            return false
+        }
+        if lhs.orderType != rhs.orderType {
+            return false
+        }
+        if lhs.tif != rhs.tif {
+            return false
+        }
+        if lhs.slippage != rhs.slippage {
+            return false
+        }
+        if lhs.cloid != rhs.cloid {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(asset)
+        hasher.combine(side)
+        hasher.combine(size)
+        hasher.combine(price)
+        hasher.combine(reduceOnly)
+        hasher.combine(orderType)
+        hasher.combine(tif)
+        hasher.combine(slippage)
+        hasher.combine(cloid)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeOrderRequest: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> OrderRequest {
+        return
+            try OrderRequest(
+                asset: FfiConverterString.read(from: &buf), 
+                side: FfiConverterTypeOrderSide.read(from: &buf), 
+                size: FfiConverterDouble.read(from: &buf), 
+                price: FfiConverterDouble.read(from: &buf), 
+                reduceOnly: FfiConverterBool.read(from: &buf), 
+                orderType: FfiConverterTypeOrderType.read(from: &buf), 
+                tif: FfiConverterTypeTimeInForce.read(from: &buf), 
+                slippage: FfiConverterOptionDouble.read(from: &buf), 
+                cloid: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: OrderRequest, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.asset, into: &buf)
+        FfiConverterTypeOrderSide.write(value.side, into: &buf)
+        FfiConverterDouble.write(value.size, into: &buf)
+        FfiConverterDouble.write(value.price, into: &buf)
+        FfiConverterBool.write(value.reduceOnly, into: &buf)
+        FfiConverterTypeOrderType.write(value.orderType, into: &buf)
+        FfiConverterTypeTimeInForce.write(value.tif, into: &buf)
+        FfiConverterOptionDouble.write(value.slippage, into: &buf)
+        FfiConverterOptionString.write(value.cloid, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOrderRequest_lift(_ buf: RustBuffer) throws -> OrderRequest {
+    return try FfiConverterTypeOrderRequest.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOrderRequest_lower(_ value: OrderRequest) -> RustBuffer {
+    return FfiConverterTypeOrderRequest.lower(value)
+}
+
+
+public struct OrderResponse {
+    public var status: String
+    public var state: OrderState
+    public var exchangeStatus: ExchangeStatus
+    public var oid: UInt64?
+    public var filledSize: Double?
+    public var averagePrice: Double?
+    public var resting: Bool
+    public var error: String?
+    public var cloid: String?
+    public var raw: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(status: String, state: OrderState, exchangeStatus: ExchangeStatus, oid: UInt64?, filledSize: Double?, averagePrice: Double?, resting: Bool, error: String?, cloid: String?, raw: String?) {
+        self.status = status
+        self.state = state
+        self.exchangeStatus = exchangeStatus
+        self.oid = oid
+        self.filledSize = filledSize
+        self.averagePrice = averagePrice
+        self.resting = resting
+        self.error = error
+        self.cloid = cloid
+        self.raw = raw
+    }
+}
+
+
+
+extension OrderResponse: Equatable, Hashable {
+    public static func ==(lhs: OrderResponse, rhs: OrderResponse) -> Bool {
+        if lhs.status != rhs.status {
+            return false
+        }
+        if lhs.state != rhs.state {
+            return false
+        }
+        if lhs.exchangeStatus != rhs.exchangeStatus {
+            return false
+        }
+        if lhs.oid != rhs.oid {
+            return false
+        }
+        if lhs.filledSize != rhs.filledSize {
+            return false
+        }
+        if lhs.averagePrice != rhs.averagePrice {
+            return false
+        }
+        if lhs.resting != rhs.resting {
+            return false
+        }
+        if lhs.error != rhs.error {
+            return false
+        }
+        if lhs.cloid != rhs.cloid {
+            return false
+        }
+        if lhs.raw != rhs.raw {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(status)
+        hasher.combine(state)
+        hasher.combine(exchangeStatus)
+        hasher.combine(oid)
+        hasher.combine(filledSize)
+        hasher.combine(averagePrice)
+        hasher.combine(resting)
+        hasher.combine(error)
+        hasher.combine(cloid)
+        hasher.combine(raw)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeOrderResponse: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> OrderResponse {
+        return
+            try OrderResponse(
+                status: FfiConverterString.read(from: &buf), 
+                state: FfiConverterTypeOrderState.read(from: &buf), 
+                exchangeStatus: FfiConverterTypeExchangeStatus.read(from: &buf), 
+                oid: FfiConverterOptionUInt64.read(from: &buf), 
+                filledSize: FfiConverterOptionDouble.read(from: &buf), 
+                averagePrice: FfiConverterOptionDouble.read(from: &buf), 
+                resting: FfiConverterBool.read(from: &buf), 
+                error: FfiConverterOptionString.read(from: &buf), 
+                cloid: FfiConverterOptionString.read(from: &buf), 
+                raw: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: OrderResponse, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.status, into: &buf)
+        FfiConverterTypeOrderState.write(value.state, into: &buf)
+        FfiConverterTypeExchangeStatus.write(value.exchangeStatus, into: &buf)
+        FfiConverterOptionUInt64.write(value.oid, into: &buf)
+        FfiConverterOptionDouble.write(value.filledSize, into: &buf)
+        FfiConverterOptionDouble.write(value.averagePrice, into: &buf)
+        FfiConverterBool.write(value.resting, into: &buf)
+        FfiConverterOptionString.write(value.error, into: &buf)
+        FfiConverterOptionString.write(value.cloid, into: &buf)
+        FfiConverterOptionString.write(value.raw, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOrderResponse_lift(_ buf: RustBuffer) throws -> OrderResponse {
+    return try FfiConverterTypeOrderResponse.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOrderResponse_lower(_ value: OrderResponse) -> RustBuffer {
+    return FfiConverterTypeOrderResponse.lower(value)
+}
+
+
+public struct OrderStatus {
+    public var status: String
+    public var filledSize: Double
+    public var remainingSize: Double
+    public var order: OpenOrder?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(status: String, filledSize: Double, remainingSize: Double, order: OpenOrder?) {
+        self.status = status
+        self.filledSize = filledSize
+        self.remainingSize = remainingSize
+        self.order = order
+    }
+}
+
+
+
+extension OrderStatus: Equatable, Hashable {
+    public static func ==(lhs: OrderStatus, rhs: OrderStatus) -> Bool {
+        if lhs.status != rhs.status {
+            return false
+        }
+        if lhs.filledSize != rhs.filledSize {
+            return false
+        }
+        if lhs.remainingSize != rhs.remainingSize {
+            return false
+        }
+        if lhs.order != rhs.order {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(status)
+        hasher.combine(filledSize)
+        hasher.combine(remainingSize)
+        hasher.combine(order)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeOrderStatus: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> OrderStatus {
+        return
+            try OrderStatus(
+                status: FfiConverterString.read(from: &buf), 
+                filledSize: FfiConverterDouble.read(from: &buf), 
+                remainingSize: FfiConverterDouble.read(from: &buf), 
+                order: FfiConverterOptionTypeOpenOrder.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: OrderStatus, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.status, into: &buf)
+        FfiConverterDouble.write(value.filledSize, into: &buf)
+        FfiConverterDouble.write(value.remainingSize, into: &buf)
+        FfiConverterOptionTypeOpenOrder.write(value.order, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOrderStatus_lift(_ buf: RustBuffer) throws -> OrderStatus {
+    return try FfiConverterTypeOrderStatus.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOrderStatus_lower(_ value: OrderStatus) -> RustBuffer {
+    return FfiConverterTypeOrderStatus.lower(value)
+}
+
+
+public struct Position {
+    public var asset: String
+    public var size: Double
+    public var entryPrice: Double
+    public var unrealizedPnl: Double
+    public var liquidationPrice: Double?
+    public var leverage: UInt32
+    public var marginUsed: Double
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(asset: String, size: Double, entryPrice: Double, unrealizedPnl: Double, liquidationPrice: Double?, leverage: UInt32, marginUsed: Double) {
+        self.asset = asset
+        self.size = size
+        self.entryPrice = entryPrice
+        self.unrealizedPnl = unrealizedPnl
+        self.liquidationPrice = liquidationPrice
+        self.leverage = leverage
+        self.marginUsed = marginUsed
+    }
+}
+
+
+
+extension Position: Equatable, Hashable {
+    public static func ==(lhs: Position, rhs: Position) -> Bool {
+        if lhs.asset != rhs.asset {
+            return false
+        }
+        if lhs.size != rhs.size {
+            return false
+        }
+        if lhs.entryPrice != rhs.entryPrice {
+            return false
+        }
+        if lhs.unrealizedPnl != rhs.unrealizedPnl {
+            return false
+        }
+        if lhs.liquidationPrice != rhs.liquidationPrice {
+            return false
+        }
+        if lhs.leverage != rhs.leverage {
+            return false
+        }
+        if lhs.marginUsed != rhs.marginUsed {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(asset)
+        hasher.combine(size)
+        hasher.combine(entryPrice)
+        hasher.combine(unrealizedPnl)
+        hasher.combine(liquidationPrice)
+        hasher.combine(leverage)
+        hasher.combine(marginUsed)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypePosition: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Position {
+        return
+            try Position(
+                asset: FfiConverterString.read(from: &buf), 
+                size: FfiConverterDouble.read(from: &buf), 
+                entryPrice: FfiConverterDouble.read(from: &buf), 
+                unrealizedPnl: FfiConverterDouble.read(from: &buf), 
+                liquidationPrice: FfiConverterOptionDouble.read(from: &buf), 
+                leverage: FfiConverterUInt32.read(from: &buf), 
+                marginUsed: FfiConverterDouble.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Position, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.asset, into: &buf)
+        FfiConverterDouble.write(value.size, into: &buf)
+        FfiConverterDouble.write(value.entryPrice, into: &buf)
+        FfiConverterDouble.write(value.unrealizedPnl, into: &buf)
+        FfiConverterOptionDouble.write(value.liquidationPrice, into: &buf)
+        FfiConverterUInt32.write(value.leverage, into: &buf)
+        FfiConverterDouble.write(value.marginUsed, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePosition_lift(_ buf: RustBuffer) throws -> Position {
+    return try FfiConverterTypePosition.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePosition_lower(_ value: Position) -> RustBuffer {
+    return FfiConverterTypePosition.lower(value)
+}
+
+
+public struct PositionSimulation {
+    public var notional: Double
+    public var marginRequired: Double
+    public var maintenanceMargin: Double
+    public var liquidationPrice: Double
+    public var fee: Double
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(notional: Double, marginRequired: Double, maintenanceMargin: Double, liquidationPrice: Double, fee: Double) {
+        self.notional = notional
+        self.marginRequired = marginRequired
+        self.maintenanceMargin = maintenanceMargin
+        self.liquidationPrice = liquidationPrice
+        self.fee = fee
+    }
+}
+
+
+
+extension PositionSimulation: Equatable, Hashable {
+    public static func ==(lhs: PositionSimulation, rhs: PositionSimulation) -> Bool {
+        if lhs.notional != rhs.notional {
+            return false
+        }
+        if lhs.marginRequired != rhs.marginRequired {
+            return false
+        }
+        if lhs.maintenanceMargin != rhs.maintenanceMargin {
+            return false
+        }
+        if lhs.liquidationPrice != rhs.liquidationPrice {
+            return false
+        }
+        if lhs.fee != rhs.fee {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(notional)
+        hasher.combine(marginRequired)
+        hasher.combine(maintenanceMargin)
+        hasher.combine(liquidationPrice)
+        hasher.combine(fee)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypePositionSimulation: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> PositionSimulation {
+        return
+            try PositionSimulation(
+                notional: FfiConverterDouble.read(from: &buf), 
+                marginRequired: FfiConverterDouble.read(from: &buf), 
+                maintenanceMargin: FfiConverterDouble.read(from: &buf), 
+                liquidationPrice: FfiConverterDouble.read(from: &buf), 
+                fee: FfiConverterDouble.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: PositionSimulation, into buf: inout [UInt8]) {
+        FfiConverterDouble.write(value.notional, into: &buf)
+        FfiConverterDouble.write(value.marginRequired, into: &buf)
+        FfiConverterDouble.write(value.maintenanceMargin, into: &buf)
+        FfiConverterDouble.write(value.liquidationPrice, into: &buf)
+        FfiConverterDouble.write(value.fee, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePositionSimulation_lift(_ buf: RustBuffer) throws -> PositionSimulation {
+    return try FfiConverterTypePositionSimulation.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePositionSimulation_lower(_ value: PositionSimulation) -> RustBuffer {
+    return FfiConverterTypePositionSimulation.lower(value)
+}
+
+
+public struct PredictedFunding {
+    public var asset: String
+    public var fundingRate: Double
+    public var nextFundingTime: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(asset: String, fundingRate: Double, nextFundingTime: UInt64) {
+        self.asset = asset
+        self.fundingRate = fundingRate
+        self.nextFundingTime = nextFundingTime
+    }
+}
+
+
+
+extension PredictedFunding: Equatable, Hashable {
+    public static func ==(lhs: PredictedFunding, rhs: PredictedFunding) -> Bool {
+        if lhs.asset != rhs.asset {
+            return false
+        }
+        if lhs.fundingRate != rhs.fundingRate {
+            return false
+        }
+        if lhs.nextFundingTime != rhs.nextFundingTime {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(asset)
+        hasher.combine(fundingRate)
+        hasher.combine(nextFundingTime)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypePredictedFunding: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> PredictedFunding {
+        return
+            try PredictedFunding(
+                asset: FfiConverterString.read(from: &buf), 
+                fundingRate: FfiConverterDouble.read(from: &buf), 
+                nextFundingTime: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: PredictedFunding, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.asset, into: &buf)
+        FfiConverterDouble.write(value.fundingRate, into: &buf)
+        FfiConverterUInt64.write(value.nextFundingTime, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePredictedFunding_lift(_ buf: RustBuffer) throws -> PredictedFunding {
+    return try FfiConverterTypePredictedFunding.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePredictedFunding_lower(_ value: PredictedFunding) -> RustBuffer {
+    return FfiConverterTypePredictedFunding.lower(value)
+}
+
+
+public struct RateLimitConfig {
+    public var requestsPerSecond: Double
+    public var burst: UInt32
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(requestsPerSecond: Double, burst: UInt32) {
+        self.requestsPerSecond = requestsPerSecond
+        self.burst = burst
+    }
+}
+
+
+
+extension RateLimitConfig: Equatable, Hashable {
+    public static func ==(lhs: RateLimitConfig, rhs: RateLimitConfig) -> Bool {
+        if lhs.requestsPerSecond != rhs.requestsPerSecond {
+            return false
+        }
+        if lhs.burst != rhs.burst {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(requestsPerSecond)
+        hasher.combine(burst)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRateLimitConfig: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RateLimitConfig {
+        return
+            try RateLimitConfig(
+                requestsPerSecond: FfiConverterDouble.read(from: &buf), 
+                burst: FfiConverterUInt32.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: RateLimitConfig, into buf: inout [UInt8]) {
+        FfiConverterDouble.write(value.requestsPerSecond, into: &buf)
+        FfiConverterUInt32.write(value.burst, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRateLimitConfig_lift(_ buf: RustBuffer) throws -> RateLimitConfig {
+    return try FfiConverterTypeRateLimitConfig.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRateLimitConfig_lower(_ value: RateLimitConfig) -> RustBuffer {
+    return FfiConverterTypeRateLimitConfig.lower(value)
+}
+
+
+public struct RateLimitStatus {
+    public var cumulativeVolume: Double
+    public var requestsUsed: UInt64
+    public var requestsCap: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(cumulativeVolume: Double, requestsUsed: UInt64, requestsCap: UInt64) {
+        self.cumulativeVolume = cumulativeVolume
+        self.requestsUsed = requestsUsed
+        self.requestsCap = requestsCap
+    }
+}
+
+
+
+extension RateLimitStatus: Equatable, Hashable {
+    public static func ==(lhs: RateLimitStatus, rhs: RateLimitStatus) -> Bool {
+        if lhs.cumulativeVolume != rhs.cumulativeVolume {
+            return false
+        }
+        if lhs.requestsUsed != rhs.requestsUsed {
+            return false
+        }
+        if lhs.requestsCap != rhs.requestsCap {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(cumulativeVolume)
+        hasher.combine(requestsUsed)
+        hasher.combine(requestsCap)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRateLimitStatus: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RateLimitStatus {
+        return
+            try RateLimitStatus(
+                cumulativeVolume: FfiConverterDouble.read(from: &buf), 
+                requestsUsed: FfiConverterUInt64.read(from: &buf), 
+                requestsCap: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: RateLimitStatus, into buf: inout [UInt8]) {
+        FfiConverterDouble.write(value.cumulativeVolume, into: &buf)
+        FfiConverterUInt64.write(value.requestsUsed, into: &buf)
+        FfiConverterUInt64.write(value.requestsCap, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRateLimitStatus_lift(_ buf: RustBuffer) throws -> RateLimitStatus {
+    return try FfiConverterTypeRateLimitStatus.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRateLimitStatus_lower(_ value: RateLimitStatus) -> RustBuffer {
+    return FfiConverterTypeRateLimitStatus.lower(value)
+}
+
+
+public struct ReferralState {
+    public var code: String?
+    public var referredBy: String?
+    public var referredUsers: UInt32
+    public var unclaimedRewards: Double
+    public var claimedRewards: Double
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(code: String?, referredBy: String?, referredUsers: UInt32, unclaimedRewards: Double, claimedRewards: Double) {
+        self.code = code
+        self.referredBy = referredBy
+        self.referredUsers = referredUsers
+        self.unclaimedRewards = unclaimedRewards
+        self.claimedRewards = claimedRewards
+    }
+}
+
+
+
+extension ReferralState: Equatable, Hashable {
+    public static func ==(lhs: ReferralState, rhs: ReferralState) -> Bool {
+        if lhs.code != rhs.code {
+            return false
+        }
+        if lhs.referredBy != rhs.referredBy {
+            return false
+        }
+        if lhs.referredUsers != rhs.referredUsers {
+            return false
+        }
+        if lhs.unclaimedRewards != rhs.unclaimedRewards {
+            return false
+        }
+        if lhs.claimedRewards != rhs.claimedRewards {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(code)
+        hasher.combine(referredBy)
+        hasher.combine(referredUsers)
+        hasher.combine(unclaimedRewards)
+        hasher.combine(claimedRewards)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeReferralState: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ReferralState {
+        return
+            try ReferralState(
+                code: FfiConverterOptionString.read(from: &buf), 
+                referredBy: FfiConverterOptionString.read(from: &buf), 
+                referredUsers: FfiConverterUInt32.read(from: &buf), 
+                unclaimedRewards: FfiConverterDouble.read(from: &buf), 
+                claimedRewards: FfiConverterDouble.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ReferralState, into buf: inout [UInt8]) {
+        FfiConverterOptionString.write(value.code, into: &buf)
+        FfiConverterOptionString.write(value.referredBy, into: &buf)
+        FfiConverterUInt32.write(value.referredUsers, into: &buf)
+        FfiConverterDouble.write(value.unclaimedRewards, into: &buf)
+        FfiConverterDouble.write(value.claimedRewards, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeReferralState_lift(_ buf: RustBuffer) throws -> ReferralState {
+    return try FfiConverterTypeReferralState.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeReferralState_lower(_ value: ReferralState) -> RustBuffer {
+    return FfiConverterTypeReferralState.lower(value)
+}
+
+
+public struct RetryConfig {
+    public var maxAttempts: UInt32
+    public var baseDelayMs: UInt64
+    public var maxDelayMs: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(maxAttempts: UInt32 = UInt32(3), baseDelayMs: UInt64 = UInt64(200), maxDelayMs: UInt64 = UInt64(5000)) {
+        self.maxAttempts = maxAttempts
+        self.baseDelayMs = baseDelayMs
+        self.maxDelayMs = maxDelayMs
+    }
+}
+
+
+
+extension RetryConfig: Equatable, Hashable {
+    public static func ==(lhs: RetryConfig, rhs: RetryConfig) -> Bool {
+        if lhs.maxAttempts != rhs.maxAttempts {
+            return false
+        }
+        if lhs.baseDelayMs != rhs.baseDelayMs {
+            return false
+        }
+        if lhs.maxDelayMs != rhs.maxDelayMs {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(maxAttempts)
+        hasher.combine(baseDelayMs)
+        hasher.combine(maxDelayMs)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRetryConfig: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RetryConfig {
+        return
+            try RetryConfig(
+                maxAttempts: FfiConverterUInt32.read(from: &buf), 
+                baseDelayMs: FfiConverterUInt64.read(from: &buf), 
+                maxDelayMs: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: RetryConfig, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.maxAttempts, into: &buf)
+        FfiConverterUInt64.write(value.baseDelayMs, into: &buf)
+        FfiConverterUInt64.write(value.maxDelayMs, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRetryConfig_lift(_ buf: RustBuffer) throws -> RetryConfig {
+    return try FfiConverterTypeRetryConfig.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRetryConfig_lower(_ value: RetryConfig) -> RustBuffer {
+    return FfiConverterTypeRetryConfig.lower(value)
+}
+
+
+public struct SpotAssetMeta {
+    public var name: String
+    public var index: UInt32
+    public var baseToken: String
+    public var quoteToken: String
+    public var baseTokenIndex: UInt32
+    public var quoteTokenIndex: UInt32
+    public var szDecimals: UInt32
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(name: String, index: UInt32, baseToken: String, quoteToken: String, baseTokenIndex: UInt32, quoteTokenIndex: UInt32, szDecimals: UInt32) {
+        self.name = name
+        self.index = index
+        self.baseToken = baseToken
+        self.quoteToken = quoteToken
+        self.baseTokenIndex = baseTokenIndex
+        self.quoteTokenIndex = quoteTokenIndex
+        self.szDecimals = szDecimals
+    }
+}
+
+
+
+extension SpotAssetMeta: Equatable, Hashable {
+    public static func ==(lhs: SpotAssetMeta, rhs: SpotAssetMeta) -> Bool {
+        if lhs.name != rhs.name {
+            return false
+        }
+        if lhs.index != rhs.index {
+            return false
+        }
+        if lhs.baseToken != rhs.baseToken {
+            return false
+        }
+        if lhs.quoteToken != rhs.quoteToken {
+            return false
+        }
+        if lhs.baseTokenIndex != rhs.baseTokenIndex {
+            return false
+        }
+        if lhs.quoteTokenIndex != rhs.quoteTokenIndex {
+            return false
+        }
+        if lhs.szDecimals != rhs.szDecimals {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(name)
+        hasher.combine(index)
+        hasher.combine(baseToken)
+        hasher.combine(quoteToken)
+        hasher.combine(baseTokenIndex)
+        hasher.combine(quoteTokenIndex)
+        hasher.combine(szDecimals)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSpotAssetMeta: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SpotAssetMeta {
+        return
+            try SpotAssetMeta(
+                name: FfiConverterString.read(from: &buf), 
+                index: FfiConverterUInt32.read(from: &buf), 
+                baseToken: FfiConverterString.read(from: &buf), 
+                quoteToken: FfiConverterString.read(from: &buf), 
+                baseTokenIndex: FfiConverterUInt32.read(from: &buf), 
+                quoteTokenIndex: FfiConverterUInt32.read(from: &buf), 
+                szDecimals: FfiConverterUInt32.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SpotAssetMeta, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterUInt32.write(value.index, into: &buf)
+        FfiConverterString.write(value.baseToken, into: &buf)
+        FfiConverterString.write(value.quoteToken, into: &buf)
+        FfiConverterUInt32.write(value.baseTokenIndex, into: &buf)
+        FfiConverterUInt32.write(value.quoteTokenIndex, into: &buf)
+        FfiConverterUInt32.write(value.szDecimals, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSpotAssetMeta_lift(_ buf: RustBuffer) throws -> SpotAssetMeta {
+    return try FfiConverterTypeSpotAssetMeta.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSpotAssetMeta_lower(_ value: SpotAssetMeta) -> RustBuffer {
+    return FfiConverterTypeSpotAssetMeta.lower(value)
+}
+
+
+public struct SpotBalance {
+    public var token: String
+    public var tokenIndex: UInt32
+    public var hold: Double
+    public var total: Double
+    public var entryNotional: Double
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(token: String, tokenIndex: UInt32, hold: Double, total: Double, entryNotional: Double) {
+        self.token = token
+        self.tokenIndex = tokenIndex
+        self.hold = hold
+        self.total = total
+        self.entryNotional = entryNotional
+    }
+}
+
+
+
+extension SpotBalance: Equatable, Hashable {
+    public static func ==(lhs: SpotBalance, rhs: SpotBalance) -> Bool {
+        if lhs.token != rhs.token {
+            return false
+        }
+        if lhs.tokenIndex != rhs.tokenIndex {
+            return false
+        }
+        if lhs.hold != rhs.hold {
+            return false
+        }
+        if lhs.total != rhs.total {
+            return false
+        }
+        if lhs.entryNotional != rhs.entryNotional {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(token)
+        hasher.combine(tokenIndex)
+        hasher.combine(hold)
+        hasher.combine(total)
+        hasher.combine(entryNotional)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSpotBalance: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SpotBalance {
+        return
+            try SpotBalance(
+                token: FfiConverterString.read(from: &buf), 
+                tokenIndex: FfiConverterUInt32.read(from: &buf), 
+                hold: FfiConverterDouble.read(from: &buf), 
+                total: FfiConverterDouble.read(from: &buf), 
+                entryNotional: FfiConverterDouble.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SpotBalance, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.token, into: &buf)
+        FfiConverterUInt32.write(value.tokenIndex, into: &buf)
+        FfiConverterDouble.write(value.hold, into: &buf)
+        FfiConverterDouble.write(value.total, into: &buf)
+        FfiConverterDouble.write(value.entryNotional, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSpotBalance_lift(_ buf: RustBuffer) throws -> SpotBalance {
+    return try FfiConverterTypeSpotBalance.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSpotBalance_lower(_ value: SpotBalance) -> RustBuffer {
+    return FfiConverterTypeSpotBalance.lower(value)
+}
+
+
+public struct SpotState {
+    public var address: String
+    public var balances: [SpotBalance]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(address: String, balances: [SpotBalance]) {
+        self.address = address
+        self.balances = balances
+    }
+}
+
+
+
+extension SpotState: Equatable, Hashable {
+    public static func ==(lhs: SpotState, rhs: SpotState) -> Bool {
+        if lhs.address != rhs.address {
+            return false
+        }
+        if lhs.balances != rhs.balances {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(address)
+        hasher.combine(balances)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSpotState: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SpotState {
+        return
+            try SpotState(
+                address: FfiConverterString.read(from: &buf), 
+                balances: FfiConverterSequenceTypeSpotBalance.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SpotState, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.address, into: &buf)
+        FfiConverterSequenceTypeSpotBalance.write(value.balances, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSpotState_lift(_ buf: RustBuffer) throws -> SpotState {
+    return try FfiConverterTypeSpotState.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSpotState_lower(_ value: SpotState) -> RustBuffer {
+    return FfiConverterTypeSpotState.lower(value)
+}
+
+
+public struct SubAccount {
+    public var name: String
+    public var address: String
+    public var master: String
+    public var state: UserState
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(name: String, address: String, master: String, state: UserState) {
+        self.name = name
+        self.address = address
+        self.master = master
+        self.state = state
+    }
+}
+
+
+
+extension SubAccount: Equatable, Hashable {
+    public static func ==(lhs: SubAccount, rhs: SubAccount) -> Bool {
+        if lhs.name != rhs.name {
+            return false
+        }
+        if lhs.address != rhs.address {
+            return false
+        }
+        if lhs.master != rhs.master {
+            return false
+        }
+        if lhs.state != rhs.state {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(name)
+        hasher.combine(address)
+        hasher.combine(master)
+        hasher.combine(state)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSubAccount: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SubAccount {
+        return
+            try SubAccount(
+                name: FfiConverterString.read(from: &buf), 
+                address: FfiConverterString.read(from: &buf), 
+                master: FfiConverterString.read(from: &buf), 
+                state: FfiConverterTypeUserState.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SubAccount, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterString.write(value.address, into: &buf)
+        FfiConverterString.write(value.master, into: &buf)
+        FfiConverterTypeUserState.write(value.state, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSubAccount_lift(_ buf: RustBuffer) throws -> SubAccount {
+    return try FfiConverterTypeSubAccount.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSubAccount_lower(_ value: SubAccount) -> RustBuffer {
+    return FfiConverterTypeSubAccount.lower(value)
+}
+
+
+public struct TriggerSpec {
+    public var triggerPrice: Double
+    public var limitPrice: Double?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(triggerPrice: Double, limitPrice: Double? = nil) {
+        self.triggerPrice = triggerPrice
+        self.limitPrice = limitPrice
+    }
+}
+
+
+
+extension TriggerSpec: Equatable, Hashable {
+    public static func ==(lhs: TriggerSpec, rhs: TriggerSpec) -> Bool {
+        if lhs.triggerPrice != rhs.triggerPrice {
+            return false
+        }
+        if lhs.limitPrice != rhs.limitPrice {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(triggerPrice)
+        hasher.combine(limitPrice)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTriggerSpec: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TriggerSpec {
+        return
+            try TriggerSpec(
+                triggerPrice: FfiConverterDouble.read(from: &buf), 
+                limitPrice: FfiConverterOptionDouble.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: TriggerSpec, into buf: inout [UInt8]) {
+        FfiConverterDouble.write(value.triggerPrice, into: &buf)
+        FfiConverterOptionDouble.write(value.limitPrice, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTriggerSpec_lift(_ buf: RustBuffer) throws -> TriggerSpec {
+    return try FfiConverterTypeTriggerSpec.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTriggerSpec_lower(_ value: TriggerSpec) -> RustBuffer {
+    return FfiConverterTypeTriggerSpec.lower(value)
+}
+
+
+public struct UserBalance {
+    public var token: String
+    public var hold: Double
+    public var total: Double
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(token: String, hold: Double, total: Double) {
+        self.token = token
+        self.hold = hold
+        self.total = total
+    }
+}
+
+
+
+extension UserBalance: Equatable, Hashable {
+    public static func ==(lhs: UserBalance, rhs: UserBalance) -> Bool {
+        if lhs.token != rhs.token {
+            return false
+        }
+        if lhs.hold != rhs.hold {
+            return false
+        }
+        if lhs.total != rhs.total {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(token)
+        hasher.combine(hold)
+        hasher.combine(total)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeUserBalance: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UserBalance {
+        return
+            try UserBalance(
+                token: FfiConverterString.read(from: &buf), 
+                hold: FfiConverterDouble.read(from: &buf), 
+                total: FfiConverterDouble.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: UserBalance, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.token, into: &buf)
+        FfiConverterDouble.write(value.hold, into: &buf)
+        FfiConverterDouble.write(value.total, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeUserBalance_lift(_ buf: RustBuffer) throws -> UserBalance {
+    return try FfiConverterTypeUserBalance.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeUserBalance_lower(_ value: UserBalance) -> RustBuffer {
+    return FfiConverterTypeUserBalance.lower(value)
+}
+
+
+public struct UserEvent {
+    public var kind: UserEventKind
+    public var asset: String
+    public var side: OrderSide
+    public var size: Double
+    public var price: Double
+    public var oid: UInt64
+    public var timestamp: UInt64
+    public var status: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(kind: UserEventKind, asset: String, side: OrderSide, size: Double, price: Double, oid: UInt64, timestamp: UInt64, status: String?) {
+        self.kind = kind
+        self.asset = asset
+        self.side = side
+        self.size = size
+        self.price = price
+        self.oid = oid
+        self.timestamp = timestamp
+        self.status = status
+    }
+}
+
+
+
+extension UserEvent: Equatable, Hashable {
+    public static func ==(lhs: UserEvent, rhs: UserEvent) -> Bool {
+        if lhs.kind != rhs.kind {
+            return false
+        }
+        if lhs.asset != rhs.asset {
+            return false
+        }
+        if lhs.side != rhs.side {
+            return false
+        }
+        if lhs.size != rhs.size {
+            return false
+        }
+        if lhs.price != rhs.price {
+            return false
+        }
+        if lhs.oid != rhs.oid {
+            return false
+        }
+        if lhs.timestamp != rhs.timestamp {
+            return false
+        }
+        if lhs.status != rhs.status {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(kind)
+        hasher.combine(asset)
+        hasher.combine(side)
+        hasher.combine(size)
+        hasher.combine(price)
+        hasher.combine(oid)
+        hasher.combine(timestamp)
+        hasher.combine(status)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeUserEvent: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UserEvent {
+        return
+            try UserEvent(
+                kind: FfiConverterTypeUserEventKind.read(from: &buf), 
+                asset: FfiConverterString.read(from: &buf), 
+                side: FfiConverterTypeOrderSide.read(from: &buf), 
+                size: FfiConverterDouble.read(from: &buf), 
+                price: FfiConverterDouble.read(from: &buf), 
+                oid: FfiConverterUInt64.read(from: &buf), 
+                timestamp: FfiConverterUInt64.read(from: &buf), 
+                status: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: UserEvent, into buf: inout [UInt8]) {
+        FfiConverterTypeUserEventKind.write(value.kind, into: &buf)
+        FfiConverterString.write(value.asset, into: &buf)
+        FfiConverterTypeOrderSide.write(value.side, into: &buf)
+        FfiConverterDouble.write(value.size, into: &buf)
+        FfiConverterDouble.write(value.price, into: &buf)
+        FfiConverterUInt64.write(value.oid, into: &buf)
+        FfiConverterUInt64.write(value.timestamp, into: &buf)
+        FfiConverterOptionString.write(value.status, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeUserEvent_lift(_ buf: RustBuffer) throws -> UserEvent {
+    return try FfiConverterTypeUserEvent.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeUserEvent_lower(_ value: UserEvent) -> RustBuffer {
+    return FfiConverterTypeUserEvent.lower(value)
+}
+
+
+public struct UserFunding {
+    public var asset: String
+    public var amount: Double
+    public var rate: Double
+    public var timestamp: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(asset: String, amount: Double, rate: Double, timestamp: UInt64) {
+        self.asset = asset
+        self.amount = amount
+        self.rate = rate
+        self.timestamp = timestamp
+    }
+}
+
+
+
+extension UserFunding: Equatable, Hashable {
+    public static func ==(lhs: UserFunding, rhs: UserFunding) -> Bool {
+        if lhs.asset != rhs.asset {
+            return false
+        }
+        if lhs.amount != rhs.amount {
+            return false
+        }
+        if lhs.rate != rhs.rate {
+            return false
+        }
+        if lhs.timestamp != rhs.timestamp {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(asset)
+        hasher.combine(amount)
+        hasher.combine(rate)
+        hasher.combine(timestamp)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeUserFunding: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UserFunding {
+        return
+            try UserFunding(
+                asset: FfiConverterString.read(from: &buf), 
+                amount: FfiConverterDouble.read(from: &buf), 
+                rate: FfiConverterDouble.read(from: &buf), 
+                timestamp: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: UserFunding, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.asset, into: &buf)
+        FfiConverterDouble.write(value.amount, into: &buf)
+        FfiConverterDouble.write(value.rate, into: &buf)
+        FfiConverterUInt64.write(value.timestamp, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeUserFunding_lift(_ buf: RustBuffer) throws -> UserFunding {
+    return try FfiConverterTypeUserFunding.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeUserFunding_lower(_ value: UserFunding) -> RustBuffer {
+    return FfiConverterTypeUserFunding.lower(value)
+}
+
+
+public struct UserOverview {
+    public var state: UserState
+    public var openOrders: [OpenOrder]
+    public var balances: [UserBalance]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(state: UserState, openOrders: [OpenOrder], balances: [UserBalance]) {
+        self.state = state
+        self.openOrders = openOrders
+        self.balances = balances
+    }
+}
+
+
+
+extension UserOverview: Equatable, Hashable {
+    public static func ==(lhs: UserOverview, rhs: UserOverview) -> Bool {
+        if lhs.state != rhs.state {
+            return false
+        }
+        if lhs.openOrders != rhs.openOrders {
+            return false
+        }
+        if lhs.balances != rhs.balances {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(state)
+        hasher.combine(openOrders)
+        hasher.combine(balances)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeUserOverview: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UserOverview {
+        return
+            try UserOverview(
+                state: FfiConverterTypeUserState.read(from: &buf), 
+                openOrders: FfiConverterSequenceTypeOpenOrder.read(from: &buf), 
+                balances: FfiConverterSequenceTypeUserBalance.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: UserOverview, into buf: inout [UInt8]) {
+        FfiConverterTypeUserState.write(value.state, into: &buf)
+        FfiConverterSequenceTypeOpenOrder.write(value.openOrders, into: &buf)
+        FfiConverterSequenceTypeUserBalance.write(value.balances, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeUserOverview_lift(_ buf: RustBuffer) throws -> UserOverview {
+    return try FfiConverterTypeUserOverview.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeUserOverview_lower(_ value: UserOverview) -> RustBuffer {
+    return FfiConverterTypeUserOverview.lower(value)
+}
+
+
+public struct UserState {
+    public var address: String
+    public var marginSummaryRawUsd: Double
+    public var marginSummaryAccountValue: Double
+    public var marginSummaryTotalMarginUsed: Double
+    public var positions: [Position]
+    public var withdrawable: Double
+    public var crossMaintenanceMarginUsed: Double
+    public var crossMarginSummary: MarginSummary
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(address: String, marginSummaryRawUsd: Double, marginSummaryAccountValue: Double, marginSummaryTotalMarginUsed: Double, positions: [Position], withdrawable: Double, crossMaintenanceMarginUsed: Double, crossMarginSummary: MarginSummary) {
+        self.address = address
+        self.marginSummaryRawUsd = marginSummaryRawUsd
+        self.marginSummaryAccountValue = marginSummaryAccountValue
+        self.marginSummaryTotalMarginUsed = marginSummaryTotalMarginUsed
+        self.positions = positions
+        self.withdrawable = withdrawable
+        self.crossMaintenanceMarginUsed = crossMaintenanceMarginUsed
+        self.crossMarginSummary = crossMarginSummary
+    }
+}
+
+
+
+extension UserState: Equatable, Hashable {
+    public static func ==(lhs: UserState, rhs: UserState) -> Bool {
+        if lhs.address != rhs.address {
+            return false
+        }
+        if lhs.marginSummaryRawUsd != rhs.marginSummaryRawUsd {
+            return false
+        }
+        if lhs.marginSummaryAccountValue != rhs.marginSummaryAccountValue {
+            return false
+        }
+        if lhs.marginSummaryTotalMarginUsed != rhs.marginSummaryTotalMarginUsed {
+            return false
+        }
+        if lhs.positions != rhs.positions {
+            return false
+        }
+        if lhs.withdrawable != rhs.withdrawable {
+            return false
+        }
+        if lhs.crossMaintenanceMarginUsed != rhs.crossMaintenanceMarginUsed {
+            return false
+        }
+        if lhs.crossMarginSummary != rhs.crossMarginSummary {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(address)
+        hasher.combine(marginSummaryRawUsd)
+        hasher.combine(marginSummaryAccountValue)
+        hasher.combine(marginSummaryTotalMarginUsed)
+        hasher.combine(positions)
+        hasher.combine(withdrawable)
+        hasher.combine(crossMaintenanceMarginUsed)
+        hasher.combine(crossMarginSummary)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeUserState: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UserState {
+        return
+            try UserState(
+                address: FfiConverterString.read(from: &buf), 
+                marginSummaryRawUsd: FfiConverterDouble.read(from: &buf), 
+                marginSummaryAccountValue: FfiConverterDouble.read(from: &buf), 
+                marginSummaryTotalMarginUsed: FfiConverterDouble.read(from: &buf), 
+                positions: FfiConverterSequenceTypePosition.read(from: &buf), 
+                withdrawable: FfiConverterDouble.read(from: &buf), 
+                crossMaintenanceMarginUsed: FfiConverterDouble.read(from: &buf), 
+                crossMarginSummary: FfiConverterTypeMarginSummary.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: UserState, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.address, into: &buf)
+        FfiConverterDouble.write(value.marginSummaryRawUsd, into: &buf)
+        FfiConverterDouble.write(value.marginSummaryAccountValue, into: &buf)
+        FfiConverterDouble.write(value.marginSummaryTotalMarginUsed, into: &buf)
+        FfiConverterSequenceTypePosition.write(value.positions, into: &buf)
+        FfiConverterDouble.write(value.withdrawable, into: &buf)
+        FfiConverterDouble.write(value.crossMaintenanceMarginUsed, into: &buf)
+        FfiConverterTypeMarginSummary.write(value.crossMarginSummary, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeUserState_lift(_ buf: RustBuffer) throws -> UserState {
+    return try FfiConverterTypeUserState.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeUserState_lower(_ value: UserState) -> RustBuffer {
+    return FfiConverterTypeUserState.lower(value)
+}
+
+
+public struct UserStateResult {
+    public var address: String
+    public var state: UserState?
+    public var error: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(address: String, state: UserState?, error: String?) {
+        self.address = address
+        self.state = state
+        self.error = error
+    }
+}
+
+
+
+extension UserStateResult: Equatable, Hashable {
+    public static func ==(lhs: UserStateResult, rhs: UserStateResult) -> Bool {
+        if lhs.address != rhs.address {
+            return false
+        }
+        if lhs.state != rhs.state {
+            return false
+        }
+        if lhs.error != rhs.error {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(address)
+        hasher.combine(state)
+        hasher.combine(error)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeUserStateResult: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UserStateResult {
+        return
+            try UserStateResult(
+                address: FfiConverterString.read(from: &buf), 
+                state: FfiConverterOptionTypeUserState.read(from: &buf), 
+                error: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: UserStateResult, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.address, into: &buf)
+        FfiConverterOptionTypeUserState.write(value.state, into: &buf)
+        FfiConverterOptionString.write(value.error, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeUserStateResult_lift(_ buf: RustBuffer) throws -> UserStateResult {
+    return try FfiConverterTypeUserStateResult.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeUserStateResult_lower(_ value: UserStateResult) -> RustBuffer {
+    return FfiConverterTypeUserStateResult.lower(value)
+}
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum BaseUrl {
+    
+    case mainnet
+    case testnet
+    case custom(url: String
+    )
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeBaseUrl: FfiConverterRustBuffer {
+    typealias SwiftType = BaseUrl
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> BaseUrl {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .mainnet
+        
+        case 2: return .testnet
+        
+        case 3: return .custom(url: try FfiConverterString.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: BaseUrl, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .mainnet:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .testnet:
+            writeInt(&buf, Int32(2))
+        
+        
+        case let .custom(url):
+            writeInt(&buf, Int32(3))
+            FfiConverterString.write(url, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeBaseUrl_lift(_ buf: RustBuffer) throws -> BaseUrl {
+    return try FfiConverterTypeBaseUrl.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeBaseUrl_lower(_ value: BaseUrl) -> RustBuffer {
+    return FfiConverterTypeBaseUrl.lower(value)
+}
+
+
+
+extension BaseUrl: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum ConnectionStatus {
+    
+    case connected
+    case reconnecting
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeConnectionStatus: FfiConverterRustBuffer {
+    typealias SwiftType = ConnectionStatus
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ConnectionStatus {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .connected
+        
+        case 2: return .reconnecting
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: ConnectionStatus, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .connected:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .reconnecting:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeConnectionStatus_lift(_ buf: RustBuffer) throws -> ConnectionStatus {
+    return try FfiConverterTypeConnectionStatus.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeConnectionStatus_lower(_ value: ConnectionStatus) -> RustBuffer {
+    return FfiConverterTypeConnectionStatus.lower(value)
+}
+
+
+
+extension ConnectionStatus: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum ExchangeStatus {
+    
+    case ok
+    case waiting
+    case err(message: String
+    )
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeExchangeStatus: FfiConverterRustBuffer {
+    typealias SwiftType = ExchangeStatus
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ExchangeStatus {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .ok
+        
+        case 2: return .waiting
+        
+        case 3: return .err(message: try FfiConverterString.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: ExchangeStatus, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .ok:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .waiting:
+            writeInt(&buf, Int32(2))
+        
+        
+        case let .err(message):
+            writeInt(&buf, Int32(3))
+            FfiConverterString.write(message, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeExchangeStatus_lift(_ buf: RustBuffer) throws -> ExchangeStatus {
+    return try FfiConverterTypeExchangeStatus.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeExchangeStatus_lower(_ value: ExchangeStatus) -> RustBuffer {
+    return FfiConverterTypeExchangeStatus.lower(value)
+}
+
+
+
+extension ExchangeStatus: Equatable, Hashable {}
+
+
+
+
+public enum HyperliquidError {
+
+    
+    
+    case InvalidPrivateKey(message: String
+    )
+    case InvalidMnemonic(message: String
+    )
+    case NetworkError(message: String
+    )
+    case ApiError(message: String
+    )
+    case InvalidInput(message: String
+    )
+    case InsufficientMargin(message: String
+    )
+    case InvalidOrder(message: String
+    )
+    case RateLimited(retryAfterMs: UInt64?
+    )
+    case Cancelled
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeHyperliquidError: FfiConverterRustBuffer {
+    typealias SwiftType = HyperliquidError
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> HyperliquidError {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+
+        
+
+        
+        case 1: return .InvalidPrivateKey(
+            message: try FfiConverterString.read(from: &buf)
+            )
+        case 2: return .InvalidMnemonic(
+            message: try FfiConverterString.read(from: &buf)
+            )
+        case 3: return .NetworkError(
+            message: try FfiConverterString.read(from: &buf)
+            )
+        case 4: return .ApiError(
+            message: try FfiConverterString.read(from: &buf)
+            )
+        case 5: return .InvalidInput(
+            message: try FfiConverterString.read(from: &buf)
+            )
+        case 6: return .InsufficientMargin(
+            message: try FfiConverterString.read(from: &buf)
+            )
+        case 7: return .InvalidOrder(
+            message: try FfiConverterString.read(from: &buf)
+            )
+        case 8: return .RateLimited(
+            retryAfterMs: try FfiConverterOptionUInt64.read(from: &buf)
+            )
+        case 9: return .Cancelled
+
+         default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: HyperliquidError, into buf: inout [UInt8]) {
+        switch value {
+
+        
+
+        
+        
+        case let .InvalidPrivateKey(message):
+            writeInt(&buf, Int32(1))
+            FfiConverterString.write(message, into: &buf)
+            
+        
+        case let .InvalidMnemonic(message):
+            writeInt(&buf, Int32(2))
+            FfiConverterString.write(message, into: &buf)
+            
+        
+        case let .NetworkError(message):
+            writeInt(&buf, Int32(3))
+            FfiConverterString.write(message, into: &buf)
+            
+        
+        case let .ApiError(message):
+            writeInt(&buf, Int32(4))
+            FfiConverterString.write(message, into: &buf)
+            
+        
+        case let .InvalidInput(message):
+            writeInt(&buf, Int32(5))
+            FfiConverterString.write(message, into: &buf)
+            
+        
+        case let .InsufficientMargin(message):
+            writeInt(&buf, Int32(6))
+            FfiConverterString.write(message, into: &buf)
+            
+        
+        case let .InvalidOrder(message):
+            writeInt(&buf, Int32(7))
+            FfiConverterString.write(message, into: &buf)
+            
+        
+        case let .RateLimited(retryAfterMs):
+            writeInt(&buf, Int32(8))
+            FfiConverterOptionUInt64.write(retryAfterMs, into: &buf)
+            
+        
+        case .Cancelled:
+            writeInt(&buf, Int32(9))
+        
+        }
+    }
+}
+
+
+extension HyperliquidError: Equatable, Hashable {}
+
+extension HyperliquidError: Foundation.LocalizedError {
+    public var errorDescription: String? {
+        String(reflecting: self)
+    }
+}
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum LogLevel {
+    
+    case error
+    case warn
+    case info
+    case debug
+    case trace
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeLogLevel: FfiConverterRustBuffer {
+    typealias SwiftType = LogLevel
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LogLevel {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .error
+        
+        case 2: return .warn
+        
+        case 3: return .info
+        
+        case 4: return .debug
+        
+        case 5: return .trace
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: LogLevel, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .error:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .warn:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .info:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .debug:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .trace:
+            writeInt(&buf, Int32(5))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeLogLevel_lift(_ buf: RustBuffer) throws -> LogLevel {
+    return try FfiConverterTypeLogLevel.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeLogLevel_lower(_ value: LogLevel) -> RustBuffer {
+    return FfiConverterTypeLogLevel.lower(value)
+}
+
+
+
+extension LogLevel: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum MarginMode {
+    
+    case cross
+    case isolated
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeMarginMode: FfiConverterRustBuffer {
+    typealias SwiftType = MarginMode
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> MarginMode {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .cross
+        
+        case 2: return .isolated
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: MarginMode, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .cross:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .isolated:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeMarginMode_lift(_ buf: RustBuffer) throws -> MarginMode {
+    return try FfiConverterTypeMarginMode.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeMarginMode_lower(_ value: MarginMode) -> RustBuffer {
+    return FfiConverterTypeMarginMode.lower(value)
+}
+
+
+
+extension MarginMode: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum Network {
+    
+    case mainnet
+    case testnet
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeNetwork: FfiConverterRustBuffer {
+    typealias SwiftType = Network
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Network {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .mainnet
+        
+        case 2: return .testnet
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: Network, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .mainnet:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .testnet:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeNetwork_lift(_ buf: RustBuffer) throws -> Network {
+    return try FfiConverterTypeNetwork.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeNetwork_lower(_ value: Network) -> RustBuffer {
+    return FfiConverterTypeNetwork.lower(value)
+}
+
+
+
+extension Network: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum OrderGrouping {
+    
+    case na
+    case normalTpsl
+    case positionTpsl
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeOrderGrouping: FfiConverterRustBuffer {
+    typealias SwiftType = OrderGrouping
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> OrderGrouping {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .na
+        
+        case 2: return .normalTpsl
+        
+        case 3: return .positionTpsl
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: OrderGrouping, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .na:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .normalTpsl:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .positionTpsl:
+            writeInt(&buf, Int32(3))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOrderGrouping_lift(_ buf: RustBuffer) throws -> OrderGrouping {
+    return try FfiConverterTypeOrderGrouping.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOrderGrouping_lower(_ value: OrderGrouping) -> RustBuffer {
+    return FfiConverterTypeOrderGrouping.lower(value)
+}
+
+
+
+extension OrderGrouping: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum OrderSide {
+    
+    case buy
+    case sell
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeOrderSide: FfiConverterRustBuffer {
+    typealias SwiftType = OrderSide
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> OrderSide {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .buy
+        
+        case 2: return .sell
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: OrderSide, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .buy:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .sell:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOrderSide_lift(_ buf: RustBuffer) throws -> OrderSide {
+    return try FfiConverterTypeOrderSide.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOrderSide_lower(_ value: OrderSide) -> RustBuffer {
+    return FfiConverterTypeOrderSide.lower(value)
+}
+
+
+
+extension OrderSide: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum OrderState {
+    
+    case resting
+    case filled
+    case waitingForFill
+    case waitingForTrigger
+    case canceled
+    case rejected
+    case accepted
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeOrderState: FfiConverterRustBuffer {
+    typealias SwiftType = OrderState
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> OrderState {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .resting
+        
+        case 2: return .filled
+        
+        case 3: return .waitingForFill
+        
+        case 4: return .waitingForTrigger
+        
+        case 5: return .canceled
+        
+        case 6: return .rejected
+        
+        case 7: return .accepted
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: OrderState, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .resting:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .filled:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .waitingForFill:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .waitingForTrigger:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .canceled:
+            writeInt(&buf, Int32(5))
+        
+        
+        case .rejected:
+            writeInt(&buf, Int32(6))
+        
+        
+        case .accepted:
+            writeInt(&buf, Int32(7))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOrderState_lift(_ buf: RustBuffer) throws -> OrderState {
+    return try FfiConverterTypeOrderState.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOrderState_lower(_ value: OrderState) -> RustBuffer {
+    return FfiConverterTypeOrderState.lower(value)
+}
+
+
+
+extension OrderState: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum OrderType {
+    
+    case limit
+    case market
+    case trigger(triggerPrice: Double, isMarket: Bool, tpsl: TpSl
+    )
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeOrderType: FfiConverterRustBuffer {
+    typealias SwiftType = OrderType
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> OrderType {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .limit
+        
+        case 2: return .market
+        
+        case 3: return .trigger(triggerPrice: try FfiConverterDouble.read(from: &buf), isMarket: try FfiConverterBool.read(from: &buf), tpsl: try FfiConverterTypeTpSl.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: OrderType, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .limit:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .market:
+            writeInt(&buf, Int32(2))
+        
+        
+        case let .trigger(triggerPrice,isMarket,tpsl):
+            writeInt(&buf, Int32(3))
+            FfiConverterDouble.write(triggerPrice, into: &buf)
+            FfiConverterBool.write(isMarket, into: &buf)
+            FfiConverterTypeTpSl.write(tpsl, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOrderType_lift(_ buf: RustBuffer) throws -> OrderType {
+    return try FfiConverterTypeOrderType.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOrderType_lower(_ value: OrderType) -> RustBuffer {
+    return FfiConverterTypeOrderType.lower(value)
+}
+
+
+
+extension OrderType: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum RuntimeFlavor {
+    
+    case multiThread
+    case currentThread
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRuntimeFlavor: FfiConverterRustBuffer {
+    typealias SwiftType = RuntimeFlavor
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RuntimeFlavor {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .multiThread
+        
+        case 2: return .currentThread
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: RuntimeFlavor, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .multiThread:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .currentThread:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRuntimeFlavor_lift(_ buf: RustBuffer) throws -> RuntimeFlavor {
+    return try FfiConverterTypeRuntimeFlavor.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRuntimeFlavor_lower(_ value: RuntimeFlavor) -> RustBuffer {
+    return FfiConverterTypeRuntimeFlavor.lower(value)
+}
+
+
+
+extension RuntimeFlavor: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum TimeInForce {
+    
+    case gtc
+    case ioc
+    case alo
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTimeInForce: FfiConverterRustBuffer {
+    typealias SwiftType = TimeInForce
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TimeInForce {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .gtc
+        
+        case 2: return .ioc
+        
+        case 3: return .alo
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: TimeInForce, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .gtc:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .ioc:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .alo:
+            writeInt(&buf, Int32(3))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTimeInForce_lift(_ buf: RustBuffer) throws -> TimeInForce {
+    return try FfiConverterTypeTimeInForce.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTimeInForce_lower(_ value: TimeInForce) -> RustBuffer {
+    return FfiConverterTypeTimeInForce.lower(value)
+}
+
+
+
+extension TimeInForce: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum TpSl {
+    
+    case takeProfit
+    case stopLoss
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTpSl: FfiConverterRustBuffer {
+    typealias SwiftType = TpSl
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TpSl {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .takeProfit
+        
+        case 2: return .stopLoss
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: TpSl, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .takeProfit:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .stopLoss:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTpSl_lift(_ buf: RustBuffer) throws -> TpSl {
+    return try FfiConverterTypeTpSl.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTpSl_lower(_ value: TpSl) -> RustBuffer {
+    return FfiConverterTypeTpSl.lower(value)
+}
+
+
+
+extension TpSl: Equatable, Hashable {}
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum UserEventKind {
+    
+    case fill
+    case orderUpdate
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeUserEventKind: FfiConverterRustBuffer {
+    typealias SwiftType = UserEventKind
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UserEventKind {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .fill
+        
+        case 2: return .orderUpdate
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: UserEventKind, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .fill:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .orderUpdate:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeUserEventKind_lift(_ buf: RustBuffer) throws -> UserEventKind {
+    return try FfiConverterTypeUserEventKind.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeUserEventKind_lower(_ value: UserEventKind) -> RustBuffer {
+    return FfiConverterTypeUserEventKind.lower(value)
+}
+
+
+
+extension UserEventKind: Equatable, Hashable {}
+
+
+
+
+
+
+public protocol ClockDriftCallback : AnyObject {
+    
+    func onClockDrift(driftMs: Int64) 
+    
+}
+
+// Magic number for the Rust proxy to call using the same mechanism as every other method,
+// to free the callback once it's dropped by Rust.
+private let IDX_CALLBACK_FREE: Int32 = 0
+// Callback return codes
+private let UNIFFI_CALLBACK_SUCCESS: Int32 = 0
+private let UNIFFI_CALLBACK_ERROR: Int32 = 1
+private let UNIFFI_CALLBACK_UNEXPECTED_ERROR: Int32 = 2
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+fileprivate struct UniffiCallbackInterfaceClockDriftCallback {
+
+    // Create the VTable using a series of closures.
+    // Swift automatically converts these into C callback functions.
+    static var vtable: UniffiVTableCallbackInterfaceClockDriftCallback = UniffiVTableCallbackInterfaceClockDriftCallback(
+        onClockDrift: { (
+            uniffiHandle: UInt64,
+            driftMs: Int64,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterCallbackInterfaceClockDriftCallback.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.onClockDrift(
+                     driftMs: try FfiConverterInt64.lift(driftMs)
+                )
+            }
+
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        },
+        uniffiFree: { (uniffiHandle: UInt64) -> () in
+            let result = try? FfiConverterCallbackInterfaceClockDriftCallback.handleMap.remove(handle: uniffiHandle)
+            if result == nil {
+                print("Uniffi callback interface ClockDriftCallback: handle missing in uniffiFree")
+            }
+        }
+    )
+}
+
+private func uniffiCallbackInitClockDriftCallback() {
+    uniffi_hyperliquid_swift_fn_init_callback_vtable_clockdriftcallback(&UniffiCallbackInterfaceClockDriftCallback.vtable)
+}
+
+// FfiConverter protocol for callback interfaces
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterCallbackInterfaceClockDriftCallback {
+    fileprivate static var handleMap = UniffiHandleMap<ClockDriftCallback>()
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+extension FfiConverterCallbackInterfaceClockDriftCallback : FfiConverter {
+    typealias SwiftType = ClockDriftCallback
+    typealias FfiType = UInt64
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lift(_ handle: UInt64) throws -> SwiftType {
+        try handleMap.get(handle: handle)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lower(_ v: SwiftType) -> UInt64 {
+        return handleMap.insert(obj: v)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func write(_ v: SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(v))
+    }
+}
+
+
+
+
+public protocol ConnectionStatusCallback : AnyObject {
+    
+    func onStatus(status: ConnectionStatus) 
+    
+}
+
+
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+fileprivate struct UniffiCallbackInterfaceConnectionStatusCallback {
+
+    // Create the VTable using a series of closures.
+    // Swift automatically converts these into C callback functions.
+    static var vtable: UniffiVTableCallbackInterfaceConnectionStatusCallback = UniffiVTableCallbackInterfaceConnectionStatusCallback(
+        onStatus: { (
+            uniffiHandle: UInt64,
+            status: RustBuffer,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterCallbackInterfaceConnectionStatusCallback.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.onStatus(
+                     status: try FfiConverterTypeConnectionStatus.lift(status)
+                )
+            }
+
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        },
+        uniffiFree: { (uniffiHandle: UInt64) -> () in
+            let result = try? FfiConverterCallbackInterfaceConnectionStatusCallback.handleMap.remove(handle: uniffiHandle)
+            if result == nil {
+                print("Uniffi callback interface ConnectionStatusCallback: handle missing in uniffiFree")
+            }
+        }
+    )
+}
+
+private func uniffiCallbackInitConnectionStatusCallback() {
+    uniffi_hyperliquid_swift_fn_init_callback_vtable_connectionstatuscallback(&UniffiCallbackInterfaceConnectionStatusCallback.vtable)
+}
+
+// FfiConverter protocol for callback interfaces
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterCallbackInterfaceConnectionStatusCallback {
+    fileprivate static var handleMap = UniffiHandleMap<ConnectionStatusCallback>()
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+extension FfiConverterCallbackInterfaceConnectionStatusCallback : FfiConverter {
+    typealias SwiftType = ConnectionStatusCallback
+    typealias FfiType = UInt64
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lift(_ handle: UInt64) throws -> SwiftType {
+        try handleMap.get(handle: handle)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lower(_ v: SwiftType) -> UInt64 {
+        return handleMap.insert(obj: v)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func write(_ v: SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(v))
+    }
+}
+
+
+
+
+public protocol L2BookCallback : AnyObject {
+    
+    func onBook(book: L2Book) 
+    
+}
+
+
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+fileprivate struct UniffiCallbackInterfaceL2BookCallback {
+
+    // Create the VTable using a series of closures.
+    // Swift automatically converts these into C callback functions.
+    static var vtable: UniffiVTableCallbackInterfaceL2BookCallback = UniffiVTableCallbackInterfaceL2BookCallback(
+        onBook: { (
+            uniffiHandle: UInt64,
+            book: RustBuffer,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterCallbackInterfaceL2BookCallback.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.onBook(
+                     book: try FfiConverterTypeL2Book.lift(book)
+                )
+            }
+
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        },
+        uniffiFree: { (uniffiHandle: UInt64) -> () in
+            let result = try? FfiConverterCallbackInterfaceL2BookCallback.handleMap.remove(handle: uniffiHandle)
+            if result == nil {
+                print("Uniffi callback interface L2BookCallback: handle missing in uniffiFree")
+            }
+        }
+    )
+}
+
+private func uniffiCallbackInitL2BookCallback() {
+    uniffi_hyperliquid_swift_fn_init_callback_vtable_l2bookcallback(&UniffiCallbackInterfaceL2BookCallback.vtable)
+}
+
+// FfiConverter protocol for callback interfaces
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterCallbackInterfaceL2BookCallback {
+    fileprivate static var handleMap = UniffiHandleMap<L2BookCallback>()
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+extension FfiConverterCallbackInterfaceL2BookCallback : FfiConverter {
+    typealias SwiftType = L2BookCallback
+    typealias FfiType = UInt64
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lift(_ handle: UInt64) throws -> SwiftType {
+        try handleMap.get(handle: handle)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lower(_ v: SwiftType) -> UInt64 {
+        return handleMap.insert(obj: v)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func write(_ v: SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(v))
+    }
+}
+
+
+
+
+public protocol LogCallback : AnyObject {
+    
+    func onLog(level: LogLevel, target: String, message: String) 
+    
+}
+
+
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+fileprivate struct UniffiCallbackInterfaceLogCallback {
+
+    // Create the VTable using a series of closures.
+    // Swift automatically converts these into C callback functions.
+    static var vtable: UniffiVTableCallbackInterfaceLogCallback = UniffiVTableCallbackInterfaceLogCallback(
+        onLog: { (
+            uniffiHandle: UInt64,
+            level: RustBuffer,
+            target: RustBuffer,
+            message: RustBuffer,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterCallbackInterfaceLogCallback.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.onLog(
+                     level: try FfiConverterTypeLogLevel.lift(level),
+                     target: try FfiConverterString.lift(target),
+                     message: try FfiConverterString.lift(message)
+                )
+            }
+
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        },
+        uniffiFree: { (uniffiHandle: UInt64) -> () in
+            let result = try? FfiConverterCallbackInterfaceLogCallback.handleMap.remove(handle: uniffiHandle)
+            if result == nil {
+                print("Uniffi callback interface LogCallback: handle missing in uniffiFree")
+            }
+        }
+    )
+}
+
+private func uniffiCallbackInitLogCallback() {
+    uniffi_hyperliquid_swift_fn_init_callback_vtable_logcallback(&UniffiCallbackInterfaceLogCallback.vtable)
+}
+
+// FfiConverter protocol for callback interfaces
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterCallbackInterfaceLogCallback {
+    fileprivate static var handleMap = UniffiHandleMap<LogCallback>()
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+extension FfiConverterCallbackInterfaceLogCallback : FfiConverter {
+    typealias SwiftType = LogCallback
+    typealias FfiType = UInt64
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lift(_ handle: UInt64) throws -> SwiftType {
+        try handleMap.get(handle: handle)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lower(_ v: SwiftType) -> UInt64 {
+        return handleMap.insert(obj: v)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func write(_ v: SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(v))
+    }
+}
+
+
+
+
+public protocol MidsCallback : AnyObject {
+    
+    func onMids(mids: [String: Double]) 
+    
+}
+
+
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+fileprivate struct UniffiCallbackInterfaceMidsCallback {
+
+    // Create the VTable using a series of closures.
+    // Swift automatically converts these into C callback functions.
+    static var vtable: UniffiVTableCallbackInterfaceMidsCallback = UniffiVTableCallbackInterfaceMidsCallback(
+        onMids: { (
+            uniffiHandle: UInt64,
+            mids: RustBuffer,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterCallbackInterfaceMidsCallback.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.onMids(
+                     mids: try FfiConverterDictionaryStringDouble.lift(mids)
+                )
+            }
+
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        },
+        uniffiFree: { (uniffiHandle: UInt64) -> () in
+            let result = try? FfiConverterCallbackInterfaceMidsCallback.handleMap.remove(handle: uniffiHandle)
+            if result == nil {
+                print("Uniffi callback interface MidsCallback: handle missing in uniffiFree")
+            }
+        }
+    )
+}
+
+private func uniffiCallbackInitMidsCallback() {
+    uniffi_hyperliquid_swift_fn_init_callback_vtable_midscallback(&UniffiCallbackInterfaceMidsCallback.vtable)
+}
+
+// FfiConverter protocol for callback interfaces
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterCallbackInterfaceMidsCallback {
+    fileprivate static var handleMap = UniffiHandleMap<MidsCallback>()
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+extension FfiConverterCallbackInterfaceMidsCallback : FfiConverter {
+    typealias SwiftType = MidsCallback
+    typealias FfiType = UInt64
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lift(_ handle: UInt64) throws -> SwiftType {
+        try handleMap.get(handle: handle)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lower(_ v: SwiftType) -> UInt64 {
+        return handleMap.insert(obj: v)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func write(_ v: SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(v))
+    }
+}
+
+
+
+
+public protocol UserEventCallback : AnyObject {
+    
+    func onEvent(event: UserEvent) 
+    
+}
+
+
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+fileprivate struct UniffiCallbackInterfaceUserEventCallback {
+
+    // Create the VTable using a series of closures.
+    // Swift automatically converts these into C callback functions.
+    static var vtable: UniffiVTableCallbackInterfaceUserEventCallback = UniffiVTableCallbackInterfaceUserEventCallback(
+        onEvent: { (
+            uniffiHandle: UInt64,
+            event: RustBuffer,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterCallbackInterfaceUserEventCallback.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.onEvent(
+                     event: try FfiConverterTypeUserEvent.lift(event)
+                )
+            }
+
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        },
+        uniffiFree: { (uniffiHandle: UInt64) -> () in
+            let result = try? FfiConverterCallbackInterfaceUserEventCallback.handleMap.remove(handle: uniffiHandle)
+            if result == nil {
+                print("Uniffi callback interface UserEventCallback: handle missing in uniffiFree")
+            }
+        }
+    )
+}
+
+private func uniffiCallbackInitUserEventCallback() {
+    uniffi_hyperliquid_swift_fn_init_callback_vtable_usereventcallback(&UniffiCallbackInterfaceUserEventCallback.vtable)
+}
+
+// FfiConverter protocol for callback interfaces
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterCallbackInterfaceUserEventCallback {
+    fileprivate static var handleMap = UniffiHandleMap<UserEventCallback>()
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+extension FfiConverterCallbackInterfaceUserEventCallback : FfiConverter {
+    typealias SwiftType = UserEventCallback
+    typealias FfiType = UInt64
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lift(_ handle: UInt64) throws -> SwiftType {
+        try handleMap.get(handle: handle)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lower(_ v: SwiftType) -> UInt64 {
+        return handleMap.insert(obj: v)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func write(_ v: SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(v))
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionUInt32: FfiConverterRustBuffer {
+    typealias SwiftType = UInt32?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterUInt32.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterUInt32.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionUInt64: FfiConverterRustBuffer {
+    typealias SwiftType = UInt64?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterUInt64.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterUInt64.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionDouble: FfiConverterRustBuffer {
+    typealias SwiftType = Double?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterDouble.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterDouble.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionString: FfiConverterRustBuffer {
+    typealias SwiftType = String?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterString.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterString.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeConnectionPool: FfiConverterRustBuffer {
+    typealias SwiftType = ConnectionPool?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeConnectionPool.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeConnectionPool.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeBuilderInfo: FfiConverterRustBuffer {
+    typealias SwiftType = BuilderInfo?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeBuilderInfo.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeBuilderInfo.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeExchangeClientConfig: FfiConverterRustBuffer {
+    typealias SwiftType = ExchangeClientConfig?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeExchangeClientConfig.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeExchangeClientConfig.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeInfoClientConfig: FfiConverterRustBuffer {
+    typealias SwiftType = InfoClientConfig?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeInfoClientConfig.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeInfoClientConfig.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeOpenOrder: FfiConverterRustBuffer {
+    typealias SwiftType = OpenOrder?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeOpenOrder.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeOpenOrder.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeRateLimitConfig: FfiConverterRustBuffer {
+    typealias SwiftType = RateLimitConfig?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeRateLimitConfig.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeRateLimitConfig.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeRetryConfig: FfiConverterRustBuffer {
+    typealias SwiftType = RetryConfig?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeRetryConfig.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeRetryConfig.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeTriggerSpec: FfiConverterRustBuffer {
+    typealias SwiftType = TriggerSpec?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeTriggerSpec.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeTriggerSpec.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeUserState: FfiConverterRustBuffer {
+    typealias SwiftType = UserState?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeUserState.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeUserState.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeTpSl: FfiConverterRustBuffer {
+    typealias SwiftType = TpSl?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeTpSl.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeTpSl.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceString: FfiConverterRustBuffer {
+    typealias SwiftType = [String]
+
+    public static func write(_ value: [String], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterString.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [String] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [String]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterString.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeAssetContext: FfiConverterRustBuffer {
+    typealias SwiftType = [AssetContext]
+
+    public static func write(_ value: [AssetContext], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeAssetContext.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [AssetContext] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [AssetContext]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeAssetContext.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeAssetMeta: FfiConverterRustBuffer {
+    typealias SwiftType = [AssetMeta]
+
+    public static func write(_ value: [AssetMeta], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeAssetMeta.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [AssetMeta] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [AssetMeta]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeAssetMeta.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeAssetMetaWithContext: FfiConverterRustBuffer {
+    typealias SwiftType = [AssetMetaWithContext]
+
+    public static func write(_ value: [AssetMetaWithContext], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeAssetMetaWithContext.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [AssetMetaWithContext] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [AssetMetaWithContext]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeAssetMetaWithContext.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeBookLevel: FfiConverterRustBuffer {
+    typealias SwiftType = [BookLevel]
+
+    public static func write(_ value: [BookLevel], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeBookLevel.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [BookLevel] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [BookLevel]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeBookLevel.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeCancelRequest: FfiConverterRustBuffer {
+    typealias SwiftType = [CancelRequest]
+
+    public static func write(_ value: [CancelRequest], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeCancelRequest.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [CancelRequest] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [CancelRequest]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeCancelRequest.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeCancelResponse: FfiConverterRustBuffer {
+    typealias SwiftType = [CancelResponse]
+
+    public static func write(_ value: [CancelResponse], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeCancelResponse.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [CancelResponse] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [CancelResponse]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeCancelResponse.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeCandle: FfiConverterRustBuffer {
+    typealias SwiftType = [Candle]
+
+    public static func write(_ value: [Candle], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeCandle.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Candle] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [Candle]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeCandle.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeFill: FfiConverterRustBuffer {
+    typealias SwiftType = [Fill]
+
+    public static func write(_ value: [Fill], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeFill.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Fill] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [Fill]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeFill.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeFrontendOrder: FfiConverterRustBuffer {
+    typealias SwiftType = [FrontendOrder]
+
+    public static func write(_ value: [FrontendOrder], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeFrontendOrder.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FrontendOrder] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [FrontendOrder]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeFrontendOrder.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeFundingEntry: FfiConverterRustBuffer {
+    typealias SwiftType = [FundingEntry]
+
+    public static func write(_ value: [FundingEntry], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeFundingEntry.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [FundingEntry] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [FundingEntry]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeFundingEntry.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeHistoricalOrder: FfiConverterRustBuffer {
+    typealias SwiftType = [HistoricalOrder]
+
+    public static func write(_ value: [HistoricalOrder], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeHistoricalOrder.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [HistoricalOrder] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [HistoricalOrder]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeHistoricalOrder.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeLedgerUpdate: FfiConverterRustBuffer {
+    typealias SwiftType = [LedgerUpdate]
+
+    public static func write(_ value: [LedgerUpdate], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeLedgerUpdate.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [LedgerUpdate] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [LedgerUpdate]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeLedgerUpdate.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeModifyRequest: FfiConverterRustBuffer {
+    typealias SwiftType = [ModifyRequest]
+
+    public static func write(_ value: [ModifyRequest], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeModifyRequest.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [ModifyRequest] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [ModifyRequest]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeModifyRequest.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeOpenOrder: FfiConverterRustBuffer {
+    typealias SwiftType = [OpenOrder]
+
+    public static func write(_ value: [OpenOrder], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeOpenOrder.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [OpenOrder] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [OpenOrder]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeOpenOrder.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeOrderRequest: FfiConverterRustBuffer {
+    typealias SwiftType = [OrderRequest]
+
+    public static func write(_ value: [OrderRequest], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeOrderRequest.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [OrderRequest] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [OrderRequest]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeOrderRequest.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeOrderResponse: FfiConverterRustBuffer {
+    typealias SwiftType = [OrderResponse]
+
+    public static func write(_ value: [OrderResponse], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeOrderResponse.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [OrderResponse] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [OrderResponse]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeOrderResponse.read(from: &buf))
+        }
+        return seq
+    }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypePosition: FfiConverterRustBuffer {
+    typealias SwiftType = [Position]
 
-extension HyperliquidError: Equatable, Hashable {}
-
-extension HyperliquidError: Foundation.LocalizedError {
-    public var errorDescription: String? {
-        String(reflecting: self)
+    public static func write(_ value: [Position], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypePosition.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Position] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [Position]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypePosition.read(from: &buf))
+        }
+        return seq
     }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionString: FfiConverterRustBuffer {
-    typealias SwiftType = String?
+fileprivate struct FfiConverterSequenceTypePredictedFunding: FfiConverterRustBuffer {
+    typealias SwiftType = [PredictedFunding]
+
+    public static func write(_ value: [PredictedFunding], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypePredictedFunding.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [PredictedFunding] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [PredictedFunding]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypePredictedFunding.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeSpotAssetMeta: FfiConverterRustBuffer {
+    typealias SwiftType = [SpotAssetMeta]
+
+    public static func write(_ value: [SpotAssetMeta], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeSpotAssetMeta.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [SpotAssetMeta] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [SpotAssetMeta]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeSpotAssetMeta.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeSpotBalance: FfiConverterRustBuffer {
+    typealias SwiftType = [SpotBalance]
+
+    public static func write(_ value: [SpotBalance], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeSpotBalance.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [SpotBalance] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [SpotBalance]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeSpotBalance.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeSubAccount: FfiConverterRustBuffer {
+    typealias SwiftType = [SubAccount]
+
+    public static func write(_ value: [SubAccount], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeSubAccount.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [SubAccount] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [SubAccount]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeSubAccount.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeUserBalance: FfiConverterRustBuffer {
+    typealias SwiftType = [UserBalance]
+
+    public static func write(_ value: [UserBalance], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeUserBalance.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [UserBalance] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [UserBalance]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeUserBalance.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeUserFunding: FfiConverterRustBuffer {
+    typealias SwiftType = [UserFunding]
+
+    public static func write(_ value: [UserFunding], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeUserFunding.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [UserFunding] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [UserFunding]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeUserFunding.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeUserStateResult: FfiConverterRustBuffer {
+    typealias SwiftType = [UserStateResult]
+
+    public static func write(_ value: [UserStateResult], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeUserStateResult.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [UserStateResult] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [UserStateResult]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeUserStateResult.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterDictionaryStringDouble: FfiConverterRustBuffer {
+    public static func write(_ value: [String: Double], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for (key, value) in value {
+            FfiConverterString.write(key, into: &buf)
+            FfiConverterDouble.write(value, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [String: Double] {
+        let len: Int32 = try readInt(&buf)
+        var dict = [String: Double]()
+        dict.reserveCapacity(Int(len))
+        for _ in 0..<len {
+            let key = try FfiConverterString.read(from: &buf)
+            let value = try FfiConverterDouble.read(from: &buf)
+            dict[key] = value
+        }
+        return dict
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterDictionaryStringString: FfiConverterRustBuffer {
+    public static func write(_ value: [String: String], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for (key, value) in value {
+            FfiConverterString.write(key, into: &buf)
+            FfiConverterString.write(value, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [String: String] {
+        let len: Int32 = try readInt(&buf)
+        var dict = [String: String]()
+        dict.reserveCapacity(Int(len))
+        for _ in 0..<len {
+            let key = try FfiConverterString.read(from: &buf)
+            let value = try FfiConverterString.read(from: &buf)
+            dict[key] = value
+        }
+        return dict
+    }
+}
+private let UNIFFI_RUST_FUTURE_POLL_READY: Int8 = 0
+private let UNIFFI_RUST_FUTURE_POLL_MAYBE_READY: Int8 = 1
+
+fileprivate let uniffiContinuationHandleMap = UniffiHandleMap<UnsafeContinuation<Int8, Never>>()
+
+fileprivate func uniffiRustCallAsync<F, T>(
+    rustFutureFunc: () -> UInt64,
+    pollFunc: (UInt64, @escaping UniffiRustFutureContinuationCallback, UInt64) -> (),
+    completeFunc: (UInt64, UnsafeMutablePointer<RustCallStatus>) -> F,
+    freeFunc: (UInt64) -> (),
+    liftFunc: (F) throws -> T,
+    errorHandler: ((RustBuffer) throws -> Swift.Error)?
+) async throws -> T {
+    // Make sure to call uniffiEnsureInitialized() since future creation doesn't have a
+    // RustCallStatus param, so doesn't use makeRustCall()
+    uniffiEnsureInitialized()
+    let rustFuture = rustFutureFunc()
+    defer {
+        freeFunc(rustFuture)
+    }
+    var pollResult: Int8;
+    repeat {
+        pollResult = await withUnsafeContinuation {
+            pollFunc(
+                rustFuture,
+                uniffiFutureContinuationCallback,
+                uniffiContinuationHandleMap.insert(obj: $0)
+            )
+        }
+    } while pollResult != UNIFFI_RUST_FUTURE_POLL_READY
+
+    return try liftFunc(makeRustCall(
+        { completeFunc(rustFuture, $0) },
+        errorHandler: errorHandler
+    ))
+}
+
+// Callback handlers for an async calls.  These are invoked by Rust when the future is ready.  They
+// lift the return value or error and resume the suspended function.
+fileprivate func uniffiFutureContinuationCallback(handle: UInt64, pollResult: Int8) {
+    if let continuation = try? uniffiContinuationHandleMap.remove(handle: handle) {
+        continuation.resume(returning: pollResult)
+    } else {
+        print("uniffiFutureContinuationCallback invalid handle")
+    }
+}
+public func createExchangeClient(privateKey: String, network: Network, config: ExchangeClientConfig? = nil)throws  -> HyperliquidExchange {
+    return try  FfiConverterTypeHyperliquidExchange.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_func_create_exchange_client(
+        FfiConverterString.lower(privateKey),
+        FfiConverterTypeNetwork.lower(network),
+        FfiConverterOptionTypeExchangeClientConfig.lower(config),$0
+    )
+})
+}
+public func createExchangeClientFromMnemonic(phrase: String, derivationIndex: UInt32, network: Network, config: ExchangeClientConfig? = nil)throws  -> HyperliquidExchange {
+    return try  FfiConverterTypeHyperliquidExchange.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_func_create_exchange_client_from_mnemonic(
+        FfiConverterString.lower(phrase),
+        FfiConverterUInt32.lower(derivationIndex),
+        FfiConverterTypeNetwork.lower(network),
+        FfiConverterOptionTypeExchangeClientConfig.lower(config),$0
+    )
+})
+}
+public func createInfoClient(baseUrl: BaseUrl, config: InfoClientConfig? = nil)throws  -> HyperliquidInfo {
+    return try  FfiConverterTypeHyperliquidInfo.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
+    uniffi_hyperliquid_swift_fn_func_create_info_client(
+        FfiConverterTypeBaseUrl.lower(baseUrl),
+        FfiConverterOptionTypeInfoClientConfig.lower(config),$0
+    )
+})
+}
+public func setLogCallback(callback: LogCallback, maxLevel: LogLevel) {try! rustCall() {
+    uniffi_hyperliquid_swift_fn_func_set_log_callback(
+        FfiConverterCallbackInterfaceLogCallback.lower(callback),
+        FfiConverterTypeLogLevel.lower(maxLevel),$0
+    )
+}
+}
 
-    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
-            return
-        }
-        writeInt(&buf, Int8(1))
-        FfiConverterString.write(value, into: &buf)
+private enum InitializationResult {
+    case ok
+    case contractVersionMismatch
+    case apiChecksumMismatch
+}
+// Use a global variable to perform the versioning checks. Swift ensures that
+// the code inside is only computed once.
+private let initializationResult: InitializationResult = {
+    // Get the bindings contract version from our ComponentInterface
+    let bindings_contract_version = 26
+    // Get the scaffolding contract version by calling the into the dylib
+    let scaffolding_contract_version = ffi_hyperliquid_swift_uniffi_contract_version()
+    if bindings_contract_version != scaffolding_contract_version {
+        return InitializationResult.contractVersionMismatch
     }
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterString.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
-        }
+    if (uniffi_hyperliquid_swift_checksum_func_create_exchange_client() != 55431) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_func_create_exchange_client_from_mnemonic() != 24109) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_func_create_info_client() != 2712) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_func_set_log_callback() != 56724) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_cancellationtoken_cancel() != 5706) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_cancellationtoken_is_cancelled() != 45188) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_approve_agent() != 51786) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_approve_agent_async() != 7638) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_approve_builder_fee() != 59318) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_approve_builder_fee_async() != 28273) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_asset_to_index() != 35602) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_bulk_modify() != 27324) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_bulk_modify_async() != 44027) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_cancel_all_orders() != 9106) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_cancel_all_orders_async() != 23184) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_cancel_by_cloid() != 44719) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_cancel_by_cloid_async() != 9006) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_cancel_order() != 56557) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_cancel_order_async() != 37896) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_cancel_orders() != 49375) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_cancel_orders_async() != 4246) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_cancel_replace() != 43584) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_cancel_replace_async() != 34337) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_cancel_twap() != 8247) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_cancel_twap_async() != 16873) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_cancellable() != 22942) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_class_transfer() != 35055) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_class_transfer_async() != 23168) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_close_position() != 30573) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_close_position_async() != 3908) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_get_base_url() != 23965) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_get_server_time() != 16530) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_get_server_time_async() != 52098) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_get_wallet_address() != 31106) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_index_to_asset() != 20739) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_modify_order() != 6326) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_modify_order_async() != 38626) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_place_order() != 61382) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_place_order_async() != 25104) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_place_order_oid() != 4271) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_place_order_oid_async() != 60749) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_place_order_relative() != 21929) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_place_order_relative_async() != 63470) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_place_order_with_margin() != 5025) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_place_order_with_margin_async() != 24035) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_place_order_with_tpsl() != 31143) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_place_order_with_tpsl_async() != 53979) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_place_orders_bulk() != 60872) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_place_orders_bulk_async() != 57710) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_place_twap() != 12064) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_place_twap_async() != 38989) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_refresh_meta() != 18474) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_refresh_meta_async() != 56534) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_schedule_cancel() != 1680) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_schedule_cancel_async() != 24938) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_set_clock_drift_callback() != 54607) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_set_default_slippage() != 54949) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_set_referrer() != 18646) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_set_referrer_async() != 39152) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_sign_message() != 58275) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_sign_order() != 45533) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_sign_order_async() != 17034) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_spot_transfer() != 64458) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_spot_transfer_async() != 33622) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_update_isolated_margin() != 32195) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_update_isolated_margin_async() != 53569) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_update_leverage() != 58888) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_update_leverage_async() != 63662) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_usd_transfer() != 29614) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_usd_transfer_async() != 4688) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_validate_order() != 34007) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_validate_order_async() != 7679) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_verify_account_exists() != 10974) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_verify_account_exists_async() != 3851) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_wait_for_order() != 25743) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_wait_for_order_async() != 57478) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_withdraw_from_bridge() != 11701) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_withdraw_from_bridge_async() != 9340) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_cancellable() != 46871) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_all_mids() != 13180) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_all_mids_async() != 59295) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_all_mids_f64() != 21700) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_all_mids_f64_async() != 45832) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_asset_contexts() != 24285) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_asset_contexts_async() != 29354) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_asset_names() != 48430) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_asset_names_async() != 59856) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_base_url() != 20515) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_candles() != 56573) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_candles_async() != 61133) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_clearinghouse_state() != 33560) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_clearinghouse_state_async() != 52547) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_frontend_open_orders() != 37275) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_frontend_open_orders_async() != 32936) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_funding_history() != 43400) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_funding_history_async() != 36224) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_historical_orders() != 48325) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_historical_orders_async() != 2846) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_l2_book() != 29503) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_l2_book_async() != 12017) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_latest_candles() != 17458) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_latest_candles_async() != 26749) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_ledger_updates() != 51403) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_ledger_updates_async() != 22595) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_max_builder_fee() != 28272) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_max_builder_fee_async() != 36156) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_meta() != 58713) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_meta_async() != 16179) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_meta_with_contexts() != 59648) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_meta_with_contexts_async() != 51368) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_mid() != 59714) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_mid_async() != 40866) {
+        return InitializationResult.apiChecksumMismatch
     }
-}
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-fileprivate struct FfiConverterSequenceTypeOpenOrder: FfiConverterRustBuffer {
-    typealias SwiftType = [OpenOrder]
-
-    public static func write(_ value: [OpenOrder], into buf: inout [UInt8]) {
-        let len = Int32(value.count)
-        writeInt(&buf, len)
-        for item in value {
-            FfiConverterTypeOpenOrder.write(item, into: &buf)
-        }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_open_orders() != 62223) {
+        return InitializationResult.apiChecksumMismatch
     }
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [OpenOrder] {
-        let len: Int32 = try readInt(&buf)
-        var seq = [OpenOrder]()
-        seq.reserveCapacity(Int(len))
-        for _ in 0 ..< len {
-            seq.append(try FfiConverterTypeOpenOrder.read(from: &buf))
-        }
-        return seq
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_open_orders_async() != 30330) {
+        return InitializationResult.apiChecksumMismatch
     }
-}
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-fileprivate struct FfiConverterSequenceTypeUserBalance: FfiConverterRustBuffer {
-    typealias SwiftType = [UserBalance]
-
-    public static func write(_ value: [UserBalance], into buf: inout [UInt8]) {
-        let len = Int32(value.count)
-        writeInt(&buf, len)
-        for item in value {
-            FfiConverterTypeUserBalance.write(item, into: &buf)
-        }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_order_status() != 64198) {
+        return InitializationResult.apiChecksumMismatch
     }
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [UserBalance] {
-        let len: Int32 = try readInt(&buf)
-        var seq = [UserBalance]()
-        seq.reserveCapacity(Int(len))
-        for _ in 0 ..< len {
-            seq.append(try FfiConverterTypeUserBalance.read(from: &buf))
-        }
-        return seq
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_order_status_async() != 38971) {
+        return InitializationResult.apiChecksumMismatch
     }
-}
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-fileprivate struct FfiConverterDictionaryStringString: FfiConverterRustBuffer {
-    public static func write(_ value: [String: String], into buf: inout [UInt8]) {
-        let len = Int32(value.count)
-        writeInt(&buf, len)
-        for (key, value) in value {
-            FfiConverterString.write(key, into: &buf)
-            FfiConverterString.write(value, into: &buf)
-        }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_perp_deploy_auction() != 24757) {
+        return InitializationResult.apiChecksumMismatch
     }
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [String: String] {
-        let len: Int32 = try readInt(&buf)
-        var dict = [String: String]()
-        dict.reserveCapacity(Int(len))
-        for _ in 0..<len {
-            let key = try FfiConverterString.read(from: &buf)
-            let value = try FfiConverterString.read(from: &buf)
-            dict[key] = value
-        }
-        return dict
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_perp_deploy_auction_async() != 27769) {
+        return InitializationResult.apiChecksumMismatch
     }
-}
-private let UNIFFI_RUST_FUTURE_POLL_READY: Int8 = 0
-private let UNIFFI_RUST_FUTURE_POLL_MAYBE_READY: Int8 = 1
-
-fileprivate let uniffiContinuationHandleMap = UniffiHandleMap<UnsafeContinuation<Int8, Never>>()
-
-fileprivate func uniffiRustCallAsync<F, T>(
-    rustFutureFunc: () -> UInt64,
-    pollFunc: (UInt64, @escaping UniffiRustFutureContinuationCallback, UInt64) -> (),
-    completeFunc: (UInt64, UnsafeMutablePointer<RustCallStatus>) -> F,
-    freeFunc: (UInt64) -> (),
-    liftFunc: (F) throws -> T,
-    errorHandler: ((RustBuffer) throws -> Swift.Error)?
-) async throws -> T {
-    // Make sure to call uniffiEnsureInitialized() since future creation doesn't have a
-    // RustCallStatus param, so doesn't use makeRustCall()
-    uniffiEnsureInitialized()
-    let rustFuture = rustFutureFunc()
-    defer {
-        freeFunc(rustFuture)
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_perp_state() != 11285) {
+        return InitializationResult.apiChecksumMismatch
     }
-    var pollResult: Int8;
-    repeat {
-        pollResult = await withUnsafeContinuation {
-            pollFunc(
-                rustFuture,
-                uniffiFutureContinuationCallback,
-                uniffiContinuationHandleMap.insert(obj: $0)
-            )
-        }
-    } while pollResult != UNIFFI_RUST_FUTURE_POLL_READY
-
-    return try liftFunc(makeRustCall(
-        { completeFunc(rustFuture, $0) },
-        errorHandler: errorHandler
-    ))
-}
-
-// Callback handlers for an async calls.  These are invoked by Rust when the future is ready.  They
-// lift the return value or error and resume the suspended function.
-fileprivate func uniffiFutureContinuationCallback(handle: UInt64, pollResult: Int8) {
-    if let continuation = try? uniffiContinuationHandleMap.remove(handle: handle) {
-        continuation.resume(returning: pollResult)
-    } else {
-        print("uniffiFutureContinuationCallback invalid handle")
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_perp_state_async() != 6877) {
+        return InitializationResult.apiChecksumMismatch
     }
-}
-public func createExchangeClient(privateKey: String, baseUrl: BaseUrl)throws  -> HyperliquidExchange {
-    return try  FfiConverterTypeHyperliquidExchange.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
-    uniffi_hyperliquid_swift_fn_func_create_exchange_client(
-        FfiConverterString.lower(privateKey),
-        FfiConverterTypeBaseUrl.lower(baseUrl),$0
-    )
-})
-}
-public func createInfoClient(baseUrl: BaseUrl)throws  -> HyperliquidInfo {
-    return try  FfiConverterTypeHyperliquidInfo.lift(try rustCallWithError(FfiConverterTypeHyperliquidError.lift) {
-    uniffi_hyperliquid_swift_fn_func_create_info_client(
-        FfiConverterTypeBaseUrl.lower(baseUrl),$0
-    )
-})
-}
-
-private enum InitializationResult {
-    case ok
-    case contractVersionMismatch
-    case apiChecksumMismatch
-}
-// Use a global variable to perform the versioning checks. Swift ensures that
-// the code inside is only computed once.
-private let initializationResult: InitializationResult = {
-    // Get the bindings contract version from our ComponentInterface
-    let bindings_contract_version = 26
-    // Get the scaffolding contract version by calling the into the dylib
-    let scaffolding_contract_version = ffi_hyperliquid_swift_uniffi_contract_version()
-    if bindings_contract_version != scaffolding_contract_version {
-        return InitializationResult.contractVersionMismatch
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_predicted_fundings() != 64889) {
+        return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_hyperliquid_swift_checksum_func_create_exchange_client() != 59663) {
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_predicted_fundings_async() != 47003) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_hyperliquid_swift_checksum_func_create_info_client() != 34613) {
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_rate_limit() != 40468) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_cancel_all_orders() != 41162) {
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_rate_limit_async() != 56159) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_cancel_all_orders_async() != 28691) {
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_referral_state() != 57420) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_cancel_order() != 30438) {
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_referral_state_async() != 47407) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_cancel_order_async() != 54346) {
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_server_time() != 14642) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_get_wallet_address() != 31106) {
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_server_time_async() != 65477) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_place_order() != 36280) {
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_spot_balances() != 6023) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidexchange_place_order_async() != 19762) {
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_spot_balances_async() != 816) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_all_mids() != 13180) {
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_spot_meta() != 57625) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_all_mids_async() != 59295) {
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_spot_meta_async() != 37956) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_spot_state() != 27736) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_open_orders() != 41245) {
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_spot_state_async() != 813) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_open_orders_async() != 45409) {
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_sub_accounts() != 59621) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_sub_accounts_async() != 56806) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_user_balances() != 54696) {
@@ -1706,13 +10428,166 @@ private let initializationResult: InitializationResult = {
     if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_user_balances_async() != 51031) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_user_fills() != 37381) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_user_fills_async() != 15166) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_user_fills_by_time() != 55471) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_user_fills_by_time_async() != 43374) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_user_funding() != 39807) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_user_funding_async() != 8717) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_user_overview() != 35483) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_user_overview_async() != 23166) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_user_state() != 26354) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_user_state_async() != 24127) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_user_states() != 11716) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_get_user_states_async() != 21337) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_info_raw() != 39155) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_info_raw_async() != 43289) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_ping() != 37337) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_ping_async() != 62875) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_refresh_meta() != 27036) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_refresh_meta_async() != 35974) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_round_price() != 5173) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_round_price_async() != 22145) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_round_size() != 18512) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_round_size_async() != 25030) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_set_connection_status_callback() != 50351) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_shutdown() != 42683) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_simulate_position() != 548) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_simulate_position_async() != 14217) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_submit_signed() != 63536) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_submit_signed_async() != 6282) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_subscribe_all_mids() != 59169) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_subscribe_l2_book() != 35843) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_subscribe_user_events() != 23231) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_unsubscribe() != 10169) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_verify_account_exists() != 11822) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_hyperliquidinfo_verify_account_exists_async() != 38824) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_orderrequestbuilder_build() != 46383) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_orderrequestbuilder_cloid() != 8967) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_orderrequestbuilder_limit_price() != 25925) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_orderrequestbuilder_market() != 7250) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_orderrequestbuilder_reduce_only() != 23044) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_orderrequestbuilder_slippage() != 7135) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_orderrequestbuilder_tif() != 10963) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_orderrequestbuilder_trigger() != 7826) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_constructor_cancellationtoken_new() != 39801) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_constructor_connectionpool_new() != 18370) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_constructor_orderrequestbuilder_new() != 60677) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_clockdriftcallback_on_clock_drift() != 36973) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_connectionstatuscallback_on_status() != 59940) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_l2bookcallback_on_book() != 27169) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_logcallback_on_log() != 48527) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_midscallback_on_mids() != 64654) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_hyperliquid_swift_checksum_method_usereventcallback_on_event() != 27617) {
+        return InitializationResult.apiChecksumMismatch
+    }
 
+    uniffiCallbackInitClockDriftCallback()
+    uniffiCallbackInitConnectionStatusCallback()
+    uniffiCallbackInitL2BookCallback()
+    uniffiCallbackInitLogCallback()
+    uniffiCallbackInitMidsCallback()
+    uniffiCallbackInitUserEventCallback()
     return InitializationResult.ok
 }()
 
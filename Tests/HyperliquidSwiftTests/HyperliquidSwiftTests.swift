@@ -15,7 +15,7 @@ func testCreateExchangeClient() throws {
     // This appears to succeed even with a test key, so just verify it doesn't crash
     let testPrivateKey = "0x0000000000000000000000000000000000000000000000000000000000000001"
     
-    _ = try createExchangeClient(privateKey: testPrivateKey, baseUrl: .testnet)
+    _ = try createExchangeClient(privateKey: testPrivateKey, network: .testnet)
     // Test passes if it doesn't crash
 }
 
@@ -23,14 +23,15 @@ func testCreateExchangeClient() throws {
 func testOrderRequest() {
     let orderRequest = OrderRequest(
         asset: "ETH",
-        isBuy: true,
+        side: .buy,
         size: 0.1,
         price: 3000.0,
-        reduceOnly: false
+        reduceOnly: false,
+        orderType: .limit
     )
     
     #expect(orderRequest.asset == "ETH")
-    #expect(orderRequest.isBuy == true)
+    #expect(orderRequest.side == .buy)
     #expect(orderRequest.size == 0.1)
     #expect(orderRequest.price == 3000.0)
     #expect(orderRequest.reduceOnly == false)
@@ -134,7 +135,7 @@ func testSOLTradingMainnet() throws {
     
     // Create clients
     let infoClient = try createInfoClient(baseUrl: .mainnet)
-    let exchangeClient = try createExchangeClient(privateKey: privateKey, baseUrl: .mainnet)
+    let exchangeClient = try createExchangeClient(privateKey: privateKey, network: .mainnet)
     
     print("✅ Connected successfully")
     print("📍 Wallet Address: \(exchangeClient.getWalletAddress())")
@@ -160,10 +161,11 @@ func testSOLTradingMainnet() throws {
     // Create order request
     let orderRequest = OrderRequest(
         asset: "SOL",
-        isBuy: true,
+        side: .buy,
         size: 0.1,
         price: orderPrice,
-        reduceOnly: false
+        reduceOnly: false,
+        orderType: .limit
     )
     
     // Place the order
@@ -179,7 +181,7 @@ func testSOLTradingMainnet() throws {
     let openOrders = try infoClient.getOpenOrders(address: exchangeClient.getWalletAddress())
     
     // Find our SOL order
-    if let solOrder = openOrders.first(where: { $0.asset == "SOL" && $0.isBuy == true }) {
+    if let solOrder = openOrders.first(where: { $0.asset == "SOL" && $0.side == .buy }) {
         print("📋 Found SOL order - ID: \(solOrder.oid), Price: $\(solOrder.price), Size: \(solOrder.size)")
         
         // Cancel the order
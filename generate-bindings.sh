@@ -0,0 +1,22 @@
+#!/bin/sh
+# Regenerates the Swift bindings and FFI headers from src/hyperliquid.udl and
+# copies them into Sources/. Run it in every change to the UDL. Set
+# UNIFFI_BINDGEN to a prebuilt uniffi-bindgen 0.28 to skip building this crate.
+set -eu
+cd "$(dirname "$0")"
+out=$(mktemp -d)
+trap 'rm -rf "$out"' EXIT
+
+if [ -n "${UNIFFI_BINDGEN:-}" ]; then
+    "$UNIFFI_BINDGEN" generate src/hyperliquid.udl --language swift --out-dir "$out" --no-format
+else
+    cargo run --features=uniffi/cli --bin uniffi-bindgen -- generate src/hyperliquid.udl --language swift --out-dir "$out" --no-format
+fi
+
+# Swift 6 strict concurrency rejects the generated mutable globals.
+sed -e 's/^fileprivate class UniffiHandleMap<T> {$/fileprivate class UniffiHandleMap<T>: @unchecked Sendable {/' \
+    -e 's/^private var initializationResult: InitializationResult = {$/private let initializationResult: InitializationResult = {/' \
+    "$out/hyperliquid.swift" > Sources/HyperliquidSwift/hyperliquid.swift
+cp "$out/hyperliquidFFI.h" Sources/CHyperliquidSwift/hyperliquidFFI.h
+cp "$out/hyperliquidFFI.h" Sources/HyperliquidSwift/include/hyperliquidFFI.h
+cp "$out/hyperliquidFFI.modulemap" Sources/HyperliquidSwift/include/hyperliquidFFI.modulemap
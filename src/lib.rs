@@ -1,371 +1,5481 @@
 use std::collections::HashMap;
-use std::sync::Arc;
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use hyperliquid_rust_sdk::{
     ExchangeClient, InfoClient, 
-    BaseUrl as SdkBaseUrl,
-    ClientOrderRequest, ClientOrder, ClientLimit,
-    ClientCancelRequest
+    BaseUrl as SdkBaseUrl, BuilderInfo as SdkBuilderInfo,
+    ClientOrderRequest, ClientOrder, ClientLimit, ClientTrigger,
+    ExchangeResponseStatus, ExchangeDataStatus,
+    Message, Subscription, UserData, UserFillsResponse
 };
-use alloy::signers::local::PrivateKeySigner;
-use alloy::primitives::Address;
+use alloy::signers::local::{coins_bip39::English, MnemonicBuilder, PrivateKeySigner};
+use alloy::primitives::{keccak256, Address, B256, U256};
+use alloy::signers::SignerSync;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
+use uuid::Uuid;
 
-uniffi::include_scaffolding!("hyperliquid");
+/// The generated FFI glue. It still exports the deprecated `get_user_state`,
+/// and its doc comments trip clippy, so both are allowed here only.
+#[allow(deprecated, clippy::empty_line_after_doc_comments)]
+mod scaffolding {
+    use super::*;
+    uniffi::include_scaffolding!("hyperliquid");
+}
+pub use scaffolding::UniFfiTag;
+
+/// Slippage applied to the mid price when building a market order, unless the
+/// request specifies its own or `set_default_slippage` changed it.
+const DEFAULT_SLIPPAGE: f64 = 0.05;
+
+/// Largest slippage a market order or `set_default_slippage` accepts.
+const MAX_SLIPPAGE: f64 = 0.5;
+
+/// Price buckets `l2Book` can aggregate into at five significant figures.
+const L2_BOOK_MANTISSAS: &[u32] = &[1, 2, 5];
+
+/// Taker fee at the lowest volume tier, used for pre-trade estimates.
+const BASE_TAKER_FEE_RATE: f64 = 0.00045;
+
+/// How long perp and spot metadata are reused before being fetched again.
+const DEFAULT_META_TTL_MS: u64 = 60_000;
+
+/// Spot pair `n` has asset id `SPOT_ASSET_OFFSET + n`.
+const SPOT_ASSET_OFFSET: u32 = 10_000;
+
+/// How long blocking calls wait for a response unless told otherwise.
+const DEFAULT_TIMEOUT_MS: u64 = 30_000;
+
+/// Multi-threaded runtime shared by every client that isn't handed its own.
+static SHARED_RUNTIME: OnceLock<tokio::runtime::Runtime> = OnceLock::new();
+
+/// Single-threaded runtime shared by clients created with
+/// `RuntimeFlavor::CurrentThread`.
+static SHARED_CURRENT_THREAD_RUNTIME: OnceLock<Result<tokio::runtime::Handle, String>> = OnceLock::new();
+
+/// Which runtime a client created by `create_info_client` drives its requests
+/// on. `CurrentThread` uses a single background thread instead of one per
+/// core, which is plenty for a price ticker and easier on battery.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum RuntimeFlavor {
+    #[default]
+    MultiThread,
+    CurrentThread,
+}
+
+impl RuntimeFlavor {
+    fn shared_runtime(self) -> Result<tokio::runtime::Handle, HyperliquidError> {
+        match self {
+            RuntimeFlavor::MultiThread => shared_runtime(),
+            RuntimeFlavor::CurrentThread => SHARED_CURRENT_THREAD_RUNTIME
+                .get_or_init(start_current_thread_runtime)
+                .clone()
+                .map_err(|message| HyperliquidError::NetworkError { message }),
+        }
+    }
+}
+
+/// A current-thread runtime only drives IO and timers while something is
+/// blocked on it, so it gets a thread of its own that does nothing else.
+/// Callers still block on it through its handle.
+fn start_current_thread_runtime() -> Result<tokio::runtime::Handle, String> {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|e| e.to_string())?;
+    let handle = runtime.handle().clone();
+    std::thread::Builder::new()
+        .name("hyperliquid-runtime".to_string())
+        .spawn(move || runtime.block_on(std::future::pending::<()>()))
+        .map_err(|e| e.to_string())?;
+    Ok(handle)
+}
+
+fn shared_runtime() -> Result<tokio::runtime::Handle, HyperliquidError> {
+    if let Some(runtime) = SHARED_RUNTIME.get() {
+        return Ok(runtime.handle().clone());
+    }
+    
+    let runtime = tokio::runtime::Runtime::new()
+        .map_err(|e| HyperliquidError::NetworkError { message: e.to_string() })?;
+    // If another thread won the race, our runtime is simply dropped.
+    Ok(SHARED_RUNTIME.get_or_init(|| runtime).handle().clone())
+}
+
+/// Runs `future` to completion on `runtime`, giving up after `timeout` so a
+/// hung connection can't block the calling thread forever.
+fn block_on_with_timeout<T>(
+    runtime: &tokio::runtime::Handle,
+    timeout: Duration,
+    future: impl Future<Output = Result<T, HyperliquidError>>,
+) -> Result<T, HyperliquidError> {
+    runtime.block_on(async {
+        tokio::time::timeout(timeout, future).await.map_err(|_| HyperliquidError::NetworkError {
+            message: format!("Request timed out after {}ms", timeout.as_millis()),
+        })?
+    })
+}
+
+/// Runs `future`, abandoning it with `Cancelled` as soon as `token` is
+/// cancelled.
+async fn cancellable<T>(
+    token: Option<&CancellationToken>,
+    future: impl Future<Output = Result<T, HyperliquidError>>,
+) -> Result<T, HyperliquidError> {
+    let Some(token) = token else { return future.await };
+    tokio::select! {
+        result = future => result,
+        _ = token.cancelled() => Err(HyperliquidError::Cancelled),
+    }
+}
+
+/// Flat fee the bridge deducts from every withdrawal; smaller amounts can't
+/// be withdrawn.
+const WITHDRAWAL_FEE_USDC: f64 = 1.0;
+
+/// Chain id that user-signed actions (transfers, agent approvals) are signed
+/// against, on both mainnet and testnet.
+const USER_ACTION_CHAIN_ID: u64 = 0x66eee;
+
+/// Smallest order value the exchange accepts, except for reduce-only orders.
+const MIN_ORDER_VALUE_USD: f64 = 10.0;
+
+/// Referral codes are short alphanumeric strings.
+const MAX_REFERRAL_CODE_LEN: usize = 20;
+
+/// Chain id of the phantom-agent domain that L1 actions are signed against.
+const L1_ACTION_CHAIN_ID: u64 = 1337;
+
+/// TWAP orders run for between 5 minutes and 24 hours.
+const TWAP_MIN_MINUTES: u32 = 5;
+const TWAP_MAX_MINUTES: u32 = 24 * 60;
+
+/// A scheduled cancel must be at least this far in the future.
+const MIN_SCHEDULE_CANCEL_DELAY_MS: u64 = 5_000;
+
+/// Clock drift beyond which `get_server_time` warns.
+const CLOCK_DRIFT_WARNING_MS: u64 = 5_000;
+
+/// How often `wait_for_order` asks for the status of a waiting order.
+const ORDER_POLL_INTERVAL_MS: u64 = 500;
+
+/// Perp used to read the exchange's clock; listed on mainnet and testnet.
+const SERVER_TIME_COIN: &str = "BTC";
+
+/// Most fills a single `userFillsByTime` response contains.
+const MAX_FILLS_PER_PAGE: usize = 2_000;
+
+/// Most cloids `with_order_dedupe` remembers at once; the oldest are
+/// forgotten first.
+const MAX_DEDUPE_ENTRIES: usize = 1_000;
+
+/// Highest builder fees the exchange accepts, in tenths of a basis point:
+/// 0.1% on perps and 1% on spot.
+const MAX_PERP_BUILDER_FEE: u32 = 100;
+const MAX_SPOT_BUILDER_FEE: u32 = 1_000;
+
+/// Fragments of the exchange's rejection messages for orders that can never
+/// be accepted as submitted (bad tick or lot size, too small, and so on).
+const API_ORDER_REJECTIONS: &[&str] = &[
+    "tick size",
+    "invalid size",
+    "minimum value",
+    "invalid price",
+    "post only order would have immediately matched",
+    "reduce only order would increase position",
+    "order could not immediately match",
+    "price too far from oracle",
+];
+
+/// Websocket reconnects back off exponentially from the base delay up to the cap.
+const WS_RECONNECT_BASE_DELAY_MS: u64 = 500;
+const WS_RECONNECT_MAX_DELAY_MS: u64 = 30_000;
+
+/// How often the websocket supervisor wakes to check that its client is
+/// still alive. Dead connections are reported by the SDK, which pings the
+/// server itself; quiet feeds such as `userEvents` are not a sign of one.
+const WS_SUPERVISOR_CHECK_MS: u64 = 30_000;
+
+/// Candle intervals accepted by the `candleSnapshot` info request.
+const CANDLE_INTERVALS: &[&str] = &[
+    "1m", "3m", "5m", "15m", "30m", "1h", "2h", "4h", "8h", "12h", "1d", "3d", "1w", "1M",
+];
+
+/// Most candles `candleSnapshot` returns for one request.
+const MAX_CANDLES: u32 = 5_000;
+
+/// Requests `get_user_states` keeps in flight at once.
+const MAX_CONCURRENT_USER_STATES: usize = 8;
 
 #[derive(Error, Debug)]
 pub enum HyperliquidError {
     #[error("Invalid private key: {message}")]
     InvalidPrivateKey { message: String },
+    #[error("Invalid mnemonic: {message}")]
+    InvalidMnemonic { message: String },
     #[error("Network error: {message}")]
     NetworkError { message: String },
     #[error("API error: {message}")]
     ApiError { message: String },
     #[error("Invalid input: {message}")]
     InvalidInput { message: String },
+    #[error("Insufficient margin: {message}")]
+    InsufficientMargin { message: String },
+    #[error("Invalid order: {message}")]
+    InvalidOrder { message: String },
+    /// `retry_after_ms` is how long the server asked us to wait, when known.
+    #[error("Rate limited")]
+    RateLimited { retry_after_ms: Option<u64> },
+    /// The call's `CancellationToken` was cancelled before it completed.
+    #[error("Cancelled")]
+    Cancelled,
 }
 
 impl From<hyperliquid_rust_sdk::Error> for HyperliquidError {
     fn from(err: hyperliquid_rust_sdk::Error) -> Self {
-        HyperliquidError::ApiError { message: err.to_string() }
+        match err {
+            // Connection failures and 5xx responses: the request may be worth
+            // repeating.
+            hyperliquid_rust_sdk::Error::GenericRequest(message) => HyperliquidError::NetworkError { message },
+            hyperliquid_rust_sdk::Error::ServerRequest { .. } => HyperliquidError::NetworkError { message: err.to_string() },
+            // The SDK doesn't keep response headers, so a `Retry-After` value
+            // can't be recovered here.
+            hyperliquid_rust_sdk::Error::ClientRequest { status_code: 429, .. } => HyperliquidError::RateLimited { retry_after_ms: None },
+            hyperliquid_rust_sdk::Error::ClientRequest { error_message, .. } => HyperliquidError::from_api_message(error_message),
+            _ => HyperliquidError::ApiError { message: err.to_string() },
+        }
     }
 }
 
-#[derive(Debug, Clone)]
-pub enum BaseUrl {
-    Mainnet,
-    Testnet,
-}
-
-impl From<BaseUrl> for SdkBaseUrl {
-    fn from(base_url: BaseUrl) -> Self {
-        match base_url {
-            BaseUrl::Mainnet => SdkBaseUrl::Mainnet,
-            BaseUrl::Testnet => SdkBaseUrl::Testnet,
+impl HyperliquidError {
+    /// Classifies an error message returned by the exchange, so that common
+    /// rejections surface as their own variants rather than as `ApiError`.
+    fn from_api_message(message: String) -> Self {
+        let lower = message.to_lowercase();
+        if lower.contains("insufficient margin") || lower.contains("insufficient balance") {
+            HyperliquidError::InsufficientMargin { message }
+        } else if API_ORDER_REJECTIONS.iter().any(|pattern| lower.contains(pattern)) {
+            HyperliquidError::InvalidOrder { message }
+        } else {
+            HyperliquidError::ApiError { message }
         }
     }
+    
+    fn is_transient(&self) -> bool {
+        matches!(self, HyperliquidError::NetworkError { .. } | HyperliquidError::RateLimited { .. })
+    }
 }
 
+/// How read-only requests are retried after transient network failures.
+/// Signed exchange actions are never retried: a failed send can't be told
+/// apart from one that reached the exchange, and repeating it could place a
+/// duplicate order.
 #[derive(Debug, Clone)]
-pub struct OrderRequest {
-    pub asset: String,
-    pub is_buy: bool,
-    pub size: f64,
-    pub price: f64,
-    pub reduce_only: bool,
+pub struct RetryConfig {
+    /// Total attempts, including the first; 1 disables retries.
+    pub max_attempts: u32,
+    pub base_delay_ms: u64,
+    pub max_delay_ms: u64,
 }
 
-#[derive(Debug, Clone)]
-pub struct CancelRequest {
-    pub asset: String,
-    pub oid: u64,
+impl Default for RetryConfig {
+    fn default() -> Self {
+        RetryConfig {
+            max_attempts: 3,
+            base_delay_ms: 200,
+            max_delay_ms: 5_000,
+        }
+    }
 }
 
-#[derive(Debug, Clone)]
-pub struct UserState {
-    pub address: String,
-    pub margin_summary_equity: f64,
-    pub margin_summary_account_value: f64,
-    pub margin_summary_total_margin_used: f64,
+impl RetryConfig {
+    fn delay(&self, attempt: u32) -> Duration {
+        let delay_ms = self.base_delay_ms
+            .saturating_mul(1u64 << attempt.saturating_sub(1).min(16))
+            .min(self.max_delay_ms);
+        Duration::from_millis(delay_ms)
+    }
 }
 
+/// Client-side token bucket: up to `burst` requests may go out back to back,
+/// after which they are spaced to `requests_per_second` on average.
 #[derive(Debug, Clone)]
-pub struct OpenOrder {
-    pub asset: String,
-    pub is_buy: bool,
-    pub size: f64,
-    pub price: f64,
-    pub oid: u64,
-    pub timestamp: u64,
+pub struct RateLimitConfig {
+    pub requests_per_second: f64,
+    pub burst: u32,
 }
 
-#[derive(Debug, Clone)]
-pub struct UserBalance {
-    pub token: String,
-    pub hold: f64,
-    pub total: f64,
+struct RateLimiter {
+    config: RateLimitConfig,
+    bucket: tokio::sync::Mutex<TokenBucket>,
 }
 
-pub struct HyperliquidExchange {
-    client: ExchangeClient,
-    runtime: tokio::runtime::Runtime,
-    wallet_address: String,
+struct TokenBucket {
+    tokens: f64,
+    refilled_at: tokio::time::Instant,
 }
 
-impl HyperliquidExchange {
-    pub fn new(private_key: String, base_url: BaseUrl) -> Result<Self, HyperliquidError> {
-        let runtime = tokio::runtime::Runtime::new()
-            .map_err(|e| HyperliquidError::NetworkError { message: e.to_string() })?;
-        
-        let wallet = private_key.parse::<PrivateKeySigner>()
-            .map_err(|e| HyperliquidError::InvalidPrivateKey { message: e.to_string() })?;
-        
-        let wallet_address = format!("{:?}", wallet.address());
-        
-        let client = runtime.block_on(async {
-            ExchangeClient::new(None, wallet, Some(base_url.into()), None, None).await
-        })?;
-        
-        Ok(HyperliquidExchange { client, runtime, wallet_address })
-    }
-    
-    pub fn get_wallet_address(&self) -> String {
-        self.wallet_address.clone()
-    }
-    
-    pub fn place_order(&self, order: OrderRequest) -> Result<String, HyperliquidError> {
-        self.runtime.block_on(async {
-            let client_order = ClientOrderRequest {
-                asset: order.asset,
-                is_buy: order.is_buy,
-                reduce_only: order.reduce_only,
-                limit_px: order.price,
-                sz: order.size,
-                order_type: ClientOrder::Limit(ClientLimit {
-                    tif: "Gtc".to_string(),
-                }),
-                cloid: None,
-            };
-            
-            let response = self.client.order(client_order, None).await?;
-            Ok(format!("{:?}", response))
-        })
-    }
-    
-    pub async fn place_order_async(&self, order: OrderRequest) -> Result<String, HyperliquidError> {
-        let client_order = ClientOrderRequest {
-            asset: order.asset,
-            is_buy: order.is_buy,
-            reduce_only: order.reduce_only,
-            limit_px: order.price,
-            sz: order.size,
-            order_type: ClientOrder::Limit(ClientLimit {
-                tif: "Gtc".to_string(),
+impl RateLimiter {
+    fn new(config: RateLimitConfig) -> Result<Self, HyperliquidError> {
+        if !config.requests_per_second.is_finite() || config.requests_per_second <= 0.0 {
+            return Err(HyperliquidError::InvalidInput {
+                message: format!("requests_per_second must be positive, got {}", config.requests_per_second),
+            });
+        }
+        if config.burst == 0 {
+            return Err(HyperliquidError::InvalidInput { message: "burst must be at least 1".to_string() });
+        }
+        Ok(RateLimiter {
+            bucket: tokio::sync::Mutex::new(TokenBucket {
+                tokens: f64::from(config.burst),
+                refilled_at: tokio::time::Instant::now(),
             }),
-            cloid: None,
-        };
-        
-        let response = self.client.order(client_order, None).await?;
-        Ok(format!("{:?}", response))
-    }
-    
-    pub fn cancel_order(&self, cancel: CancelRequest) -> Result<String, HyperliquidError> {
-        self.runtime.block_on(async {
-            let cancel_req = ClientCancelRequest {
-                asset: cancel.asset,
-                oid: cancel.oid,
-            };
-            
-            let response = self.client.cancel(cancel_req, None).await?;
-            Ok(format!("{:?}", response))
+            config,
         })
     }
     
-    pub async fn cancel_order_async(&self, cancel: CancelRequest) -> Result<String, HyperliquidError> {
-        let cancel_req = ClientCancelRequest {
-            asset: cancel.asset,
-            oid: cancel.oid,
-        };
+    /// Waits until a request may be sent and takes its token. The bucket stays
+    /// locked while waiting, so callers are served in arrival order.
+    async fn acquire(&self) {
+        let mut bucket = self.bucket.lock().await;
+        let now = tokio::time::Instant::now();
+        let refill = now.duration_since(bucket.refilled_at).as_secs_f64() * self.config.requests_per_second;
+        bucket.tokens = (bucket.tokens + refill).min(f64::from(self.config.burst));
+        bucket.refilled_at = now;
         
-        let response = self.client.cancel(cancel_req, None).await?;
-        Ok(format!("{:?}", response))
+        if bucket.tokens < 1.0 {
+            let wait = Duration::from_secs_f64((1.0 - bucket.tokens) / self.config.requests_per_second);
+            tokio::time::sleep(wait).await;
+            bucket.tokens = 1.0;
+            bucket.refilled_at = now + wait;
+        }
+        bucket.tokens -= 1.0;
     }
-    
-    pub fn cancel_all_orders(&self, asset: Option<String>) -> Result<String, HyperliquidError> {
-        self.runtime.block_on(async {
-            let response = if let Some(asset_name) = asset {
-                let cancel_req = ClientCancelRequest {
-                    asset: asset_name,
-                    oid: 0, // Cancel all orders for this asset
-                };
-                self.client.bulk_cancel(vec![cancel_req], None).await?
-            } else {
-                // Cancel all orders for all assets
-                let mut cancel_reqs = Vec::new();
-                for asset_name in self.client.coin_to_asset.keys() {
-                    cancel_reqs.push(ClientCancelRequest {
-                        asset: asset_name.clone(),
-                        oid: 0,
-                    });
+}
+
+/// Runs the request produced by `request` until it succeeds, fails with a
+/// non-transient error, or `config.max_attempts` is used up.
+async fn with_retry<T, E, F, Fut>(config: &RetryConfig, mut request: F) -> Result<T, HyperliquidError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+    E: Into<HyperliquidError>,
+{
+    let mut attempt = 1;
+    loop {
+        match request().await.map_err(Into::into) {
+            Err(e) if e.is_transient() && attempt < config.max_attempts => {
+                log::debug!("retrying after transient error (attempt {}): {}", attempt, e);
+                let mut delay = config.delay(attempt);
+                if let HyperliquidError::RateLimited { retry_after_ms: Some(retry_after_ms) } = e {
+                    delay = delay.max(Duration::from_millis(retry_after_ms));
                 }
-                self.client.bulk_cancel(cancel_reqs, None).await?
-            };
-            
-            Ok(format!("{:?}", response))
-        })
-    }
-    
-    pub async fn cancel_all_orders_async(&self, asset: Option<String>) -> Result<String, HyperliquidError> {
-        let response = if let Some(asset_name) = asset {
-            let cancel_req = ClientCancelRequest {
-                asset: asset_name,
-                oid: 0, // Cancel all orders for this asset
-            };
-            self.client.bulk_cancel(vec![cancel_req], None).await?
-        } else {
-            // Cancel all orders for all assets
-            let mut cancel_reqs = Vec::new();
-            for asset_name in self.client.coin_to_asset.keys() {
-                cancel_reqs.push(ClientCancelRequest {
-                    asset: asset_name.clone(),
-                    oid: 0,
-                });
+                tokio::time::sleep(delay).await;
+                attempt += 1;
             }
-            self.client.bulk_cancel(cancel_reqs, None).await?
-        };
-        
-        Ok(format!("{:?}", response))
+            Err(e) => {
+                log::warn!("request failed (attempt {}): {}", attempt, e);
+                return Err(e);
+            }
+            result => return result,
+        }
     }
 }
 
-pub struct HyperliquidInfo {
-    client: InfoClient,
-    runtime: tokio::runtime::Runtime,
+#[derive(Debug, Clone, Copy)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
 }
 
-impl HyperliquidInfo {
-    pub fn new(base_url: BaseUrl) -> Result<Self, HyperliquidError> {
-        let runtime = tokio::runtime::Runtime::new()
-            .map_err(|e| HyperliquidError::NetworkError { message: e.to_string() })?;
-        
-        let client = runtime.block_on(async {
-            InfoClient::new(None, Some(base_url.into())).await
-        })?;
-        
-        Ok(HyperliquidInfo { client, runtime })
+impl From<LogLevel> for log::LevelFilter {
+    fn from(level: LogLevel) -> Self {
+        match level {
+            LogLevel::Error => log::LevelFilter::Error,
+            LogLevel::Warn => log::LevelFilter::Warn,
+            LogLevel::Info => log::LevelFilter::Info,
+            LogLevel::Debug => log::LevelFilter::Debug,
+            LogLevel::Trace => log::LevelFilter::Trace,
+        }
     }
-    
-    pub fn get_user_state(&self, address: String) -> Result<UserState, HyperliquidError> {
-        self.runtime.block_on(async {
-            let addr = address.parse::<Address>()
-                .map_err(|e| HyperliquidError::InvalidInput { message: e.to_string() })?;
-            
-            let state = self.client.user_state(addr).await?;
-            
-            Ok(UserState {
-                address,
-                margin_summary_equity: state.margin_summary.account_value.parse().unwrap_or(0.0),
-                margin_summary_account_value: state.margin_summary.account_value.parse().unwrap_or(0.0),
-                margin_summary_total_margin_used: state.margin_summary.total_margin_used.parse().unwrap_or(0.0),
-            })
-        })
+}
+
+impl From<log::Level> for LogLevel {
+    fn from(level: log::Level) -> Self {
+        match level {
+            log::Level::Error => LogLevel::Error,
+            log::Level::Warn => LogLevel::Warn,
+            log::Level::Info => LogLevel::Info,
+            log::Level::Debug => LogLevel::Debug,
+            log::Level::Trace => LogLevel::Trace,
+        }
+    }
+}
+
+/// Receives the crate's log records: requests at `Debug`, response bodies at
+/// `Trace`, failures at `Warn`. Private keys and mnemonics are never logged.
+pub trait LogCallback: Send + Sync {
+    fn on_log(&self, level: LogLevel, target: String, message: String);
+}
+
+/// `log` backend forwarding records to the callback registered with
+/// `set_log_callback`.
+struct CallbackLogger {
+    callback: std::sync::Mutex<Option<Box<dyn LogCallback>>>,
+}
+
+static CALLBACK_LOGGER: CallbackLogger = CallbackLogger { callback: std::sync::Mutex::new(None) };
+
+impl log::Log for CallbackLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.level() <= log::max_level()
     }
     
-    pub async fn get_user_state_async(&self, address: String) -> Result<UserState, HyperliquidError> {
-        let addr = address.parse::<Address>()
-            .map_err(|e| HyperliquidError::InvalidInput { message: e.to_string() })?;
-        
-        let state = self.client.user_state(addr).await?;
-        
-        Ok(UserState {
-            address,
-            margin_summary_equity: state.margin_summary.account_value.parse().unwrap_or(0.0),
-            margin_summary_account_value: state.margin_summary.account_value.parse().unwrap_or(0.0),
-            margin_summary_total_margin_used: state.margin_summary.total_margin_used.parse().unwrap_or(0.0),
-        })
+    fn log(&self, record: &log::Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        if let Some(callback) = &*self.callback.lock().unwrap_or_else(|e| e.into_inner()) {
+            callback.on_log(record.level().into(), record.target().to_string(), record.args().to_string());
+        }
     }
     
-    pub fn get_open_orders(&self, address: String) -> Result<Vec<OpenOrder>, HyperliquidError> {
-        self.runtime.block_on(async {
-            let addr = address.parse::<Address>()
-                .map_err(|e| HyperliquidError::InvalidInput { message: e.to_string() })?;
-            
-            let orders = self.client.open_orders(addr).await?;
-            let mut result = Vec::new();
-            
-            for order in orders {
-                result.push(OpenOrder {
-                    asset: order.coin,
-                    is_buy: order.side == "B", // B for buy, A for sell
-                    size: order.sz.parse().unwrap_or(0.0),
-                    price: order.limit_px.parse().unwrap_or(0.0),
-                    oid: order.oid,
-                    timestamp: order.timestamp,
-                });
-            }
-            
-            Ok(result)
-        })
+    fn flush(&self) {}
+}
+
+#[derive(Debug, Clone)]
+pub enum BaseUrl {
+    Mainnet,
+    Testnet,
+    /// A proxy or gateway in front of the API, e.g. `https://hl.example.com`.
+    /// Exchange clients take a `Network` instead, so they can't be given one.
+    Custom { url: String },
+}
+
+/// The chain an exchange client signs for, and whose official host it sends
+/// to. The SDK tells mainnet from testnet signing by the host it posts to, so
+/// unlike `BaseUrl` there is no custom host for trading.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Network {
+    Mainnet,
+    Testnet,
+}
+
+impl From<Network> for BaseUrl {
+    fn from(network: Network) -> Self {
+        match network {
+            Network::Mainnet => BaseUrl::Mainnet,
+            Network::Testnet => BaseUrl::Testnet,
+        }
+    }
+}
+
+impl BaseUrl {
+    /// Checks that a custom URL can serve as the API root and strips any
+    /// trailing slash, since the SDK appends paths such as `/info` to it.
+    fn validated(self) -> Result<Self, HyperliquidError> {
+        let BaseUrl::Custom { url } = self else { return Ok(self) };
+        let invalid = |reason: &str| HyperliquidError::InvalidInput {
+            message: format!("Invalid base URL {}: {}", url, reason),
+        };
+        let parsed = url::Url::parse(&url).map_err(|e| invalid(&e.to_string()))?;
+        if !matches!(parsed.scheme(), "http" | "https") {
+            return Err(invalid("scheme must be http or https"));
+        }
+        if parsed.host_str().is_none() {
+            return Err(invalid("missing host"));
+        }
+        if parsed.query().is_some() || parsed.fragment().is_some() {
+            return Err(invalid("query and fragment are not allowed"));
+        }
+        Ok(BaseUrl::Custom { url: url.trim_end_matches('/').to_string() })
     }
     
-    pub async fn get_open_orders_async(&self, address: String) -> Result<Vec<OpenOrder>, HyperliquidError> {
-        let addr = address.parse::<Address>()
-            .map_err(|e| HyperliquidError::InvalidInput { message: e.to_string() })?;
-        
-        let orders = self.client.open_orders(addr).await?;
-        let mut result = Vec::new();
-        
-        for order in orders {
-            result.push(OpenOrder {
-                asset: order.coin,
-                is_buy: order.side == "B", // B for buy, A for sell
-                size: order.sz.parse().unwrap_or(0.0),
-                price: order.limit_px.parse().unwrap_or(0.0),
-                oid: order.oid,
-                timestamp: order.timestamp,
-            });
+    /// The SDK only knows its built-in hosts; custom URLs are patched onto the
+    /// clients after they are created (see `connect_info`).
+    fn sdk_base_url(&self) -> SdkBaseUrl {
+        match self {
+            BaseUrl::Mainnet | BaseUrl::Custom { .. } => SdkBaseUrl::Mainnet,
+            BaseUrl::Testnet => SdkBaseUrl::Testnet,
         }
-        
-        Ok(result)
+    }
+}
+
+/// Creates an SDK info client pointed at `base_url`. The SDK derives its
+/// websocket URL from the same field, so subscriptions follow a custom host too.
+async fn connect_info(base_url: &BaseUrl) -> Result<InfoClient, HyperliquidError> {
+    let mut client = InfoClient::new(None, Some(base_url.sdk_base_url())).await?;
+    if let BaseUrl::Custom { url } = base_url {
+        client.http_client.base_url = url.clone();
+    }
+    Ok(client)
+}
+
+/// Keep-alive HTTP connections shared by the clients configured with it, so
+/// a second client reuses the first one's TLS sessions instead of opening
+/// its own. Connections are driven by the runtime of the client that opened
+/// them, so share a pool only between clients on the same runtime.
+pub struct ConnectionPool {
+    /// The first client to join donates its HTTP client and later ones adopt
+    /// it. The SDK doesn't export the HTTP client's type, so the donor's
+    /// whole `InfoClient` is kept.
+    donor: std::sync::Mutex<Option<Arc<InfoClient>>>,
+}
+
+impl Default for ConnectionPool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ConnectionPool {
+    pub fn new() -> Self {
+        ConnectionPool { donor: std::sync::Mutex::new(None) }
     }
     
-    pub fn get_user_balances(&self, address: String) -> Result<Vec<UserBalance>, HyperliquidError> {
-        self.runtime.block_on(async {
-            let addr = address.parse::<Address>()
-                .map_err(|e| HyperliquidError::InvalidInput { message: e.to_string() })?;
-            
-            let balances = self.client.user_token_balances(addr).await?;
-            let mut result = Vec::new();
-            
-            for balance in balances.balances {
-                result.push(UserBalance {
-                    token: balance.coin,
-                    hold: balance.hold.parse().unwrap_or(0.0),
-                    total: balance.total.parse().unwrap_or(0.0),
-                });
+    /// Points `info` at the pool's HTTP client, or makes it the pool's if it
+    /// is the first to join.
+    fn join(&self, info: &mut Arc<InfoClient>) -> Result<(), HyperliquidError> {
+        let mut donor = self.donor.lock().unwrap_or_else(|e| e.into_inner());
+        match &*donor {
+            Some(donor) => {
+                let info = Arc::get_mut(info).ok_or_else(|| HyperliquidError::InvalidInput {
+                    message: "A client can't join a connection pool once it has been cloned".to_string(),
+                })?;
+                info.http_client.client = donor.http_client.client.clone();
             }
-            
-            Ok(result)
-        })
+            None => *donor = Some(info.clone()),
+        }
+        Ok(())
+    }
+}
+
+/// Lets another thread abort blocking calls made through a client returned
+/// by `cancellable`, e.g. when the user leaves the screen that started them.
+/// Once cancelled it stays cancelled; use a new token for the next call.
+pub struct CancellationToken {
+    cancelled: AtomicBool,
+    notify: tokio::sync::Notify,
+}
+
+impl Default for CancellationToken {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        CancellationToken { cancelled: AtomicBool::new(false), notify: tokio::sync::Notify::new() }
     }
     
-    pub async fn get_user_balances_async(&self, address: String) -> Result<Vec<UserBalance>, HyperliquidError> {
-        let addr = address.parse::<Address>()
-            .map_err(|e| HyperliquidError::InvalidInput { message: e.to_string() })?;
-        
-        let balances = self.client.user_token_balances(addr).await?;
-        let mut result = Vec::new();
-        
-        for balance in balances.balances {
-            result.push(UserBalance {
-                token: balance.coin,
-                hold: balance.hold.parse().unwrap_or(0.0),
-                total: balance.total.parse().unwrap_or(0.0),
-            });
-        }
-        
-        Ok(result)
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+        self.notify.notify_waiters();
     }
     
-    pub fn get_all_mids(&self) -> Result<HashMap<String, String>, HyperliquidError> {
-        self.runtime.block_on(async {
-            let mids = self.client.all_mids().await?;
-            Ok(mids)
-        })
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
     }
     
-    pub async fn get_all_mids_async(&self) -> Result<HashMap<String, String>, HyperliquidError> {
-        let mids = self.client.all_mids().await?;
-        Ok(mids)
+    async fn cancelled(&self) {
+        let notified = self.notify.notified();
+        if self.is_cancelled() {
+            return;
+        }
+        notified.await;
     }
 }
 
-pub fn create_exchange_client(private_key: String, base_url: BaseUrl) -> Result<Arc<HyperliquidExchange>, HyperliquidError> {
-    let client = HyperliquidExchange::new(private_key, base_url)?;
-    Ok(Arc::new(client))
+#[derive(Debug, Clone)]
+pub enum TpSl {
+    TakeProfit,
+    StopLoss,
 }
 
-pub fn create_info_client(base_url: BaseUrl) -> Result<Arc<HyperliquidInfo>, HyperliquidError> {
-    let client = HyperliquidInfo::new(base_url)?;
-    Ok(Arc::new(client))
-}
\ No newline at end of file
+impl TpSl {
+    fn as_sdk_str(&self) -> &'static str {
+        match self {
+            TpSl::TakeProfit => "tp",
+            TpSl::StopLoss => "sl",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum OrderType {
+    Limit,
+    Market,
+    /// Rests untriggered until the mark price crosses `trigger_price`, then
+    /// executes as a market order or as a limit at the request's `price`.
+    Trigger {
+        trigger_price: f64,
+        is_market: bool,
+        tpsl: TpSl,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub enum TimeInForce {
+    Gtc,
+    Ioc,
+    Alo,
+}
+
+impl TimeInForce {
+    fn as_sdk_str(&self) -> &'static str {
+        match self {
+            TimeInForce::Gtc => "Gtc",
+            TimeInForce::Ioc => "Ioc",
+            TimeInForce::Alo => "Alo",
+        }
+    }
+}
+
+/// How a position's margin is held: shared with the rest of the account
+/// (cross) or ring-fenced to the position (isolated).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MarginMode {
+    Cross,
+    Isolated,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderSide {
+    Buy,
+    Sell,
+}
+
+impl OrderSide {
+    pub fn is_buy(self) -> bool {
+        self == OrderSide::Buy
+    }
+    
+    /// Reads the exchange's side code: `B` (bid) for buys, `A` (ask) for sells.
+    fn from_wire(side: &str) -> Self {
+        if side == "B" { OrderSide::Buy } else { OrderSide::Sell }
+    }
+}
+
+impl From<bool> for OrderSide {
+    fn from(is_buy: bool) -> Self {
+        if is_buy { OrderSide::Buy } else { OrderSide::Sell }
+    }
+}
+
+impl From<OrderSide> for bool {
+    fn from(side: OrderSide) -> Self {
+        side.is_buy()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct OrderRequest {
+    pub asset: String,
+    pub side: OrderSide,
+    pub size: f64,
+    pub price: f64,
+    pub reduce_only: bool,
+    pub order_type: OrderType,
+    pub tif: TimeInForce,
+    pub slippage: Option<f64>,
+    pub cloid: Option<String>,
+}
+
+/// Chainable alternative to filling in every `OrderRequest` field, for
+/// callers on the Swift side. Choose the order type with `limit_price`,
+/// `market` or `trigger`; `build` rejects combinations that don't fit it.
+pub struct OrderRequestBuilder {
+    draft: std::sync::Mutex<OrderDraft>,
+}
+
+struct OrderDraft {
+    asset: String,
+    side: OrderSide,
+    size: f64,
+    price: Option<f64>,
+    market: bool,
+    trigger: Option<(f64, bool, TpSl)>,
+    tif: Option<TimeInForce>,
+    slippage: Option<f64>,
+    reduce_only: bool,
+    cloid: Option<String>,
+}
+
+impl OrderRequestBuilder {
+    pub fn new(asset: String, side: OrderSide, size: f64) -> Self {
+        OrderRequestBuilder {
+            draft: std::sync::Mutex::new(OrderDraft {
+                asset,
+                side,
+                size,
+                price: None,
+                market: false,
+                trigger: None,
+                tif: None,
+                slippage: None,
+                reduce_only: false,
+                cloid: None,
+            }),
+        }
+    }
+    
+    fn update(self: Arc<Self>, change: impl FnOnce(&mut OrderDraft)) -> Arc<Self> {
+        change(&mut self.draft.lock().unwrap_or_else(|e| e.into_inner()));
+        self
+    }
+    
+    /// Limit price; for a trigger order, the price it executes at once
+    /// triggered.
+    pub fn limit_price(self: Arc<Self>, price: f64) -> Arc<Self> {
+        self.update(|draft| draft.price = Some(price))
+    }
+    
+    pub fn market(self: Arc<Self>) -> Arc<Self> {
+        self.update(|draft| draft.market = true)
+    }
+    
+    pub fn trigger(self: Arc<Self>, trigger_price: f64, is_market: bool, tpsl: TpSl) -> Arc<Self> {
+        self.update(|draft| draft.trigger = Some((trigger_price, is_market, tpsl)))
+    }
+    
+    /// Only for limit orders; they default to `Gtc`.
+    pub fn tif(self: Arc<Self>, tif: TimeInForce) -> Arc<Self> {
+        self.update(|draft| draft.tif = Some(tif))
+    }
+    
+    /// Only for market orders; they default to the client's default slippage.
+    pub fn slippage(self: Arc<Self>, slippage: f64) -> Arc<Self> {
+        self.update(|draft| draft.slippage = Some(slippage))
+    }
+    
+    pub fn reduce_only(self: Arc<Self>, reduce_only: bool) -> Arc<Self> {
+        self.update(|draft| draft.reduce_only = reduce_only)
+    }
+    
+    pub fn cloid(self: Arc<Self>, cloid: String) -> Arc<Self> {
+        self.update(|draft| draft.cloid = Some(cloid))
+    }
+    
+    pub fn build(&self) -> Result<OrderRequest, HyperliquidError> {
+        let draft = self.draft.lock().unwrap_or_else(|e| e.into_inner());
+        let invalid = |message: &str| Err(HyperliquidError::InvalidInput { message: message.to_string() });
+        
+        if draft.size.is_nan() || draft.size <= 0.0 {
+            return invalid("Size must be positive");
+        }
+        if let Some(cloid) = &draft.cloid {
+            parse_cloid(cloid)?;
+        }
+        if draft.market && draft.trigger.is_some() {
+            return invalid("An order can't be both market and trigger; use trigger with is_market for a stop market");
+        }
+        if draft.tif.is_some() && (draft.market || draft.trigger.is_some()) {
+            return invalid("Time in force only applies to limit orders");
+        }
+        if draft.slippage.is_some() && !draft.market {
+            return invalid("Slippage only applies to market orders");
+        }
+        
+        let (order_type, price) = match (&draft.trigger, draft.market, draft.price) {
+            (Some((trigger_price, is_market, tpsl)), _, price) => {
+                let price = match (price, *is_market) {
+                    (Some(price), _) => price,
+                    // The exchange wants a limit even for stop markets; the
+                    // trigger price is what the frontend sends.
+                    (None, true) => *trigger_price,
+                    (None, false) => return invalid("Trigger limit orders need a limit price"),
+                };
+                (OrderType::Trigger { trigger_price: *trigger_price, is_market: *is_market, tpsl: tpsl.clone() }, price)
+            }
+            (None, true, None) => (OrderType::Market, 0.0),
+            (None, true, Some(_)) => return invalid("Market orders take no limit price"),
+            (None, false, Some(price)) => (OrderType::Limit, price),
+            (None, false, None) => return invalid("Set a limit price, or make it a market or trigger order"),
+        };
+        if !matches!(order_type, OrderType::Market) && (price.is_nan() || price <= 0.0) {
+            return invalid("Price must be positive");
+        }
+        
+        Ok(OrderRequest {
+            asset: draft.asset.clone(),
+            side: draft.side,
+            size: draft.size,
+            price,
+            reduce_only: draft.reduce_only,
+            order_type,
+            tif: draft.tif.clone().unwrap_or(TimeInForce::Gtc),
+            slippage: draft.slippage,
+            cloid: draft.cloid.clone(),
+        })
+    }
+}
+
+/// Optional settings for `create_exchange_client`; each unset field keeps the
+/// client's default. The Rust `with_*` builders on `HyperliquidExchange` set
+/// the same things one at a time.
+#[derive(Clone, Default)]
+pub struct ExchangeClientConfig {
+    pub timeout_ms: Option<u64>,
+    pub retry: Option<RetryConfig>,
+    pub rate_limit: Option<RateLimitConfig>,
+    /// Trade for this vault or subaccount instead of the signing wallet.
+    pub vault_address: Option<String>,
+    pub builder: Option<BuilderInfo>,
+    /// Replays the first response for repeated cloids within this window.
+    pub dedupe_window_ms: Option<u64>,
+    pub pool: Option<Arc<ConnectionPool>>,
+}
+
+/// Optional settings for `create_info_client`; each unset field keeps the
+/// client's default. The Rust `with_*` builders on `HyperliquidInfo` set the
+/// same things one at a time.
+#[derive(Clone, Default)]
+pub struct InfoClientConfig {
+    pub timeout_ms: Option<u64>,
+    pub retry: Option<RetryConfig>,
+    pub rate_limit: Option<RateLimitConfig>,
+    pub runtime: RuntimeFlavor,
+    /// How long asset metadata is reused before being fetched again.
+    pub meta_ttl_ms: Option<u64>,
+    pub pool: Option<Arc<ConnectionPool>>,
+}
+
+/// Builder code attached to orders so the builder collects a fee on them.
+/// The user must have approved the builder for at least this fee, see
+/// `HyperliquidExchange::approve_builder_fee`.
+#[derive(Debug, Clone)]
+pub struct BuilderInfo {
+    pub address: String,
+    /// Fee in tenths of a basis point, e.g. 10 = 1bp.
+    pub fee_tenths_bps: u32,
+}
+
+/// One leg of a bracket placed by `place_order_with_tpsl`. Without a
+/// `limit_price` the leg executes as a market order once triggered.
+#[derive(Debug, Clone)]
+pub struct TriggerSpec {
+    pub trigger_price: f64,
+    pub limit_price: Option<f64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ModifyRequest {
+    pub oid: u64,
+    pub order: OrderRequest,
+}
+
+/// Outcome of an exchange action or one item of it, for switching on instead
+/// of matching `status` strings. Orders and cancels carry one per item;
+/// other actions, such as leverage changes and transfers, return one directly.
+/// Rejections of a whole action are thrown as errors instead.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExchangeStatus {
+    /// Accepted: resting, filled, or cancelled.
+    Ok,
+    /// Accepted but not yet active, such as a trigger order or a take profit
+    /// waiting for its entry to fill.
+    Waiting,
+    Err { message: String },
+}
+
+impl From<&ExchangeDataStatus> for ExchangeStatus {
+    fn from(status: &ExchangeDataStatus) -> Self {
+        match status {
+            ExchangeDataStatus::WaitingForFill | ExchangeDataStatus::WaitingForTrigger => ExchangeStatus::Waiting,
+            ExchangeDataStatus::Error(message) => ExchangeStatus::Err { message: message.clone() },
+            _ => ExchangeStatus::Ok,
+        }
+    }
+}
+
+/// Where an order stands, as far as the exchange has said. Only `Resting`
+/// and the waiting states can still change.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OrderState {
+    Resting,
+    Filled,
+    /// Accepted but held back until another order fills, like the take
+    /// profit of a bracket whose entry hasn't filled yet.
+    WaitingForFill,
+    /// Accepted trigger order whose trigger price hasn't been reached.
+    WaitingForTrigger,
+    Canceled,
+    Rejected,
+    /// Accepted with no more detail, or triggered into a new order.
+    Accepted,
+}
+
+impl OrderState {
+    fn is_waiting(self) -> bool {
+        matches!(self, OrderState::WaitingForFill | OrderState::WaitingForTrigger)
+    }
+    
+    /// The state for an `orderStatus` status string.
+    fn from_order_status(status: &str) -> Self {
+        let status = status.to_lowercase();
+        match status.as_str() {
+            "open" => OrderState::Resting,
+            "filled" => OrderState::Filled,
+            _ if status.ends_with("rejected") => OrderState::Rejected,
+            _ if status.ends_with("canceled") => OrderState::Canceled,
+            _ => OrderState::Accepted,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct OrderResponse {
+    pub status: String,
+    pub state: OrderState,
+    pub exchange_status: ExchangeStatus,
+    pub oid: Option<u64>,
+    pub filled_size: Option<f64>,
+    pub average_price: Option<f64>,
+    pub resting: bool,
+    pub error: Option<String>,
+    pub cloid: Option<String>,
+    pub raw: Option<String>,
+}
+
+impl From<ExchangeDataStatus> for OrderResponse {
+    fn from(status: ExchangeDataStatus) -> Self {
+        let raw = Some(format!("{:?}", status));
+        let mut response = OrderResponse {
+            status: String::new(),
+            state: OrderState::Accepted,
+            exchange_status: ExchangeStatus::from(&status),
+            oid: None,
+            filled_size: None,
+            average_price: None,
+            resting: false,
+            error: None,
+            cloid: None,
+            raw,
+        };
+        
+        match status {
+            ExchangeDataStatus::Resting(order) => {
+                response.status = "resting".to_string();
+                response.state = OrderState::Resting;
+                response.oid = Some(order.oid);
+                response.resting = true;
+            }
+            ExchangeDataStatus::Filled(order) => {
+                response.status = "filled".to_string();
+                response.state = OrderState::Filled;
+                response.oid = Some(order.oid);
+                response.filled_size = order.total_sz.parse().ok();
+                response.average_price = order.avg_px.parse().ok();
+            }
+            ExchangeDataStatus::Success => response.status = "success".to_string(),
+            ExchangeDataStatus::WaitingForFill => {
+                response.status = "waitingForFill".to_string();
+                response.state = OrderState::WaitingForFill;
+            }
+            ExchangeDataStatus::WaitingForTrigger => {
+                response.status = "waitingForTrigger".to_string();
+                response.state = OrderState::WaitingForTrigger;
+            }
+            ExchangeDataStatus::Error(message) => {
+                response.status = "error".to_string();
+                response.state = OrderState::Rejected;
+                response.error = Some(message);
+            }
+        }
+        
+        response
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CancelResponse {
+    pub status: String,
+    pub exchange_status: ExchangeStatus,
+    pub error: Option<String>,
+    pub raw: Option<String>,
+}
+
+impl From<ExchangeDataStatus> for CancelResponse {
+    fn from(status: ExchangeDataStatus) -> Self {
+        let raw = Some(format!("{:?}", status));
+        let exchange_status = ExchangeStatus::from(&status);
+        match status {
+            ExchangeDataStatus::Error(message) => CancelResponse {
+                status: "error".to_string(),
+                exchange_status,
+                error: Some(message),
+                raw,
+            },
+            _ => CancelResponse {
+                status: "success".to_string(),
+                exchange_status,
+                error: None,
+                raw,
+            },
+        }
+    }
+}
+
+/// The only status of a single-item action.
+fn single<T>(statuses: Vec<T>) -> Result<T, HyperliquidError> {
+    statuses
+        .into_iter()
+        .next()
+        .ok_or_else(|| HyperliquidError::ApiError { message: "Exchange returned no status".to_string() })
+}
+
+/// Outcome of an action other than an order or cancel batch, such as a
+/// leverage change or a transfer. These rarely carry per-item statuses;
+/// without one, acceptance is `ExchangeStatus::Ok`.
+fn exchange_status(response: ExchangeResponseStatus) -> Result<ExchangeStatus, HyperliquidError> {
+    match response {
+        ExchangeResponseStatus::Ok(response) => Ok(response.data
+            .and_then(|data| data.statuses.first().map(ExchangeStatus::from))
+            .unwrap_or(ExchangeStatus::Ok)),
+        ExchangeResponseStatus::Err(message) => Err(HyperliquidError::from_api_message(message)),
+    }
+}
+
+/// Parses the reply to an action posted with `post_exchange`.
+fn parse_exchange_reply(body: &str) -> Result<ExchangeResponseStatus, HyperliquidError> {
+    serde_json::from_str(body).map_err(|e| HyperliquidError::ApiError { message: e.to_string() })
+}
+
+/// Exchange actions signed by `sign_l1_action` rather than the SDK, either
+/// because the SDK has no method for them or so their nonce follows the
+/// exchange's clock. Field order matters: the signature covers their msgpack
+/// encoding.
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+enum L1Action {
+    TwapOrder { twap: TwapWire },
+    TwapCancel { a: u32, t: u64 },
+    ScheduleCancel {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        time: Option<u64>,
+    },
+    Order {
+        orders: Vec<OrderWire>,
+        grouping: OrderGrouping,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        builder: Option<SdkBuilderInfo>,
+    },
+    Cancel { cancels: Vec<CancelWire> },
+    CancelByCloid { cancels: Vec<CancelCloidWire> },
+    BatchModify { modifies: Vec<ModifyWire> },
+}
+
+/// How the exchange relates the orders of one `place_orders_bulk` request.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum OrderGrouping {
+    /// Independent orders; each is accepted or rejected on its own.
+    Na,
+    /// One entry order followed by a take profit and/or stop loss trigger on
+    /// the same asset. The triggers only become active once the entry fills,
+    /// sized to what filled.
+    NormalTpsl,
+    /// Take profit and/or stop loss triggers only, attached to the existing
+    /// position on their asset and resized with it.
+    PositionTpsl,
+}
+
+#[derive(Serialize)]
+struct OrderWire {
+    a: u32,
+    b: bool,
+    p: String,
+    s: String,
+    r: bool,
+    t: OrderTypeWire,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    c: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+enum OrderTypeWire {
+    Limit {
+        tif: String,
+    },
+    Trigger {
+        #[serde(rename = "isMarket")]
+        is_market: bool,
+        #[serde(rename = "triggerPx")]
+        trigger_px: String,
+        tpsl: String,
+    },
+}
+
+impl OrderWire {
+    fn new(order: ClientOrderRequest, asset_id: u32) -> Self {
+        OrderWire {
+            a: asset_id,
+            b: order.is_buy,
+            p: float_to_wire(order.limit_px),
+            s: float_to_wire(order.sz),
+            r: order.reduce_only,
+            t: match order.order_type {
+                ClientOrder::Limit(limit) => OrderTypeWire::Limit { tif: limit.tif },
+                ClientOrder::Trigger(trigger) => OrderTypeWire::Trigger {
+                    is_market: trigger.is_market,
+                    trigger_px: float_to_wire(trigger.trigger_px),
+                    tpsl: trigger.tpsl,
+                },
+            },
+            c: order.cloid.map(format_cloid),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct CancelWire {
+    a: u32,
+    o: u64,
+}
+
+#[derive(Serialize)]
+struct CancelCloidWire {
+    asset: u32,
+    cloid: String,
+}
+
+#[derive(Serialize)]
+struct ModifyWire {
+    oid: u64,
+    order: OrderWire,
+}
+
+#[derive(Serialize)]
+struct TwapWire {
+    a: u32,
+    b: bool,
+    s: String,
+    r: bool,
+    m: u32,
+    t: bool,
+}
+
+/// Raw reply to an exchange action: `response` is an object when `status`
+/// is `ok` and an error message otherwise.
+#[derive(Deserialize)]
+struct RawExchangeReply {
+    status: String,
+    response: serde_json::Value,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum RawTwapStatus {
+    Running {
+        #[serde(rename = "twapId")]
+        twap_id: u64,
+    },
+    Error(String),
+}
+
+pub trait ClockDriftCallback: Send + Sync {
+    /// `drift_ms` is the exchange's clock minus the device's.
+    fn on_clock_drift(&self, drift_ms: i64);
+}
+
+#[derive(Default)]
+struct ClockSync {
+    offset_ms: AtomicI64,
+    drift_callback: std::sync::Mutex<Option<Box<dyn ClockDriftCallback>>>,
+}
+
+impl ClockSync {
+    fn record(&self, offset_ms: i64) {
+        self.offset_ms.store(offset_ms, Ordering::Relaxed);
+        if offset_ms.unsigned_abs() > CLOCK_DRIFT_WARNING_MS {
+            log::warn!("device clock is {}ms off the exchange's", -offset_ms);
+            if let Some(callback) = self.drift_callback.lock().unwrap_or_else(|e| e.into_inner()).as_ref() {
+                callback.on_clock_drift(offset_ms);
+            }
+        }
+    }
+}
+
+/// Outcomes of recent orders by cloid, for `with_order_dedupe`.
+struct OrderDedupe {
+    window: Duration,
+    entries: std::sync::Mutex<HashMap<Uuid, DedupeEntry>>,
+}
+
+struct DedupeEntry {
+    submitted_at: std::time::Instant,
+    /// `None` while the order is in flight, or if sending it failed.
+    response: Option<OrderResponse>,
+    /// Locked by the call sending the order, so repeats wait for it.
+    sending: Arc<tokio::sync::Mutex<()>>,
+}
+
+/// What `OrderDedupe::begin` found for a cloid.
+enum DedupeCheck {
+    /// Not seen before; send it, holding the guard until `finish`.
+    New(tokio::sync::OwnedMutexGuard<()>),
+    /// Another call is sending it right now; wait for this lock, then check
+    /// again.
+    InFlight(Arc<tokio::sync::Mutex<()>>),
+    Done(OrderResponse),
+    /// An earlier attempt ended without an answer, e.g. it timed out. Look it
+    /// up before sending it again, holding the guard meanwhile.
+    Unresolved(tokio::sync::OwnedMutexGuard<()>),
+}
+
+impl OrderDedupe {
+    fn new(window: Duration) -> Self {
+        OrderDedupe { window, entries: std::sync::Mutex::new(HashMap::new()) }
+    }
+    
+    /// Looks `cloid` up among the orders submitted within the window, and
+    /// records it as in flight if it is new.
+    fn begin(&self, cloid: Uuid) -> DedupeCheck {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        let now = std::time::Instant::now();
+        entries.retain(|_, entry| now.duration_since(entry.submitted_at) < self.window);
+        if let Some(entry) = entries.get(&cloid) {
+            if let Some(response) = &entry.response {
+                return DedupeCheck::Done(response.clone());
+            }
+            return match entry.sending.clone().try_lock_owned() {
+                Ok(guard) => DedupeCheck::Unresolved(guard),
+                Err(_) => DedupeCheck::InFlight(entry.sending.clone()),
+            };
+        }
+        
+        if entries.len() >= MAX_DEDUPE_ENTRIES {
+            let oldest = entries.iter().min_by_key(|(_, entry)| entry.submitted_at).map(|(cloid, _)| *cloid);
+            if let Some(oldest) = oldest {
+                entries.remove(&oldest);
+            }
+        }
+        let sending = Arc::new(tokio::sync::Mutex::new(()));
+        let guard = sending.clone().try_lock_owned().expect("a new lock is free");
+        entries.insert(cloid, DedupeEntry { submitted_at: now, response: None, sending });
+        DedupeCheck::New(guard)
+    }
+    
+    fn finish(&self, cloid: Uuid, response: &OrderResponse) {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(entry) = entries.get_mut(&cloid) {
+            entry.response = Some(response.clone());
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CancelRequest {
+    pub asset: String,
+    pub oid: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct UserState {
+    pub address: String,
+    /// Raw USD balance of the account (`totalRawUsd`): collateral plus the
+    /// cash legs of open positions, before they are marked. Not equity; that
+    /// is `margin_summary_account_value`.
+    pub margin_summary_raw_usd: f64,
+    /// Account value including unrealized PnL.
+    pub margin_summary_account_value: f64,
+    pub margin_summary_total_margin_used: f64,
+    pub positions: Vec<Position>,
+    /// USDC that can be withdrawn or used as margin for new positions.
+    pub withdrawable: f64,
+    pub cross_maintenance_margin_used: f64,
+    pub cross_margin_summary: MarginSummary,
+}
+
+impl UserState {
+    /// The single place a `clearinghouseState` response is mapped, so the sync
+    /// and async getters can't drift apart. Each margin field reads its own
+    /// source field.
+    fn from_clearinghouse_state(address: String, state: RawClearinghouseState) -> Result<Self, HyperliquidError> {
+        let margin_summary = MarginSummary::try_from(state.margin_summary)?;
+        Ok(UserState {
+            address,
+            margin_summary_raw_usd: margin_summary.total_raw_usd,
+            margin_summary_account_value: margin_summary.account_value,
+            margin_summary_total_margin_used: margin_summary.total_margin_used,
+            positions: state.asset_positions.into_iter().map(Position::try_from).collect::<Result<_, _>>()?,
+            withdrawable: parse_number("withdrawable", &state.withdrawable)?,
+            cross_maintenance_margin_used: parse_number("crossMaintenanceMarginUsed", &state.cross_maintenance_margin_used)?,
+            cross_margin_summary: state.cross_margin_summary.try_into()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MarginSummary {
+    pub account_value: f64,
+    pub total_notional_position: f64,
+    pub total_raw_usd: f64,
+    pub total_margin_used: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Position {
+    pub asset: String,
+    /// Signed size: positive for longs, negative for shorts.
+    pub size: f64,
+    pub entry_price: f64,
+    pub unrealized_pnl: f64,
+    pub liquidation_price: Option<f64>,
+    pub leverage: u32,
+    pub margin_used: f64,
+}
+
+/// `clearinghouseState` response. Decoded here rather than through the SDK so
+/// the maintenance margin figure is not dropped.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawClearinghouseState {
+    asset_positions: Vec<RawAssetPosition>,
+    margin_summary: RawMarginSummary,
+    cross_margin_summary: RawMarginSummary,
+    cross_maintenance_margin_used: String,
+    withdrawable: String,
+}
+
+#[derive(Deserialize)]
+struct RawAssetPosition {
+    position: RawPosition,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawPosition {
+    coin: String,
+    szi: String,
+    entry_px: Option<String>,
+    unrealized_pnl: String,
+    liquidation_px: Option<String>,
+    leverage: RawLeverage,
+    margin_used: String,
+}
+
+#[derive(Deserialize)]
+struct RawLeverage {
+    value: u32,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawMarginSummary {
+    account_value: String,
+    total_ntl_pos: String,
+    total_raw_usd: String,
+    total_margin_used: String,
+}
+
+impl TryFrom<RawAssetPosition> for Position {
+    type Error = HyperliquidError;
+    
+    fn try_from(asset_position: RawAssetPosition) -> Result<Self, Self::Error> {
+        let position = asset_position.position;
+        Ok(Position {
+            asset: position.coin,
+            size: parse_number("szi", &position.szi)?,
+            entry_price: position.entry_px.as_deref().map(|px| parse_number("entryPx", px)).transpose()?.unwrap_or(0.0),
+            unrealized_pnl: parse_number("unrealizedPnl", &position.unrealized_pnl)?,
+            liquidation_price: position.liquidation_px.as_deref().map(|px| parse_number("liquidationPx", px)).transpose()?,
+            leverage: position.leverage.value,
+            margin_used: parse_number("marginUsed", &position.margin_used)?,
+        })
+    }
+}
+
+impl TryFrom<RawMarginSummary> for MarginSummary {
+    type Error = HyperliquidError;
+    
+    fn try_from(summary: RawMarginSummary) -> Result<Self, Self::Error> {
+        Ok(MarginSummary {
+            account_value: parse_number("accountValue", &summary.account_value)?,
+            total_notional_position: parse_number("totalNtlPos", &summary.total_ntl_pos)?,
+            total_raw_usd: parse_number("totalRawUsd", &summary.total_raw_usd)?,
+            total_margin_used: parse_number("totalMarginUsed", &summary.total_margin_used)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct OpenOrder {
+    pub asset: String,
+    pub side: OrderSide,
+    pub size: f64,
+    pub price: f64,
+    pub oid: u64,
+    pub timestamp: u64,
+    pub cloid: Option<String>,
+}
+
+/// `openOrders` entry as returned by the info endpoint. Decoded here rather
+/// than through the SDK so the client order id is not dropped.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawOpenOrder {
+    coin: String,
+    limit_px: String,
+    oid: u64,
+    side: String,
+    sz: String,
+    timestamp: u64,
+    #[serde(default)]
+    cloid: Option<String>,
+}
+
+impl TryFrom<RawOpenOrder> for OpenOrder {
+    type Error = HyperliquidError;
+    
+    fn try_from(order: RawOpenOrder) -> Result<Self, Self::Error> {
+        Ok(OpenOrder {
+            asset: order.coin,
+            side: OrderSide::from_wire(&order.side),
+            size: parse_number("sz", &order.sz)?,
+            price: parse_number("limitPx", &order.limit_px)?,
+            oid: order.oid,
+            timestamp: order.timestamp,
+            cloid: order.cloid,
+        })
+    }
+}
+
+/// Resting order as shown by the web frontend, with the trigger and TP/SL
+/// details `OpenOrder` leaves out.
+#[derive(Debug, Clone)]
+pub struct FrontendOrder {
+    pub asset: String,
+    pub side: OrderSide,
+    pub size: f64,
+    pub orig_size: f64,
+    pub price: f64,
+    pub oid: u64,
+    pub timestamp: u64,
+    pub cloid: Option<String>,
+    /// The exchange's description, e.g. `Limit`, `Stop Market` or
+    /// `Take Profit Limit`.
+    pub order_type: String,
+    pub is_trigger: bool,
+    /// Only set for trigger orders.
+    pub trigger_price: Option<f64>,
+    /// Whether a trigger order takes profit or stops a loss.
+    pub tpsl: Option<TpSl>,
+    /// Whether the TP/SL is attached to the whole position rather than to a
+    /// single order.
+    pub is_position_tpsl: bool,
+    pub reduce_only: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawFrontendOrder {
+    coin: String,
+    limit_px: String,
+    oid: u64,
+    side: String,
+    sz: String,
+    orig_sz: String,
+    timestamp: u64,
+    #[serde(default)]
+    cloid: Option<String>,
+    order_type: String,
+    is_trigger: bool,
+    trigger_px: String,
+    #[serde(default)]
+    is_position_tpsl: bool,
+    reduce_only: bool,
+}
+
+impl TryFrom<RawFrontendOrder> for FrontendOrder {
+    type Error = HyperliquidError;
+    
+    fn try_from(order: RawFrontendOrder) -> Result<Self, Self::Error> {
+        let tpsl = if !order.is_trigger {
+            None
+        } else if order.order_type.starts_with("Take Profit") {
+            Some(TpSl::TakeProfit)
+        } else if order.order_type.starts_with("Stop") {
+            Some(TpSl::StopLoss)
+        } else {
+            None
+        };
+        Ok(FrontendOrder {
+            asset: order.coin,
+            side: OrderSide::from_wire(&order.side),
+            size: parse_number("sz", &order.sz)?,
+            orig_size: parse_number("origSz", &order.orig_sz)?,
+            price: parse_number("limitPx", &order.limit_px)?,
+            oid: order.oid,
+            timestamp: order.timestamp,
+            cloid: order.cloid,
+            trigger_price: if order.is_trigger { Some(parse_number("triggerPx", &order.trigger_px)?) } else { None },
+            order_type: order.order_type,
+            is_trigger: order.is_trigger,
+            tpsl,
+            is_position_tpsl: order.is_position_tpsl,
+            reduce_only: order.reduce_only,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct OrderStatus {
+    /// `open`, `filled`, `canceled`, `triggered`, `rejected`, one of the
+    /// exchange's `...Canceled` reasons, or `unknownOid` if no such order exists.
+    pub status: String,
+    pub filled_size: f64,
+    pub remaining_size: f64,
+    pub order: Option<OpenOrder>,
+}
+
+#[derive(Deserialize)]
+struct RawOrderStatusResponse {
+    status: String,
+    #[serde(default)]
+    order: Option<RawOrderStatus>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawOrderStatus {
+    order: RawDetailedOrder,
+    status: String,
+    status_timestamp: u64,
+}
+
+/// An order that has left the book, as returned by `historicalOrders`.
+#[derive(Debug, Clone)]
+pub struct HistoricalOrder {
+    /// The order as last seen; `size` is what was left when it resolved.
+    pub order: OpenOrder,
+    pub status: String,
+    /// When the order reached `status`, in milliseconds.
+    pub status_timestamp: u64,
+    pub original_size: f64,
+    pub filled_size: f64,
+}
+
+impl TryFrom<RawOrderStatus> for HistoricalOrder {
+    type Error = HyperliquidError;
+    
+    fn try_from(raw: RawOrderStatus) -> Result<Self, Self::Error> {
+        let original_size = parse_number("origSz", &raw.order.orig_sz)?;
+        let order = OpenOrder::try_from(raw.order.order)?;
+        Ok(HistoricalOrder {
+            status: raw.status,
+            status_timestamp: raw.status_timestamp,
+            original_size,
+            filled_size: original_size - order.size,
+            order,
+        })
+    }
+}
+
+/// Order as reported by `orderStatus`, which adds the original size to the
+/// fields `openOrders` returns.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawDetailedOrder {
+    #[serde(flatten)]
+    order: RawOpenOrder,
+    orig_sz: String,
+}
+
+impl TryFrom<RawOrderStatusResponse> for OrderStatus {
+    type Error = HyperliquidError;
+    
+    fn try_from(response: RawOrderStatusResponse) -> Result<Self, Self::Error> {
+        let Some(RawOrderStatus { order, status, .. }) = response.order else {
+            return Ok(OrderStatus { status: response.status, filled_size: 0.0, remaining_size: 0.0, order: None });
+        };
+        let original_size = parse_number("origSz", &order.orig_sz)?;
+        let order = OpenOrder::try_from(order.order)?;
+        Ok(OrderStatus {
+            status,
+            filled_size: original_size - order.size,
+            remaining_size: order.size,
+            order: Some(order),
+        })
+    }
+}
+
+#[derive(Deserialize)]
+struct RawTimestamped {
+    time: u64,
+}
+
+/// `l2Book` response: `levels` holds the bids, then the asks.
+#[derive(Deserialize)]
+struct RawL2Book {
+    coin: String,
+    time: u64,
+    levels: Vec<Vec<RawBookLevel>>,
+}
+
+#[derive(Deserialize)]
+struct RawBookLevel {
+    px: String,
+    sz: String,
+    n: u32,
+}
+
+impl TryFrom<RawBookLevel> for BookLevel {
+    type Error = HyperliquidError;
+    
+    fn try_from(level: RawBookLevel) -> Result<Self, Self::Error> {
+        Ok(BookLevel {
+            price: parse_number("px", &level.px)?,
+            size: parse_number("sz", &level.sz)?,
+            num_orders: level.n,
+        })
+    }
+}
+
+/// `activeAssetData` response; only what order validation needs.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawActiveAssetData {
+    /// Largest order the account's margin allows, for buys then sells.
+    max_trade_szs: [String; 2],
+}
+
+#[derive(Debug, Clone)]
+pub struct BookLevel {
+    pub price: f64,
+    pub size: f64,
+    pub num_orders: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct L2Book {
+    pub asset: String,
+    pub time: u64,
+    pub bids: Vec<BookLevel>,
+    pub asks: Vec<BookLevel>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Candle {
+    pub time_open: u64,
+    pub time_close: u64,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Fill {
+    pub asset: String,
+    pub side: OrderSide,
+    pub price: f64,
+    pub size: f64,
+    pub oid: u64,
+    pub fee: f64,
+    pub closed_pnl: f64,
+    pub timestamp: u64,
+    pub hash: String,
+}
+
+impl TryFrom<UserFillsResponse> for Fill {
+    type Error = HyperliquidError;
+    
+    fn try_from(fill: UserFillsResponse) -> Result<Self, Self::Error> {
+        Ok(Fill {
+            asset: fill.coin,
+            side: OrderSide::from_wire(&fill.side),
+            price: parse_number("px", &fill.px)?,
+            size: parse_number("sz", &fill.sz)?,
+            oid: fill.oid,
+            fee: parse_number("fee", &fill.fee)?,
+            closed_pnl: parse_number("closedPnl", &fill.closed_pnl)?,
+            timestamp: fill.time,
+            hash: fill.hash,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FundingEntry {
+    pub time: u64,
+    pub funding_rate: f64,
+    pub premium: f64,
+}
+
+/// A sub-account of a master wallet. Orders for it are signed by the master
+/// and routed with the sub-account as the vault address.
+#[derive(Debug, Clone)]
+pub struct SubAccount {
+    pub name: String,
+    pub address: String,
+    pub master: String,
+    pub state: UserState,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawSubAccount {
+    name: String,
+    sub_account_user: String,
+    master: String,
+    clearinghouse_state: RawClearinghouseState,
+}
+
+impl TryFrom<RawSubAccount> for SubAccount {
+    type Error = HyperliquidError;
+    
+    fn try_from(sub_account: RawSubAccount) -> Result<Self, Self::Error> {
+        Ok(SubAccount {
+            state: UserState::from_clearinghouse_state(sub_account.sub_account_user.clone(), sub_account.clearinghouse_state)?,
+            name: sub_account.name,
+            address: sub_account.sub_account_user,
+            master: sub_account.master,
+        })
+    }
+}
+
+/// Everything a portfolio screen needs about one account, fetched at once.
+#[derive(Debug, Clone)]
+pub struct UserOverview {
+    pub state: UserState,
+    pub open_orders: Vec<OpenOrder>,
+    pub balances: Vec<UserBalance>,
+}
+
+/// One address of `get_user_states`: its perp state, or why it couldn't be
+/// read.
+#[derive(Debug, Clone)]
+pub struct UserStateResult {
+    pub address: String,
+    pub state: Option<UserState>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ReferralState {
+    /// The user's own referral code, once they have created one.
+    pub code: Option<String>,
+    /// Code of the referrer this user signed up with, if any.
+    pub referred_by: Option<String>,
+    pub referred_users: u32,
+    pub unclaimed_rewards: f64,
+    pub claimed_rewards: f64,
+}
+
+#[derive(Deserialize)]
+struct RawUserRole {
+    role: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawReferral {
+    #[serde(default)]
+    referred_by: Option<RawReferredBy>,
+    unclaimed_rewards: String,
+    claimed_rewards: String,
+    referrer_state: RawReferrerState,
+}
+
+#[derive(Deserialize)]
+struct RawReferredBy {
+    code: String,
+}
+
+/// `data` is only present once the user has a code (`stage` is `ready`).
+#[derive(Deserialize)]
+struct RawReferrerState {
+    #[serde(default)]
+    data: Option<RawReferrerData>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawReferrerData {
+    #[serde(default)]
+    code: Option<String>,
+    #[serde(default)]
+    referral_states: Vec<serde_json::Value>,
+}
+
+impl TryFrom<RawReferral> for ReferralState {
+    type Error = HyperliquidError;
+    
+    fn try_from(referral: RawReferral) -> Result<Self, Self::Error> {
+        let (code, referred_users) = match referral.referrer_state.data {
+            Some(data) => (data.code, data.referral_states.len() as u32),
+            None => (None, 0),
+        };
+        Ok(ReferralState {
+            code,
+            referred_by: referral.referred_by.map(|referrer| referrer.code),
+            referred_users,
+            unclaimed_rewards: parse_number("unclaimedRewards", &referral.unclaimed_rewards)?,
+            claimed_rewards: parse_number("claimedRewards", &referral.claimed_rewards)?,
+        })
+    }
+}
+
+/// How much of an address's request budget is used. The cap grows with
+/// traded volume: one request per USDC traded, plus a fixed allowance.
+#[derive(Debug, Clone)]
+pub struct RateLimitStatus {
+    /// Total USDC volume traded.
+    pub cumulative_volume: f64,
+    pub requests_used: u64,
+    pub requests_cap: u64,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawUserRateLimit {
+    cum_vlm: String,
+    n_requests_used: u64,
+    n_requests_cap: u64,
+}
+
+impl TryFrom<RawUserRateLimit> for RateLimitStatus {
+    type Error = HyperliquidError;
+    
+    fn try_from(limit: RawUserRateLimit) -> Result<Self, Self::Error> {
+        Ok(RateLimitStatus {
+            cumulative_volume: parse_number("cumVlm", &limit.cum_vlm)?,
+            requests_used: limit.n_requests_used,
+            requests_cap: limit.n_requests_cap,
+        })
+    }
+}
+
+/// The Dutch auction for deploying a new perp market. The gas price falls from
+/// `start_gas` over the auction's duration; once someone deploys, the auction
+/// ends at `end_gas`.
+#[derive(Debug, Clone)]
+pub struct DeployAuctionStatus {
+    /// Milliseconds since the epoch.
+    pub start_time: u64,
+    pub duration_seconds: u64,
+    pub start_gas: f64,
+    /// Current price in USDC, `None` once the auction has ended.
+    pub current_gas: Option<f64>,
+    /// Price the market was deployed at, `None` while the auction is running.
+    pub end_gas: Option<f64>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawDeployAuctionStatus {
+    start_time_seconds: u64,
+    duration_seconds: u64,
+    start_gas: String,
+    #[serde(default)]
+    current_gas: Option<String>,
+    #[serde(default)]
+    end_gas: Option<String>,
+}
+
+impl TryFrom<RawDeployAuctionStatus> for DeployAuctionStatus {
+    type Error = HyperliquidError;
+    
+    fn try_from(status: RawDeployAuctionStatus) -> Result<Self, Self::Error> {
+        Ok(DeployAuctionStatus {
+            start_time: status.start_time_seconds * 1000,
+            duration_seconds: status.duration_seconds,
+            start_gas: parse_number("startGas", &status.start_gas)?,
+            current_gas: status.current_gas.as_deref().map(|gas| parse_number("currentGas", gas)).transpose()?,
+            end_gas: status.end_gas.as_deref().map(|gas| parse_number("endGas", gas)).transpose()?,
+        })
+    }
+}
+
+/// A funding payment on one of the user's positions.
+#[derive(Debug, Clone)]
+pub struct UserFunding {
+    pub asset: String,
+    /// USDC received (positive) or paid (negative).
+    pub amount: f64,
+    pub rate: f64,
+    pub timestamp: u64,
+}
+
+#[derive(Deserialize)]
+struct RawUserFunding {
+    time: u64,
+    delta: RawFundingDelta,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawFundingDelta {
+    coin: String,
+    usdc: String,
+    funding_rate: String,
+}
+
+impl TryFrom<RawUserFunding> for UserFunding {
+    type Error = HyperliquidError;
+    
+    fn try_from(funding: RawUserFunding) -> Result<Self, Self::Error> {
+        Ok(UserFunding {
+            amount: parse_number("usdc", &funding.delta.usdc)?,
+            rate: parse_number("fundingRate", &funding.delta.funding_rate)?,
+            asset: funding.delta.coin,
+            timestamp: funding.time,
+        })
+    }
+}
+
+/// A balance change other than trading or funding: deposits, withdrawals,
+/// transfers, vault flows and the like.
+#[derive(Debug, Clone)]
+pub struct LedgerUpdate {
+    /// The exchange's name for the change, e.g. `deposit`, `withdraw`,
+    /// `internalTransfer`, `spotTransfer` or `accountClassTransfer`.
+    pub kind: String,
+    /// Token moved; `USDC` unless the update names another.
+    pub token: String,
+    /// Amount moved, `None` for updates that carry no single amount, such as
+    /// liquidations.
+    pub amount: Option<f64>,
+    pub timestamp: u64,
+    pub hash: String,
+}
+
+/// `delta` differs per kind; only the fields common to most are read.
+#[derive(Deserialize)]
+struct RawLedgerUpdate {
+    time: u64,
+    hash: String,
+    delta: RawLedgerDelta,
+}
+
+#[derive(Deserialize)]
+struct RawLedgerDelta {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    usdc: Option<String>,
+    #[serde(default)]
+    token: Option<String>,
+    #[serde(default)]
+    amount: Option<String>,
+}
+
+impl TryFrom<RawLedgerUpdate> for LedgerUpdate {
+    type Error = HyperliquidError;
+    
+    fn try_from(update: RawLedgerUpdate) -> Result<Self, Self::Error> {
+        let delta = update.delta;
+        let amount = match (&delta.amount, &delta.usdc) {
+            (Some(amount), _) => Some(parse_number("amount", amount)?),
+            (None, Some(usdc)) => Some(parse_number("usdc", usdc)?),
+            (None, None) => None,
+        };
+        Ok(LedgerUpdate {
+            kind: delta.kind,
+            token: delta.token.unwrap_or_else(|| "USDC".to_string()),
+            amount,
+            timestamp: update.time,
+            hash: update.hash,
+        })
+    }
+}
+
+/// Funding rate the exchange expects to charge at the next funding time.
+#[derive(Debug, Clone)]
+pub struct PredictedFunding {
+    pub asset: String,
+    pub funding_rate: f64,
+    pub next_funding_time: u64,
+}
+
+/// One venue's prediction in a `predictedFundings` entry. Venues that don't
+/// list the asset are `null`.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawPredictedFunding {
+    funding_rate: String,
+    next_funding_time: u64,
+}
+
+/// `predictedFundings` also reports other exchanges; this is Hyperliquid's own.
+const HYPERLIQUID_FUNDING_VENUE: &str = "HlPerp";
+
+#[derive(Debug, Clone)]
+pub struct AssetMeta {
+    pub name: String,
+    pub sz_decimals: u32,
+    pub max_leverage: u32,
+    pub only_isolated: bool,
+}
+
+impl L2Book {
+    /// `levels[0]` holds the bids and `levels[1]` the asks, both best price first.
+    fn from_levels(asset: String, time: u64, levels: Vec<Vec<BookLevel>>) -> Self {
+        let mut sides = levels.into_iter();
+        L2Book {
+            asset,
+            time,
+            bids: sides.next().unwrap_or_default(),
+            asks: sides.next().unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SpotAssetMeta {
+    /// Pair name, e.g. `PURR/USDC`; most pairs are named `@<index>`.
+    pub name: String,
+    pub index: u32,
+    pub base_token: String,
+    pub quote_token: String,
+    pub base_token_index: u32,
+    pub quote_token_index: u32,
+    /// Size decimals of the base token.
+    pub sz_decimals: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct UserBalance {
+    pub token: String,
+    pub hold: f64,
+    pub total: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct SpotState {
+    pub address: String,
+    pub balances: Vec<SpotBalance>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SpotBalance {
+    pub token: String,
+    /// Index of the token in the spot metadata.
+    pub token_index: u32,
+    /// Amount reserved by resting orders.
+    pub hold: f64,
+    pub total: f64,
+    /// USD paid for `total`, for working out spot PnL.
+    pub entry_notional: f64,
+}
+
+#[derive(Deserialize)]
+struct RawSpotClearinghouseState {
+    balances: Vec<RawSpotBalance>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawSpotBalance {
+    coin: String,
+    token: u32,
+    hold: String,
+    total: String,
+    entry_ntl: String,
+}
+
+impl TryFrom<RawSpotBalance> for SpotBalance {
+    type Error = HyperliquidError;
+    
+    fn try_from(balance: RawSpotBalance) -> Result<Self, Self::Error> {
+        Ok(SpotBalance {
+            hold: parse_number("hold", &balance.hold)?,
+            total: parse_number("total", &balance.total)?,
+            entry_notional: parse_number("entryNtl", &balance.entry_ntl)?,
+            token: balance.coin,
+            token_index: balance.token,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawMeta {
+    universe: Vec<RawAssetMeta>,
+    #[serde(default)]
+    margin_tables: Vec<(u32, RawMarginTable)>,
+}
+
+impl RawMeta {
+    /// Maximum leverage allowed for a position of `notional` USD in `asset`.
+    /// Larger positions fall into tiers with lower caps; assets without a
+    /// margin table use their flat `max_leverage`.
+    fn max_leverage_at(&self, asset: &RawAssetMeta, notional: f64) -> f64 {
+        let table = asset.margin_table_id
+            .and_then(|id| self.margin_tables.iter().find(|(table_id, _)| *table_id == id))
+            .map(|(_, table)| table);
+        table
+            .and_then(|table| {
+                table.margin_tiers.iter()
+                    .rev()
+                    .find(|tier| tier.lower_bound.parse::<f64>().is_ok_and(|bound| bound <= notional))
+            })
+            .map_or(asset.max_leverage, |tier| tier.max_leverage) as f64
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawMarginTable {
+    margin_tiers: Vec<RawMarginTier>,
+}
+
+/// Tiers are listed in ascending `lower_bound` (position notional in USD).
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawMarginTier {
+    lower_bound: String,
+    max_leverage: u32,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct RawAssetMeta {
+    name: String,
+    sz_decimals: u32,
+    max_leverage: u32,
+    #[serde(default)]
+    only_isolated: bool,
+    #[serde(default)]
+    margin_table_id: Option<u32>,
+    #[serde(default)]
+    is_delisted: bool,
+}
+
+/// Estimated outcome of opening an isolated perp position.
+#[derive(Debug, Clone)]
+pub struct PositionSimulation {
+    /// Position value at entry, in USD.
+    pub notional: f64,
+    /// Initial margin posted at the chosen leverage.
+    pub margin_required: f64,
+    /// Margin below which the position is liquidated.
+    pub maintenance_margin: f64,
+    /// Mark price at which the position is liquidated.
+    pub liquidation_price: f64,
+    /// Taker fee for opening at the base fee tier.
+    pub fee: f64,
+}
+
+/// Live market data for a perp.
+#[derive(Debug, Clone)]
+pub struct AssetContext {
+    pub asset: String,
+    pub mark_price: f64,
+    pub oracle_price: f64,
+    /// `None` when the book is empty on one side.
+    pub mid_price: Option<f64>,
+    /// In units of the asset.
+    pub open_interest: f64,
+    /// Current hourly funding rate.
+    pub funding: f64,
+    /// Notional volume over the last 24 hours, in USD.
+    pub day_volume: f64,
+}
+
+/// Static metadata of a perp together with its live market data.
+#[derive(Debug, Clone)]
+pub struct AssetMetaWithContext {
+    pub meta: AssetMeta,
+    pub context: AssetContext,
+}
+
+/// Per-asset half of `metaAndAssetCtxs`, index-aligned with the universe.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawAssetContext {
+    mark_px: String,
+    oracle_px: String,
+    #[serde(default)]
+    mid_px: Option<String>,
+    open_interest: String,
+    funding: String,
+    day_ntl_vlm: String,
+}
+
+impl RawAssetContext {
+    fn into_context(self, asset: String) -> Result<AssetContext, HyperliquidError> {
+        Ok(AssetContext {
+            asset,
+            mark_price: parse_number("markPx", &self.mark_px)?,
+            oracle_price: parse_number("oraclePx", &self.oracle_px)?,
+            mid_price: self.mid_px.as_deref().map(|mid| parse_number("midPx", mid)).transpose()?,
+            open_interest: parse_number("openInterest", &self.open_interest)?,
+            funding: parse_number("funding", &self.funding)?,
+            day_volume: parse_number("dayNtlVlm", &self.day_ntl_vlm)?,
+        })
+    }
+}
+
+impl From<RawAssetMeta> for AssetMeta {
+    fn from(meta: RawAssetMeta) -> Self {
+        AssetMeta {
+            name: meta.name,
+            sz_decimals: meta.sz_decimals,
+            max_leverage: meta.max_leverage,
+            only_isolated: meta.only_isolated,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct RawSpotMeta {
+    universe: Vec<RawSpotPair>,
+    tokens: Vec<RawSpotToken>,
+}
+
+#[derive(Deserialize)]
+struct RawSpotPair {
+    name: String,
+    /// Token indices of the base and quote token.
+    tokens: Vec<u32>,
+    index: u32,
+}
+
+#[derive(Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct RawSpotToken {
+    name: String,
+    sz_decimals: u32,
+    index: u32,
+    token_id: String,
+}
+
+impl RawSpotToken {
+    /// `NAME:tokenId`, the form spot transfers identify tokens by.
+    fn wire_name(&self) -> String {
+        format!("{}:{}", self.name, self.token_id)
+    }
+}
+
+/// `meta` and `spotMeta` responses, reused for `ttl` so placing or rounding
+/// an order doesn't cost a metadata round trip. Shared by a client's clones
+/// and safe to use from any thread; two lookups missing at the same time may
+/// both fetch.
+struct MetaCache {
+    ttl: Duration,
+    perp: std::sync::Mutex<Option<(std::time::Instant, Arc<RawMeta>)>>,
+    spot: std::sync::Mutex<Option<(std::time::Instant, Arc<RawSpotMeta>)>>,
+}
+
+impl MetaCache {
+    fn new(ttl: Duration) -> Self {
+        MetaCache { ttl, perp: std::sync::Mutex::new(None), spot: std::sync::Mutex::new(None) }
+    }
+    
+    async fn perp(&self, client: &InfoClient) -> Result<Arc<RawMeta>, HyperliquidError> {
+        cached(&self.perp, self.ttl, || post_info(client, serde_json::json!({ "type": "meta" }))).await
+    }
+    
+    async fn spot(&self, client: &InfoClient) -> Result<Arc<RawSpotMeta>, HyperliquidError> {
+        cached(&self.spot, self.ttl, || post_info(client, serde_json::json!({ "type": "spotMeta" }))).await
+    }
+    
+    fn clear(&self) {
+        *self.perp.lock().unwrap_or_else(|e| e.into_inner()) = None;
+        *self.spot.lock().unwrap_or_else(|e| e.into_inner()) = None;
+    }
+}
+
+/// Asset ids by name as the exchange numbers them: perps by their place in
+/// the universe, spot pairs after `SPOT_ASSET_OFFSET`, under both their own
+/// name (`PURR/USDC`, `@1`) and `BASE/QUOTE`.
+fn asset_ids(perp: &RawMeta, spot: &RawSpotMeta) -> HashMap<String, u32> {
+    let mut ids: HashMap<String, u32> = perp.universe.iter()
+        .enumerate()
+        .map(|(index, asset)| (asset.name.clone(), index as u32))
+        .collect();
+    let token_name = |index: u32| spot.tokens.iter().find(|token| token.index == index).map(|token| token.name.as_str());
+    for pair in &spot.universe {
+        let id = SPOT_ASSET_OFFSET + pair.index;
+        ids.insert(pair.name.clone(), id);
+        if let [base, quote] = pair.tokens[..] {
+            if let (Some(base), Some(quote)) = (token_name(base), token_name(quote)) {
+                ids.insert(format!("{}/{}", base, quote), id);
+            }
+        }
+    }
+    ids
+}
+
+/// The value in `slot` if it is younger than `ttl`, otherwise a fresh one
+/// from `fetch`, which is then stored.
+async fn cached<T, Fut>(slot: &std::sync::Mutex<Option<(std::time::Instant, Arc<T>)>>, ttl: Duration, fetch: impl FnOnce() -> Fut) -> Result<Arc<T>, HyperliquidError>
+where
+    Fut: Future<Output = Result<T, HyperliquidError>>,
+{
+    if let Some((fetched_at, value)) = &*slot.lock().unwrap_or_else(|e| e.into_inner()) {
+        if fetched_at.elapsed() < ttl {
+            return Ok(value.clone());
+        }
+    }
+    let value = Arc::new(fetch().await?);
+    *slot.lock().unwrap_or_else(|e| e.into_inner()) = Some((std::time::Instant::now(), value.clone()));
+    Ok(value)
+}
+
+/// Signing client for trading and transfers.
+///
+/// Cloning is cheap: clones share the SDK clients, the runtime and the rate
+/// limiter, and every method takes `&self`, so a client can be called from
+/// several threads or tasks at once. Requests from different clones are not
+/// ordered with respect to each other.
+#[derive(Clone)]
+pub struct HyperliquidExchange {
+    client: Arc<ExchangeClient>,
+    info: Arc<InfoClient>,
+    runtime: tokio::runtime::Handle,
+    timeout: Duration,
+    retry: RetryConfig,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    meta: Arc<MetaCache>,
+    /// Asset ids by name, for the orders and cancels this crate signs;
+    /// `refresh_meta` reloads it.
+    assets: Arc<std::sync::RwLock<HashMap<String, u32>>>,
+    builder: Option<SdkBuilderInfo>,
+    dedupe: Option<Arc<OrderDedupe>>,
+    clock: Arc<ClockSync>,
+    /// `f64` bits of the slippage market orders use when they don't set one.
+    default_slippage: Arc<AtomicU64>,
+    wallet_address: String,
+    base_url: BaseUrl,
+    cancellation: Option<Arc<CancellationToken>>,
+}
+
+impl HyperliquidExchange {
+    pub fn new(private_key: String, network: Network) -> Result<Self, HyperliquidError> {
+        Self::with_runtime(private_key, network, shared_runtime()?)
+    }
+    
+    /// Like `new`, but drives all requests on the caller's runtime instead of
+    /// the crate's shared one.
+    pub fn with_runtime(private_key: String, network: Network, runtime: tokio::runtime::Handle) -> Result<Self, HyperliquidError> {
+        Self::with_wallet(parse_private_key(&private_key)?, network, runtime)
+    }
+    
+    /// Derives the signing key from a BIP-39 recovery phrase along the standard
+    /// Ethereum path `m/44'/60'/0'/0/<derivation_index>`, as MetaMask and most
+    /// wallets do; index 0 is the first account.
+    pub fn from_mnemonic(phrase: String, derivation_index: u32, network: Network) -> Result<Self, HyperliquidError> {
+        let wallet = MnemonicBuilder::<English>::default()
+            .phrase(phrase.trim())
+            .index(derivation_index)
+            .and_then(|builder| builder.build())
+            .map_err(|e| HyperliquidError::InvalidMnemonic { message: e.to_string() })?;
+        Self::with_wallet(wallet, network, shared_runtime()?)
+    }
+    
+    fn with_wallet(wallet: PrivateKeySigner, network: Network, runtime: tokio::runtime::Handle) -> Result<Self, HyperliquidError> {
+        let wallet_address = checksummed_address(&wallet);
+        let base_url = BaseUrl::from(network);
+        
+        let timeout = Duration::from_millis(DEFAULT_TIMEOUT_MS);
+        let (client, info, clock_offset) = block_on_with_timeout(&runtime, timeout, async {
+            let client = ExchangeClient::new(None, wallet, Some(base_url.sdk_base_url()), None, None).await?;
+            let info = connect_info(&base_url).await?;
+            // Best effort: until `get_server_time` succeeds, nonces use the
+            // device clock.
+            let clock_offset = match measure_clock_offset(&info).await {
+                Ok((_, offset_ms)) => Some(offset_ms),
+                Err(e) => {
+                    log::debug!("could not read the exchange's clock: {}", e);
+                    None
+                }
+            };
+            Ok((client, info, clock_offset))
+        })?;
+        let clock = ClockSync::default();
+        if let Some(offset_ms) = clock_offset {
+            clock.record(offset_ms);
+        }
+        
+        Ok(HyperliquidExchange {
+            assets: Arc::new(std::sync::RwLock::new(client.coin_to_asset.clone())),
+            client: Arc::new(client),
+            info: Arc::new(info),
+            runtime,
+            timeout,
+            retry: RetryConfig::default(),
+            rate_limiter: None,
+            meta: Arc::new(MetaCache::new(Duration::from_millis(DEFAULT_META_TTL_MS))),
+            builder: None,
+            dedupe: None,
+            clock: Arc::new(clock),
+            default_slippage: Arc::new(AtomicU64::new(DEFAULT_SLIPPAGE.to_bits())),
+            wallet_address,
+            base_url,
+            cancellation: None,
+        })
+    }
+    
+    /// Sets how long blocking calls wait before failing with a `NetworkError`.
+    pub fn with_timeout(mut self, timeout_ms: u64) -> Self {
+        self.timeout = Duration::from_millis(timeout_ms);
+        self
+    }
+    
+    /// Makes blocking calls fail with `Cancelled` once `token` is cancelled.
+    /// What a cancelled call already sent still happens: an order may have
+    /// reached the exchange, so look it up (placing it with a cloid helps)
+    /// before placing it again.
+    pub fn with_cancellation(mut self, token: Arc<CancellationToken>) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+    
+    /// A clone of this client whose blocking calls `token` can abort; see
+    /// `with_cancellation`. The `_async` methods are not affected.
+    pub fn cancellable(self: Arc<Self>, token: Arc<CancellationToken>) -> Arc<Self> {
+        Arc::new((*self).clone().with_cancellation(token))
+    }
+    
+    /// Sets how read-only requests are retried after transient failures.
+    pub fn with_retry(mut self, retry: RetryConfig) -> Self {
+        self.retry = retry;
+        self
+    }
+    
+    /// Throttles every HTTP request this client makes, retries included.
+    pub fn with_rate_limit(mut self, rate_limit: RateLimitConfig) -> Result<Self, HyperliquidError> {
+        self.rate_limiter = Some(Arc::new(RateLimiter::new(rate_limit)?));
+        Ok(self)
+    }
+    
+    /// Sets how long asset metadata is reused before being fetched again
+    /// (one minute by default).
+    pub fn with_meta_ttl(mut self, ttl_ms: u64) -> Self {
+        self.meta = Arc::new(MetaCache::new(Duration::from_millis(ttl_ms)));
+        self
+    }
+    
+    /// Places orders, cancels and other trading actions on behalf of the vault
+    /// at `vault_address`, which this key must manage. Must be called before
+    /// the client is cloned, since clones share the SDK client.
+    pub fn with_vault(mut self, vault_address: String) -> Result<Self, HyperliquidError> {
+        let vault = vault_address.parse::<Address>().map_err(|e| HyperliquidError::InvalidInput {
+            message: format!("Invalid vault address {:?}: {}", vault_address, e),
+        })?;
+        let client = Arc::get_mut(&mut self.client).ok_or_else(|| HyperliquidError::InvalidInput {
+            message: "The vault address can't be changed once the client has been cloned".to_string(),
+        })?;
+        client.vault_address = Some(vault);
+        Ok(self)
+    }
+    
+    /// Sends this client's requests over `pool`'s connections. Must be called
+    /// before the client is cloned.
+    pub fn with_connection_pool(mut self, pool: Arc<ConnectionPool>) -> Result<Self, HyperliquidError> {
+        pool.join(&mut self.info)?;
+        let client = Arc::get_mut(&mut self.client).ok_or_else(|| HyperliquidError::InvalidInput {
+            message: "A client can't join a connection pool once it has been cloned".to_string(),
+        })?;
+        client.http_client.client = self.info.http_client.client.clone();
+        Ok(self)
+    }
+    
+    /// Routes trading actions to `sub_account_address`, after checking that it
+    /// is a sub-account of this wallet.
+    pub fn with_sub_account(self, sub_account_address: String) -> Result<Self, HyperliquidError> {
+        let sub_account = sub_account_address.parse::<Address>().map_err(|e| HyperliquidError::InvalidInput {
+            message: format!("Invalid sub-account address {:?}: {}", sub_account_address, e),
+        })?;
+        let master = self.client.wallet.address();
+        let sub_accounts = self.block_on(self.request(|| fetch_sub_accounts(&self.info, master)))?;
+        if !sub_accounts.iter().any(|s| s.sub_account_user.parse::<Address>().ok() == Some(sub_account)) {
+            return Err(HyperliquidError::InvalidInput {
+                message: format!("{} is not a sub-account of {}", sub_account_address, self.wallet_address),
+            });
+        }
+        self.with_vault(sub_account_address)
+    }
+    
+    /// Makes `place_order` idempotent per cloid: placing an order whose cloid
+    /// was already used in the last `window_ms` returns the first outcome
+    /// instead of sending it again. If the first attempt never got an answer
+    /// (e.g. it timed out), the order's status is looked up and it is only
+    /// resent if the exchange has never seen it. A repeat made while the first
+    /// is still in flight waits for its outcome. Orders without a cloid are
+    /// not deduplicated. At most `MAX_DEDUPE_ENTRIES` cloids are remembered.
+    pub fn with_order_dedupe(mut self, window_ms: u64) -> Self {
+        self.dedupe = Some(Arc::new(OrderDedupe::new(Duration::from_millis(window_ms))));
+        self
+    }
+    
+    /// Attaches `builder` to every order placed through this client.
+    pub fn with_builder(mut self, builder: BuilderInfo) -> Result<Self, HyperliquidError> {
+        let address = builder.address.parse::<Address>().map_err(|e| HyperliquidError::InvalidInput {
+            message: format!("Invalid builder address {:?}: {}", builder.address, e),
+        })?;
+        if builder.fee_tenths_bps > MAX_SPOT_BUILDER_FEE {
+            return Err(HyperliquidError::InvalidInput {
+                message: format!("Builder fee {} exceeds the maximum of {} tenths of a bp", builder.fee_tenths_bps, MAX_SPOT_BUILDER_FEE),
+            });
+        }
+        // The exchange expects the builder address in lowercase hex.
+        self.builder = Some(SdkBuilderInfo {
+            builder: format!("{:?}", address),
+            fee: u64::from(builder.fee_tenths_bps),
+        });
+        Ok(self)
+    }
+    
+    fn block_on<T>(&self, future: impl Future<Output = Result<T, HyperliquidError>>) -> Result<T, HyperliquidError> {
+        block_on_with_timeout(&self.runtime, self.timeout, cancellable(self.cancellation.as_deref(), future))
+    }
+    
+    /// Issues a read-only request, retrying it per the client's `RetryConfig`.
+    async fn request<T, E, F, Fut>(&self, mut request: F) -> Result<T, HyperliquidError>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+        E: Into<HyperliquidError>,
+    {
+        with_retry(&self.retry, || {
+            let attempt = request();
+            async move {
+                self.throttle().await;
+                attempt.await
+            }
+        }).await
+    }
+    
+    async fn throttle(&self) {
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.acquire().await;
+        }
+    }
+    
+    /// Sends a signed exchange action once; unlike `request` it is never retried.
+    async fn send<T: std::fmt::Debug>(&self, action: impl Future<Output = Result<T, hyperliquid_rust_sdk::Error>>) -> Result<T, HyperliquidError> {
+        self.throttle().await;
+        match action.await {
+            Ok(response) => {
+                log::trace!("exchange response: {:?}", response);
+                Ok(response)
+            }
+            Err(e) => {
+                let e = HyperliquidError::from(e);
+                log::warn!("exchange action failed: {}", e);
+                Err(e)
+            }
+        }
+    }
+    
+    /// Posts a hand-signed action to `/exchange`. The payload only carries the
+    /// signature, so it is safe to log.
+    async fn post_exchange(&self, request: serde_json::Value) -> Result<String, HyperliquidError> {
+        log::debug!("POST /exchange {}", request);
+        self.send(self.client.http_client.post("/exchange", request.to_string())).await
+    }
+    
+    async fn to_client_order(&self, order: OrderRequest) -> Result<ClientOrderRequest, HyperliquidError> {
+        let cloid = order.cloid.as_deref().map(parse_cloid).transpose()?;
+        // Perps are keyed by coin (`ETH`), spot by pair name (`PURR/USDC`) or
+        // `@<pair index>`; the SDK maps both to asset ids.
+        if self.asset_id(&order.asset).is_err() {
+            return Err(HyperliquidError::InvalidInput {
+                message: format!("Unknown asset {}; spot markets are named like PURR/USDC or @1", order.asset),
+            });
+        }
+        if let Some(builder) = &self.builder {
+            if !is_spot_asset(&order.asset) && builder.fee > u64::from(MAX_PERP_BUILDER_FEE) {
+                return Err(HyperliquidError::InvalidInput {
+                    message: format!("Builder fee {} exceeds the perp maximum of {} tenths of a bp", builder.fee, MAX_PERP_BUILDER_FEE),
+                });
+            }
+        }
+        
+        let (limit_px, order_type) = match order.order_type {
+            OrderType::Limit => (order.price, ClientOrder::Limit(ClientLimit {
+                tif: order.tif.as_sdk_str().to_string(),
+            })),
+            OrderType::Market => {
+                if order.size == 0.0 {
+                    return Err(HyperliquidError::InvalidInput {
+                        message: "Market orders require a non-zero size".to_string(),
+                    });
+                }
+                let slippage = match order.slippage {
+                    Some(slippage) => validate_slippage(slippage)?,
+                    None => self.default_slippage(),
+                };
+                // Market orders are always immediate-or-cancel, whatever tif says.
+                (self.market_price(&order.asset, order.side.is_buy(), slippage).await?, ClientOrder::Limit(ClientLimit {
+                    tif: TimeInForce::Ioc.as_sdk_str().to_string(),
+                }))
+            }
+            OrderType::Trigger { trigger_price, is_market, tpsl } => (order.price, ClientOrder::Trigger(ClientTrigger {
+                is_market,
+                trigger_px: trigger_price,
+                tpsl: tpsl.as_sdk_str().to_string(),
+            })),
+        };
+        
+        Ok(ClientOrderRequest {
+            asset: order.asset,
+            is_buy: order.side.is_buy(),
+            reduce_only: order.reduce_only,
+            limit_px,
+            sz: order.size,
+            order_type,
+            cloid,
+        })
+    }
+    
+    /// Aggressive limit price for a market order: the current mid moved by
+    /// `slippage` against us, rounded to what the exchange will accept.
+    async fn market_price(&self, asset: &str, is_buy: bool, slippage: f64) -> Result<f64, HyperliquidError> {
+        let precision = self.request(|| fetch_asset_precision(&self.info, &self.meta, asset)).await?;
+        let mids = self.request(|| self.info.all_mids()).await?;
+        let mid = find_mid(&mids, asset, precision.mid_key.as_deref())?;
+        
+        let px = if is_buy { mid * (1.0 + slippage) } else { mid * (1.0 - slippage) };
+        Ok(precision.round_price(px))
+    }
+    
+    pub fn get_wallet_address(&self) -> String {
+        self.wallet_address.clone()
+    }
+    
+    /// Signs `message` as an EIP-191 personal message (what wallets'
+    /// `personal_sign` produces) and returns the 65-byte signature as hex.
+    /// Only the signature leaves the client; the key stays inside.
+    pub fn sign_message(&self, message: String) -> Result<String, HyperliquidError> {
+        let signature = self.client.wallet.sign_message_sync(message.as_bytes())
+            .map_err(|e| HyperliquidError::ApiError { message: format!("Failed to sign message: {}", e) })?;
+        Ok(alloy::primitives::hex::encode_prefixed(signature.as_bytes()))
+    }
+    
+    /// Drops cached asset metadata and fetches it again, along with the asset
+    /// ids orders and cancels are sent with, so markets listed since the
+    /// client was created can be traded. Leverage and margin changes go
+    /// through the SDK, whose ids are fixed at creation, and still need a new
+    /// client for such markets.
+    pub fn refresh_meta(&self) -> Result<(), HyperliquidError> {
+        self.block_on(self.refresh_meta_async())
+    }
+    
+    pub async fn refresh_meta_async(&self) -> Result<(), HyperliquidError> {
+        self.meta.clear();
+        let (perp, spot) = tokio::try_join!(
+            self.request(|| self.meta.perp(&self.info)),
+            self.request(|| self.meta.spot(&self.info)),
+        )?;
+        *self.assets.write().unwrap_or_else(|e| e.into_inner()) = asset_ids(&perp, &spot);
+        Ok(())
+    }
+    
+    /// The network this client signs for and sends to.
+    pub fn get_base_url(&self) -> BaseUrl {
+        self.base_url.clone()
+    }
+    
+    /// The integer asset id the exchange uses for `name` (`ETH`, `PURR/USDC`,
+    /// `@1`), as of the client's creation or last `refresh_meta`.
+    pub fn asset_to_index(&self, name: String) -> Result<u32, HyperliquidError> {
+        self.asset_id(&name)
+    }
+    
+    /// The asset name for an exchange asset id; spot ids start at 10000.
+    pub fn index_to_asset(&self, index: u32) -> Result<String, HyperliquidError> {
+        self.assets.read().unwrap_or_else(|e| e.into_inner()).iter()
+            .find(|(_, id)| **id == index)
+            .map(|(name, _)| name.clone())
+            .ok_or_else(|| HyperliquidError::InvalidInput { message: format!("Unknown asset index {}", index) })
+    }
+    
+    /// Whether the account orders are placed for exists on this client's
+    /// network. `false` usually means a mainnet key on testnet or vice versa,
+    /// and is also logged as a warning.
+    pub fn verify_account_exists(&self) -> Result<bool, HyperliquidError> {
+        self.block_on(self.verify_account_exists_async())
+    }
+    
+    pub async fn verify_account_exists_async(&self) -> Result<bool, HyperliquidError> {
+        let user = self.account_address();
+        self.request(|| account_exists(&self.info, user, &self.base_url)).await
+    }
+    
+    /// Milliseconds since the epoch on the exchange's clock, as far as it is
+    /// known. Used as the nonce of every action this crate signs itself.
+    fn nonce(&self) -> u64 {
+        now_ms().saturating_add_signed(self.clock.offset_ms.load(Ordering::Relaxed))
+    }
+    
+    /// Reads the exchange's clock and corrects the nonces of orders, modifies,
+    /// cancels and the other actions this crate signs itself (agent and
+    /// builder approvals, TWAPs, scheduled cancels) for the device's drift.
+    /// The client does this once when it is created; call it again after the
+    /// device clock may have changed. Leverage, margin and transfer actions
+    /// are signed by the SDK with the device clock and are not corrected.
+    /// Reports drift beyond `CLOCK_DRIFT_WARNING_MS` to the clock drift
+    /// callback.
+    pub fn get_server_time(&self) -> Result<u64, HyperliquidError> {
+        self.block_on(self.get_server_time_async())
+    }
+    
+    pub async fn get_server_time_async(&self) -> Result<u64, HyperliquidError> {
+        let (server_time, offset_ms) = self.request(|| measure_clock_offset(&self.info)).await?;
+        self.clock.record(offset_ms);
+        Ok(server_time)
+    }
+    
+    pub fn set_clock_drift_callback(&self, callback: Box<dyn ClockDriftCallback>) {
+        *self.clock.drift_callback.lock().unwrap_or_else(|e| e.into_inner()) = Some(callback);
+    }
+    
+    /// Sets the slippage, as a fraction of the mid, that market orders,
+    /// `close_position` and market TP/SL legs use when they don't pass their
+    /// own. Applies to every clone of this client. Starts at 5%.
+    pub fn set_default_slippage(&self, fraction: f64) -> Result<(), HyperliquidError> {
+        validate_slippage(fraction)?;
+        self.default_slippage.store(fraction.to_bits(), Ordering::Relaxed);
+        Ok(())
+    }
+    
+    fn default_slippage(&self) -> f64 {
+        f64::from_bits(self.default_slippage.load(Ordering::Relaxed))
+    }
+    
+    /// The account orders are placed for: the vault if one is set, otherwise
+    /// the signing wallet.
+    fn account_address(&self) -> Address {
+        self.client.vault_address.unwrap_or_else(|| self.client.wallet.address())
+    }
+    
+    /// Checks `order` against the asset's tick and lot size, the minimum order
+    /// value and, for perps, the margin the account has available, without
+    /// sending it. Spot balances are not checked.
+    pub fn validate_order(&self, order: OrderRequest) -> Result<(), HyperliquidError> {
+        self.block_on(self.validate_order_async(order))
+    }
+    
+    pub async fn validate_order_async(&self, order: OrderRequest) -> Result<(), HyperliquidError> {
+        let order = self.to_client_order(order).await?;
+        let invalid = |message: String| Err(HyperliquidError::InvalidInput { message });
+        if !order.sz.is_finite() || order.sz <= 0.0 {
+            return invalid(format!("Size must be positive, got {}", order.sz));
+        }
+        if !order.limit_px.is_finite() || order.limit_px <= 0.0 {
+            return invalid(format!("Price must be positive, got {}", order.limit_px));
+        }
+        
+        let precision = self.request(|| fetch_asset_precision(&self.info, &self.meta, &order.asset)).await?;
+        if !is_on_step(order.sz, precision.round_size(order.sz)) {
+            return invalid(format!("Size {} of {} has more than {} decimals", order.sz, order.asset, precision.sz_decimals));
+        }
+        let mut prices = vec![order.limit_px];
+        if let ClientOrder::Trigger(trigger) = &order.order_type {
+            prices.push(trigger.trigger_px);
+        }
+        for price in prices {
+            if !is_on_step(price, precision.round_price(price)) {
+                return invalid(format!("Price {} of {} is not on the tick size; try {}", price, order.asset, precision.round_price(price)));
+            }
+        }
+        
+        let notional = order.sz * order.limit_px;
+        if !order.reduce_only && notional < MIN_ORDER_VALUE_USD {
+            return invalid(format!("Order value {:.2} is below the minimum of {} USD", notional, MIN_ORDER_VALUE_USD));
+        }
+        
+        if !precision.is_spot && !order.reduce_only {
+            let user = self.account_address();
+            let data: RawActiveAssetData = self.request(|| post_info(&self.info, serde_json::json!({ "type": "activeAssetData", "user": user, "coin": order.asset }))).await?;
+            let side = if order.is_buy { 0 } else { 1 };
+            let max_size = parse_number("maxTradeSzs", &data.max_trade_szs[side])?;
+            if order.sz > max_size {
+                return invalid(format!("Size {} exceeds the {} the account's available margin allows", order.sz, max_size));
+            }
+        }
+        Ok(())
+    }
+    
+    pub fn place_order(&self, order: OrderRequest) -> Result<OrderResponse, HyperliquidError> {
+        self.block_on(self.place_order_async(order))
+    }
+    
+    pub async fn place_order_async(&self, order: OrderRequest) -> Result<OrderResponse, HyperliquidError> {
+        let client_order = self.to_client_order(order).await?;
+        let cloid = client_order.cloid;
+        
+        let dedupe = self.dedupe.as_ref().zip(cloid);
+        // Held until the outcome is recorded; released early on errors, which
+        // leaves the order unresolved for the next attempt.
+        let mut _sending = None;
+        if let Some((dedupe, cloid)) = dedupe {
+            loop {
+                match dedupe.begin(cloid) {
+                    DedupeCheck::Done(previous) => return Ok(previous),
+                    DedupeCheck::InFlight(sending) => drop(sending.lock().await),
+                    DedupeCheck::Unresolved(guard) => {
+                        if let Some(previous) = self.lookup_order(serde_json::json!(format_cloid(cloid))).await? {
+                            dedupe.finish(cloid, &previous);
+                            return Ok(previous);
+                        }
+                        _sending = Some(guard);
+                        break;
+                    }
+                    DedupeCheck::New(guard) => {
+                        _sending = Some(guard);
+                        break;
+                    }
+                }
+            }
+        }
+        
+        let result = single(self.send_orders(vec![client_order], OrderGrouping::Na).await?)?;
+        if let Some((dedupe, cloid)) = dedupe {
+            dedupe.finish(cloid, &result);
+        }
+        Ok(result)
+    }
+    
+    /// Signs `order` without sending it and returns the `/exchange` request
+    /// body as JSON, for `HyperliquidInfo::submit_signed` to broadcast from
+    /// another process. Limit and trigger orders are signed without network
+    /// access; market orders read the mid price to set their limit. The nonce
+    /// is the signing time, and the exchange refuses nonces more than a day or
+    /// two away from its clock, so submit the payload soon after.
+    pub fn sign_order(&self, order: OrderRequest) -> Result<String, HyperliquidError> {
+        self.block_on(self.sign_order_async(order))
+    }
+    
+    pub async fn sign_order_async(&self, order: OrderRequest) -> Result<String, HyperliquidError> {
+        let asset_id = self.asset_id(&order.asset)?;
+        let client_order = self.to_client_order(order).await?;
+        let request = self.signed_l1_request(&L1Action::Order {
+            orders: vec![OrderWire::new(client_order, asset_id)],
+            grouping: OrderGrouping::Na,
+            builder: self.builder.clone(),
+        })?;
+        Ok(request.to_string())
+    }
+    
+    /// What the exchange knows about the order with `id`, an oid or a cloid
+    /// string, or `None` if it never received it.
+    async fn lookup_order(&self, id: serde_json::Value) -> Result<Option<OrderResponse>, HyperliquidError> {
+        let user = self.account_address();
+        let response: RawOrderStatusResponse = self.request(|| post_info(&self.info, serde_json::json!({ "type": "orderStatus", "user": user, "oid": id }))).await?;
+        let status = OrderStatus::try_from(response)?;
+        let Some(order) = status.order else { return Ok(None) };
+        
+        // `orderStatus` reports refused orders as `rejected` or, for specific
+        // reasons, `<reason>Rejected`.
+        let exchange_status = if status.status.to_lowercase().ends_with("rejected") {
+            ExchangeStatus::Err { message: status.status.clone() }
+        } else {
+            ExchangeStatus::Ok
+        };
+        Ok(Some(OrderResponse {
+            resting: status.status == "open",
+            state: OrderState::from_order_status(&status.status),
+            status: status.status,
+            exchange_status,
+            oid: Some(order.oid),
+            filled_size: Some(status.filled_size).filter(|size| *size > 0.0),
+            average_price: None,
+            error: None,
+            cloid: order.cloid,
+            raw: None,
+        }))
+    }
+    
+    /// Follows up an order whose response was `WaitingForFill` or
+    /// `WaitingForTrigger`, polling `orderStatus` every
+    /// `ORDER_POLL_INTERVAL_MS` until the exchange reports it in another
+    /// state or `timeout_ms` passes. Returns the latest response either way,
+    /// so check `state` before treating it as final. Waiting orders carry no
+    /// oid, so `response` needs the cloid the order was placed with; other
+    /// responses are returned as they are.
+    pub fn wait_for_order(&self, response: OrderResponse, timeout_ms: u64) -> Result<OrderResponse, HyperliquidError> {
+        // The poll itself may take the whole timeout; leave room for its last request.
+        let timeout = Duration::from_millis(timeout_ms).saturating_add(self.timeout);
+        block_on_with_timeout(&self.runtime, timeout, cancellable(self.cancellation.as_deref(), self.wait_for_order_async(response, timeout_ms)))
+    }
+    
+    pub async fn wait_for_order_async(&self, response: OrderResponse, timeout_ms: u64) -> Result<OrderResponse, HyperliquidError> {
+        if !response.state.is_waiting() {
+            return Ok(response);
+        }
+        let id = match (response.oid, &response.cloid) {
+            (Some(oid), _) => serde_json::json!(oid),
+            (None, Some(cloid)) => serde_json::json!(cloid),
+            (None, None) => return Err(HyperliquidError::InvalidInput {
+                message: "Waiting orders can only be followed up by cloid; place the order with one".to_string(),
+            }),
+        };
+        
+        let deadline = tokio::time::Instant::now() + Duration::from_millis(timeout_ms);
+        loop {
+            if let Some(latest) = self.lookup_order(id.clone()).await? {
+                // An untriggered trigger order shows as open.
+                let still_waiting = response.state == OrderState::WaitingForTrigger && latest.state == OrderState::Resting;
+                if !still_waiting {
+                    return Ok(latest);
+                }
+            }
+            if tokio::time::Instant::now() >= deadline {
+                return Ok(response);
+            }
+            tokio::time::sleep_until(deadline.min(tokio::time::Instant::now() + Duration::from_millis(ORDER_POLL_INTERVAL_MS))).await;
+        }
+    }
+    
+    /// Places a limit order `offset_bps` basis points from the current mid,
+    /// rounded to the asset's tick: below the mid for buys, above it for
+    /// sells. A negative offset prices through the mid.
+    pub fn place_order_relative(&self, asset: String, side: OrderSide, size: f64, offset_bps: f64, tif: TimeInForce) -> Result<OrderResponse, HyperliquidError> {
+        self.block_on(self.place_order_relative_async(asset, side, size, offset_bps, tif))
+    }
+    
+    pub async fn place_order_relative_async(&self, asset: String, side: OrderSide, size: f64, offset_bps: f64, tif: TimeInForce) -> Result<OrderResponse, HyperliquidError> {
+        if !offset_bps.is_finite() {
+            return Err(HyperliquidError::InvalidInput { message: format!("Invalid offset {} bps", offset_bps) });
+        }
+        // A market price with negative slippage sits on our side of the mid.
+        let price = self.market_price(&asset, side.is_buy(), -offset_bps / 10_000.0).await?;
+        self.place_order_async(OrderRequest {
+            asset,
+            side,
+            size,
+            price,
+            reduce_only: false,
+            order_type: OrderType::Limit,
+            tif,
+            slippage: None,
+            cloid: None,
+        }).await
+    }
+    
+    /// Closes the whole `asset` position with a reduce-only market order,
+    /// `slippage` past the mid (the default slippage if not given).
+    pub fn close_position(&self, asset: String, slippage: Option<f64>) -> Result<OrderResponse, HyperliquidError> {
+        self.block_on(self.close_position_async(asset, slippage))
+    }
+    
+    pub async fn close_position_async(&self, asset: String, slippage: Option<f64>) -> Result<OrderResponse, HyperliquidError> {
+        let user = self.account_address();
+        let state: RawClearinghouseState = self.request(|| post_info(&self.info, serde_json::json!({ "type": "clearinghouseState", "user": user }))).await?;
+        let position = state.asset_positions
+            .into_iter()
+            .find(|p| p.position.coin == asset)
+            .map(Position::try_from)
+            .transpose()?
+            .filter(|p| p.size != 0.0)
+            .ok_or_else(|| HyperliquidError::InvalidInput { message: format!("No open {} position", asset) })?;
+        
+        self.place_order_async(OrderRequest {
+            asset,
+            side: OrderSide::from(position.size < 0.0),
+            size: position.size.abs(),
+            price: 0.0,
+            reduce_only: true,
+            order_type: OrderType::Market,
+            tif: TimeInForce::Ioc,
+            slippage,
+            cloid: None,
+        }).await
+    }
+    
+    /// Sets `asset` to `margin_mode` at `leverage`, then places `order`. The
+    /// order is only sent once the margin update succeeded, so a position it
+    /// opens never ends up in the account's default mode. The exchange refuses
+    /// to switch modes while a position in `asset` is open; that rejection is
+    /// returned as an error and nothing is placed.
+    pub fn place_order_with_margin(&self, order: OrderRequest, margin_mode: MarginMode, leverage: u32) -> Result<OrderResponse, HyperliquidError> {
+        self.block_on(self.place_order_with_margin_async(order, margin_mode, leverage))
+    }
+    
+    pub async fn place_order_with_margin_async(&self, order: OrderRequest, margin_mode: MarginMode, leverage: u32) -> Result<OrderResponse, HyperliquidError> {
+        if is_spot_asset(&order.asset) {
+            return Err(HyperliquidError::InvalidInput {
+                message: format!("{} is a spot market and has no margin mode", order.asset),
+            });
+        }
+        self.update_leverage_async(order.asset.clone(), leverage, margin_mode == MarginMode::Cross).await?;
+        self.place_order_async(order).await
+    }
+    
+    /// Places `order` and returns just its oid: `Some` once it rests or fills,
+    /// `None` for trigger orders still waiting. A rejection is returned as an
+    /// error rather than an `error` status.
+    pub fn place_order_oid(&self, order: OrderRequest) -> Result<Option<u64>, HyperliquidError> {
+        self.block_on(self.place_order_oid_async(order))
+    }
+    
+    pub async fn place_order_oid_async(&self, order: OrderRequest) -> Result<Option<u64>, HyperliquidError> {
+        let response = self.place_order_async(order).await?;
+        match response.error {
+            Some(message) => Err(HyperliquidError::from_api_message(message)),
+            None => Ok(response.oid),
+        }
+    }
+    
+    /// Submits all orders in a single request. The result is index-aligned with
+    /// `orders`; rejected orders come back with an `error` status rather than
+    /// failing the whole call.
+    ///
+    /// With `OrderGrouping::NormalTpsl` the first order is the entry and the
+    /// rest must be reduce-only triggers on the same perp; with `PositionTpsl`
+    /// every order must be such a trigger. Cancels can't join a placement
+    /// request, so a cancel and a place never land atomically; use
+    /// `modify_order` or `cancel_replace` to swap an order instead.
+    pub fn place_orders_bulk(&self, orders: Vec<OrderRequest>, grouping: OrderGrouping) -> Result<Vec<OrderResponse>, HyperliquidError> {
+        self.block_on(self.place_orders_bulk_async(orders, grouping))
+    }
+    
+    pub async fn place_orders_bulk_async(&self, orders: Vec<OrderRequest>, grouping: OrderGrouping) -> Result<Vec<OrderResponse>, HyperliquidError> {
+        if grouping != OrderGrouping::Na {
+            return self.place_tpsl_group(orders, grouping).await;
+        }
+        let mut client_orders = Vec::with_capacity(orders.len());
+        for order in orders {
+            client_orders.push(self.to_client_order(order).await?);
+        }
+        self.send_orders(client_orders, OrderGrouping::Na).await
+    }
+    
+    /// Places `entry` together with a reduce-only take profit and/or stop loss
+    /// in one request, as the Hyperliquid frontend does. The exchange holds
+    /// the legs back until the entry fills and sizes them to the filled
+    /// amount. Results are the entry first, then take profit, then stop loss.
+    pub fn place_order_with_tpsl(&self, entry: OrderRequest, take_profit: Option<TriggerSpec>, stop_loss: Option<TriggerSpec>) -> Result<Vec<OrderResponse>, HyperliquidError> {
+        self.block_on(self.place_order_with_tpsl_async(entry, take_profit, stop_loss))
+    }
+    
+    pub async fn place_order_with_tpsl_async(&self, entry: OrderRequest, take_profit: Option<TriggerSpec>, stop_loss: Option<TriggerSpec>) -> Result<Vec<OrderResponse>, HyperliquidError> {
+        if is_spot_asset(&entry.asset) {
+            return Err(HyperliquidError::InvalidInput {
+                message: format!("{} is a spot market; take profit and stop loss need a perp", entry.asset),
+            });
+        }
+        let legs = [(take_profit, TpSl::TakeProfit), (stop_loss, TpSl::StopLoss)];
+        let mut orders = vec![entry.clone()];
+        for (spec, tpsl) in legs {
+            if let Some(spec) = spec {
+                orders.push(self.bracket_leg(&entry, spec, tpsl).await?);
+            }
+        }
+        self.place_tpsl_group(orders, OrderGrouping::NormalTpsl).await
+    }
+    
+    /// Sends `orders` as one `NormalTpsl` or `PositionTpsl` group.
+    async fn place_tpsl_group(&self, orders: Vec<OrderRequest>, grouping: OrderGrouping) -> Result<Vec<OrderResponse>, HyperliquidError> {
+        let Some(first) = orders.first() else {
+            return Err(HyperliquidError::InvalidInput { message: "No orders to place".to_string() });
+        };
+        if is_spot_asset(&first.asset) {
+            return Err(HyperliquidError::InvalidInput {
+                message: format!("{} is a spot market; take profit and stop loss need a perp", first.asset),
+            });
+        }
+        let skip = if grouping == OrderGrouping::NormalTpsl { 1 } else { 0 };
+        for order in &orders[skip..] {
+            if order.asset != first.asset || !order.reduce_only || !matches!(order.order_type, OrderType::Trigger { .. }) {
+                return Err(HyperliquidError::InvalidInput {
+                    message: format!("{:?} groups only take reduce-only trigger orders on {}", grouping, first.asset),
+                });
+            }
+        }
+        
+        let mut client_orders = Vec::with_capacity(orders.len());
+        for order in orders {
+            client_orders.push(self.to_client_order(order).await?);
+        }
+        self.send_orders(client_orders, grouping).await
+    }
+    
+    /// Signs `orders` as one order action and sends it. The result is
+    /// index-aligned with `orders`.
+    async fn send_orders(&self, orders: Vec<ClientOrderRequest>, grouping: OrderGrouping) -> Result<Vec<OrderResponse>, HyperliquidError> {
+        let mut wires = Vec::with_capacity(orders.len());
+        let mut cloids = Vec::with_capacity(orders.len());
+        for order in orders {
+            let asset_id = self.asset_id(&order.asset)?;
+            cloids.push(order.cloid);
+            wires.push(OrderWire::new(order, asset_id));
+        }
+        
+        let response = self.send_l1_action(L1Action::Order {
+            orders: wires,
+            grouping,
+            builder: self.builder.clone(),
+        }).await?;
+        Ok(order_statuses(&response)?
+            .into_iter()
+            .zip(cloids)
+            .map(|(status, cloid)| {
+                let mut result = OrderResponse::from(status);
+                result.cloid = cloid.map(format_cloid);
+                result
+            })
+            .collect())
+    }
+    
+    /// Signs `modifies` as one batch modify action and sends it. The result
+    /// is index-aligned with `modifies`.
+    async fn send_modifies(&self, modifies: Vec<(u64, ClientOrderRequest)>) -> Result<Vec<OrderResponse>, HyperliquidError> {
+        let mut wires = Vec::with_capacity(modifies.len());
+        let mut cloids = Vec::with_capacity(modifies.len());
+        for (oid, order) in modifies {
+            let asset_id = self.asset_id(&order.asset)?;
+            cloids.push(order.cloid);
+            wires.push(ModifyWire { oid, order: OrderWire::new(order, asset_id) });
+        }
+        
+        let response = self.send_l1_action(L1Action::BatchModify { modifies: wires }).await?;
+        Ok(order_statuses(&response)?
+            .into_iter()
+            .zip(cloids)
+            .map(|(status, cloid)| {
+                let mut result = OrderResponse::from(status);
+                result.cloid = cloid.map(format_cloid);
+                result
+            })
+            .collect())
+    }
+    
+    /// The reduce-only trigger order closing `entry` at `spec`. Market legs
+    /// get a limit the default slippage past the trigger, as the exchange
+    /// requires one even for market triggers.
+    async fn bracket_leg(&self, entry: &OrderRequest, spec: TriggerSpec, tpsl: TpSl) -> Result<OrderRequest, HyperliquidError> {
+        let side = OrderSide::from(!entry.side.is_buy());
+        let price = match spec.limit_price {
+            Some(price) => price,
+            None => {
+                let precision = self.request(|| fetch_asset_precision(&self.info, &self.meta, &entry.asset)).await?;
+                let slippage = if side.is_buy() { 1.0 + self.default_slippage() } else { 1.0 - self.default_slippage() };
+                precision.round_price(spec.trigger_price * slippage)
+            }
+        };
+        Ok(OrderRequest {
+            asset: entry.asset.clone(),
+            side,
+            size: entry.size,
+            price,
+            reduce_only: true,
+            order_type: OrderType::Trigger {
+                trigger_price: spec.trigger_price,
+                is_market: spec.limit_price.is_none(),
+                tpsl,
+            },
+            tif: TimeInForce::Gtc,
+            slippage: None,
+            cloid: None,
+        })
+    }
+    
+    /// Amends a resting order in place, keeping its queue priority where the
+    /// exchange allows it.
+    pub fn modify_order(&self, oid: u64, new_order: OrderRequest) -> Result<OrderResponse, HyperliquidError> {
+        self.block_on(self.modify_order_async(oid, new_order))
+    }
+    
+    pub async fn modify_order_async(&self, oid: u64, new_order: OrderRequest) -> Result<OrderResponse, HyperliquidError> {
+        let order = self.to_client_order(new_order).await?;
+        single(self.send_modifies(vec![(oid, order)]).await?)
+    }
+    
+    /// Replaces resting order `oid` with `new_order` (on the same asset) without
+    /// a window in which neither is live:
+    ///
+    /// 1. The order is modified in place. If the exchange accepts, its
+    ///    response is returned.
+    /// 2. If the modify is rejected (e.g. a post-only price that would cross),
+    ///    `new_order` is placed as a fresh order. If that is rejected too, its
+    ///    response is returned and `oid` is left as it was.
+    /// 3. Only once the new order is accepted is `oid` cancelled. A failed
+    ///    cancel, typically because `oid` filled in the meantime, is logged
+    ///    and the new order's response is still returned; both orders may
+    ///    then have executed.
+    ///
+    /// A transport error at any step is returned as is, and the state of the
+    /// orders should be checked with `get_open_orders`.
+    pub fn cancel_replace(&self, oid: u64, new_order: OrderRequest) -> Result<OrderResponse, HyperliquidError> {
+        self.block_on(self.cancel_replace_async(oid, new_order))
+    }
+    
+    pub async fn cancel_replace_async(&self, oid: u64, new_order: OrderRequest) -> Result<OrderResponse, HyperliquidError> {
+        let asset = new_order.asset.clone();
+        let modified = self.modify_order_async(oid, new_order.clone()).await?;
+        let Some(reason) = modified.error else { return Ok(modified) };
+        log::debug!("modify of order {} rejected ({}); placing the replacement before cancelling", oid, reason);
+        
+        let placed = self.place_order_async(new_order).await?;
+        if placed.error.is_some() {
+            return Ok(placed);
+        }
+        let cancel_error = match self.cancel_order_async(CancelRequest { asset, oid }).await {
+            Ok(response) => response.error,
+            Err(e) => Some(e.to_string()),
+        };
+        if let Some(e) = cancel_error {
+            log::warn!("replacement placed but cancelling order {} failed: {}", oid, e);
+        }
+        Ok(placed)
+    }
+    
+    /// Amends several resting orders in one request. The result is index-aligned
+    /// with `modifies`.
+    pub fn bulk_modify(&self, modifies: Vec<ModifyRequest>) -> Result<Vec<OrderResponse>, HyperliquidError> {
+        self.block_on(self.bulk_modify_async(modifies))
+    }
+    
+    pub async fn bulk_modify_async(&self, modifies: Vec<ModifyRequest>) -> Result<Vec<OrderResponse>, HyperliquidError> {
+        let mut client_modifies = Vec::with_capacity(modifies.len());
+        for modify in modifies {
+            client_modifies.push((modify.oid, self.to_client_order(modify.order).await?));
+        }
+        self.send_modifies(client_modifies).await
+    }
+    
+    pub fn cancel_order(&self, cancel: CancelRequest) -> Result<CancelResponse, HyperliquidError> {
+        self.block_on(self.cancel_order_async(cancel))
+    }
+    
+    pub async fn cancel_order_async(&self, cancel: CancelRequest) -> Result<CancelResponse, HyperliquidError> {
+        single(self.cancel_orders_async(vec![cancel]).await?)
+    }
+    
+    /// Cancels an order by the client order id it was placed with, so callers
+    /// don't need to look up the exchange-assigned oid first.
+    pub fn cancel_by_cloid(&self, asset: String, cloid: String) -> Result<CancelResponse, HyperliquidError> {
+        self.block_on(self.cancel_by_cloid_async(asset, cloid))
+    }
+    
+    pub async fn cancel_by_cloid_async(&self, asset: String, cloid: String) -> Result<CancelResponse, HyperliquidError> {
+        let cloid = parse_cloid(&cloid)?;
+        let cancel = CancelCloidWire { asset: self.asset_id(&asset)?, cloid: format_cloid(cloid) };
+        let response = self.send_l1_action(L1Action::CancelByCloid { cancels: vec![cancel] }).await?;
+        Ok(single(order_statuses(&response)?)?.into())
+    }
+    
+    /// Cancels all of `cancels` in a single request. The result is
+    /// index-aligned with `cancels`; an order that could not be canceled comes
+    /// back with an `error` status rather than failing the whole call.
+    pub fn cancel_orders(&self, cancels: Vec<CancelRequest>) -> Result<Vec<CancelResponse>, HyperliquidError> {
+        self.block_on(self.cancel_orders_async(cancels))
+    }
+    
+    pub async fn cancel_orders_async(&self, cancels: Vec<CancelRequest>) -> Result<Vec<CancelResponse>, HyperliquidError> {
+        if cancels.is_empty() {
+            return Ok(Vec::new());
+        }
+        let mut wires = Vec::with_capacity(cancels.len());
+        for cancel in cancels {
+            wires.push(CancelWire { a: self.asset_id(&cancel.asset)?, o: cancel.oid });
+        }
+        
+        let response = self.send_l1_action(L1Action::Cancel { cancels: wires }).await?;
+        Ok(order_statuses(&response)?.into_iter().map(CancelResponse::from).collect())
+    }
+    
+    /// Cancels every open order of this account (or of its vault), or only
+    /// those on `asset` when given. The result is index-aligned with the orders
+    /// that were open at the time of the call.
+    pub fn cancel_all_orders(&self, asset: Option<String>) -> Result<Vec<CancelResponse>, HyperliquidError> {
+        self.block_on(self.cancel_all_orders_async(asset))
+    }
+    
+    pub async fn cancel_all_orders_async(&self, asset: Option<String>) -> Result<Vec<CancelResponse>, HyperliquidError> {
+        // A typo'd asset would otherwise match no orders and look like success.
+        if let Some(asset) = &asset {
+            self.asset_id(asset)?;
+        }
+        
+        // There is no "cancel everything" action, so look up what is open and
+        // cancel those orders by oid.
+        let user = self.account_address();
+        let orders: Vec<RawOpenOrder> = self.request(|| post_info(&self.info, serde_json::json!({ "type": "openOrders", "user": user }))).await?;
+        self.cancel_orders_async(open_order_cancels(orders, asset.as_deref())).await
+    }
+    
+    /// Sets the leverage and margin mode (cross when `is_cross`, isolated
+    /// otherwise) used for `asset`. This only takes effect for positions
+    /// opened afterwards, so call it before entering the trade, or use
+    /// `place_order_with_margin` to do both in order.
+    pub fn update_leverage(&self, asset: String, leverage: u32, is_cross: bool) -> Result<ExchangeStatus, HyperliquidError> {
+        self.block_on(self.update_leverage_async(asset, leverage, is_cross))
+    }
+    
+    pub async fn update_leverage_async(&self, asset: String, leverage: u32, is_cross: bool) -> Result<ExchangeStatus, HyperliquidError> {
+        let meta = self.request(|| fetch_asset_meta(&self.info, &self.meta, &asset)).await?;
+        if leverage == 0 || leverage > meta.max_leverage {
+            return Err(HyperliquidError::InvalidInput {
+                message: format!("Leverage {} out of range for {} (1-{})", leverage, asset, meta.max_leverage),
+            });
+        }
+        
+        if is_cross && meta.only_isolated {
+            return Err(HyperliquidError::InvalidInput {
+                message: format!("{} only supports isolated margin", asset),
+            });
+        }
+        
+        let response = self.send(self.client.update_leverage(leverage, &asset, is_cross, None)).await?;
+        exchange_status(response)
+    }
+    
+    /// Adds (positive `amount`) or removes (negative `amount`) USDC margin from
+    /// an isolated position.
+    pub fn update_isolated_margin(&self, asset: String, amount: f64) -> Result<ExchangeStatus, HyperliquidError> {
+        self.block_on(self.update_isolated_margin_async(asset, amount))
+    }
+    
+    pub async fn update_isolated_margin_async(&self, asset: String, amount: f64) -> Result<ExchangeStatus, HyperliquidError> {
+        self.asset_id(&asset)?;
+        if !amount.is_finite() || amount == 0.0 {
+            return Err(HyperliquidError::InvalidInput {
+                message: format!("Margin amount must be a non-zero number, got {}", amount),
+            });
+        }
+        
+        let response = self.send(self.client.update_isolated_margin(amount, &asset, None)).await?;
+        exchange_status(response)
+    }
+    
+    /// Withdraws `amount` USDC to `destination` on Arbitrum through the bridge.
+    /// The bridge fee is taken out of `amount`.
+    pub fn withdraw_from_bridge(&self, amount: f64, destination: String) -> Result<ExchangeStatus, HyperliquidError> {
+        self.block_on(self.withdraw_from_bridge_async(amount, destination))
+    }
+    
+    pub async fn withdraw_from_bridge_async(&self, amount: f64, destination: String) -> Result<ExchangeStatus, HyperliquidError> {
+        let destination = parse_destination(&destination)?;
+        if amount.is_nan() || amount <= WITHDRAWAL_FEE_USDC {
+            return Err(HyperliquidError::InvalidInput {
+                message: format!("Withdrawal amount must exceed the {} USDC bridge fee", WITHDRAWAL_FEE_USDC),
+            });
+        }
+        
+        let response = self.send(self.client.withdraw_from_bridge(&amount.to_string(), &format!("{:?}", destination), None)).await?;
+        exchange_status(response)
+    }
+    
+    /// Sends `amount` USDC from this wallet's perp balance to `destination` on
+    /// Hyperliquid.
+    pub fn usd_transfer(&self, amount: f64, destination: String) -> Result<ExchangeStatus, HyperliquidError> {
+        self.block_on(self.usd_transfer_async(amount, destination))
+    }
+    
+    pub async fn usd_transfer_async(&self, amount: f64, destination: String) -> Result<ExchangeStatus, HyperliquidError> {
+        let destination = parse_destination(&destination)?;
+        check_positive_amount(amount)?;
+        
+        let response = self.send(self.client.usdc_transfer(&amount.to_string(), &format!("{:?}", destination), None)).await?;
+        exchange_status(response)
+    }
+    
+    /// Sends `amount` of the spot token `token` (e.g. `PURR`) to `destination`.
+    pub fn spot_transfer(&self, token: String, amount: f64, destination: String) -> Result<ExchangeStatus, HyperliquidError> {
+        self.block_on(self.spot_transfer_async(token, amount, destination))
+    }
+    
+    pub async fn spot_transfer_async(&self, token: String, amount: f64, destination: String) -> Result<ExchangeStatus, HyperliquidError> {
+        let destination = parse_destination(&destination)?;
+        check_positive_amount(amount)?;
+        let token = self.request(|| fetch_spot_token(&self.info, &self.meta, &token)).await?;
+        
+        let response = self.send(self.client.spot_transfer(&amount.to_string(), &format!("{:?}", destination), &token.wire_name(), None)).await?;
+        exchange_status(response)
+    }
+    
+    /// Moves `amount` USDC from the spot wallet to the perp wallet, or back when
+    /// `to_perp` is false.
+    pub fn class_transfer(&self, amount: f64, to_perp: bool) -> Result<ExchangeStatus, HyperliquidError> {
+        self.block_on(self.class_transfer_async(amount, to_perp))
+    }
+    
+    pub async fn class_transfer_async(&self, amount: f64, to_perp: bool) -> Result<ExchangeStatus, HyperliquidError> {
+        check_positive_amount(amount)?;
+        
+        let response = self.send(self.client.class_transfer(amount, to_perp, None)).await?;
+        exchange_status(response)
+    }
+    
+    /// Authorizes `agent_address` to trade on this account without being able
+    /// to withdraw, so the main key doesn't need to live on the device.
+    pub fn approve_agent(&self, agent_address: String, name: Option<String>) -> Result<ExchangeStatus, HyperliquidError> {
+        self.block_on(self.approve_agent_async(agent_address, name))
+    }
+    
+    pub async fn approve_agent_async(&self, agent_address: String, name: Option<String>) -> Result<ExchangeStatus, HyperliquidError> {
+        let agent = agent_address.parse::<Address>().map_err(|e| HyperliquidError::InvalidInput {
+            message: format!("Invalid agent address {:?}: {}", agent_address, e),
+        })?;
+        if agent == self.client.wallet.address() {
+            return Err(HyperliquidError::InvalidInput {
+                message: "Agent address must differ from the account address".to_string(),
+            });
+        }
+        
+        // The SDK's approve_agent always generates a fresh key, so the action
+        // is signed here to approve an address the caller already holds.
+        let hyperliquid_chain = if self.client.http_client.is_mainnet() { "Mainnet" } else { "Testnet" };
+        let nonce = self.nonce();
+        let signature = sign_user_action(
+            &self.client.wallet,
+            "HyperliquidTransaction:ApproveAgent(string hyperliquidChain,address agentAddress,string agentName,uint64 nonce)",
+            &[
+                Eip712Field::String(hyperliquid_chain),
+                Eip712Field::Address(agent),
+                Eip712Field::String(name.as_deref().unwrap_or("")),
+                Eip712Field::U64(nonce),
+            ],
+        )?;
+        
+        let mut action = serde_json::json!({
+            "type": "approveAgent",
+            "signatureChainId": format!("{:#x}", USER_ACTION_CHAIN_ID),
+            "hyperliquidChain": hyperliquid_chain,
+            "agentAddress": format!("{:?}", agent),
+            "nonce": nonce,
+        });
+        if let Some(name) = name {
+            action["agentName"] = serde_json::Value::String(name);
+        }
+        let request = serde_json::json!({
+            "action": action,
+            "nonce": nonce,
+            "signature": signature,
+            "vaultAddress": null,
+        });
+        
+        let body = self.post_exchange(request).await?;
+        exchange_status(parse_exchange_reply(&body)?)
+    }
+    
+    /// Registers the referral code `code` for this account. The exchange only
+    /// accepts this once, for accounts that haven't traded much yet.
+    pub fn set_referrer(&self, code: String) -> Result<ExchangeStatus, HyperliquidError> {
+        self.block_on(self.set_referrer_async(code))
+    }
+    
+    pub async fn set_referrer_async(&self, code: String) -> Result<ExchangeStatus, HyperliquidError> {
+        if code.is_empty() || code.len() > MAX_REFERRAL_CODE_LEN || !code.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err(HyperliquidError::InvalidInput {
+                message: format!("Invalid referral code {:?}: expected 1-{} letters or digits", code, MAX_REFERRAL_CODE_LEN),
+            });
+        }
+        
+        let response = self.send(self.client.set_referrer(code, None)).await?;
+        exchange_status(response)
+    }
+    
+    /// The exchange's id for `asset`.
+    fn asset_id(&self, asset: &str) -> Result<u32, HyperliquidError> {
+        self.assets.read().unwrap_or_else(|e| e.into_inner()).get(asset).copied()
+            .ok_or_else(|| HyperliquidError::InvalidInput { message: format!("Unknown asset {}", asset) })
+    }
+    
+    /// Signs `action` with the exchange-clock nonce and sends it. Returns the
+    /// `response` object of the exchange's reply.
+    async fn send_l1_action(&self, action: L1Action) -> Result<serde_json::Value, HyperliquidError> {
+        let request = self.signed_l1_request(&action)?;
+        let body = self.post_exchange(request).await?;
+        exchange_response(&body)
+    }
+    
+    /// The `/exchange` request body for `action`, signed with a fresh nonce.
+    fn signed_l1_request(&self, action: &L1Action) -> Result<serde_json::Value, HyperliquidError> {
+        let nonce = self.nonce();
+        let vault = self.client.vault_address;
+        let signature = sign_l1_action(&self.client.wallet, action, nonce, vault, self.client.http_client.is_mainnet())?;
+        Ok(serde_json::json!({
+            "action": action,
+            "nonce": nonce,
+            "signature": signature,
+            "vaultAddress": vault.map(|vault| format!("{:?}", vault)),
+        }))
+    }
+    
+    /// Starts a TWAP that works `size` of `asset` into the market over
+    /// `duration_minutes`, and returns its id for `cancel_twap`.
+    pub fn place_twap(&self, asset: String, side: OrderSide, size: f64, duration_minutes: u32, reduce_only: bool) -> Result<u64, HyperliquidError> {
+        self.block_on(self.place_twap_async(asset, side, size, duration_minutes, reduce_only))
+    }
+    
+    pub async fn place_twap_async(&self, asset: String, side: OrderSide, size: f64, duration_minutes: u32, reduce_only: bool) -> Result<u64, HyperliquidError> {
+        let asset_id = self.asset_id(&asset)?;
+        if size.is_nan() || size <= 0.0 {
+            return Err(HyperliquidError::InvalidInput { message: format!("Size must be positive, got {}", size) });
+        }
+        if !(TWAP_MIN_MINUTES..=TWAP_MAX_MINUTES).contains(&duration_minutes) {
+            return Err(HyperliquidError::InvalidInput {
+                message: format!("TWAP duration must be between {} and {} minutes, got {}", TWAP_MIN_MINUTES, TWAP_MAX_MINUTES, duration_minutes),
+            });
+        }
+        
+        let response = self.send_l1_action(L1Action::TwapOrder {
+            twap: TwapWire {
+                a: asset_id,
+                b: side.is_buy(),
+                s: float_to_wire(size),
+                r: reduce_only,
+                m: duration_minutes,
+                t: false,
+            },
+        }).await?;
+        let status: RawTwapStatus = serde_json::from_value(response["data"]["status"].clone())
+            .map_err(|e| HyperliquidError::ApiError { message: format!("Unexpected TWAP response {}: {}", response, e) })?;
+        match status {
+            RawTwapStatus::Running { twap_id } => Ok(twap_id),
+            RawTwapStatus::Error(message) => Err(HyperliquidError::from_api_message(message)),
+        }
+    }
+    
+    pub fn cancel_twap(&self, asset: String, twap_id: u64) -> Result<ExchangeStatus, HyperliquidError> {
+        self.block_on(self.cancel_twap_async(asset, twap_id))
+    }
+    
+    pub async fn cancel_twap_async(&self, asset: String, twap_id: u64) -> Result<ExchangeStatus, HyperliquidError> {
+        let asset_id = self.asset_id(&asset)?;
+        
+        let response = self.send_l1_action(L1Action::TwapCancel { a: asset_id, t: twap_id }).await?;
+        // A single `status` (`success` or an error) rather than a list.
+        let status: ExchangeDataStatus = serde_json::from_value(response["data"]["status"].clone())
+            .map_err(|e| HyperliquidError::ApiError { message: format!("Unexpected TWAP cancel response {}: {}", response, e) })?;
+        Ok(ExchangeStatus::from(&status))
+    }
+    
+    /// Dead man's switch: has the exchange cancel all open orders at `time_ms`
+    /// (milliseconds since the epoch) unless this is called again with a later
+    /// time first. `None` clears the scheduled cancel.
+    pub fn schedule_cancel(&self, time_ms: Option<u64>) -> Result<ExchangeStatus, HyperliquidError> {
+        self.block_on(self.schedule_cancel_async(time_ms))
+    }
+    
+    pub async fn schedule_cancel_async(&self, time_ms: Option<u64>) -> Result<ExchangeStatus, HyperliquidError> {
+        if let Some(time_ms) = time_ms {
+            let earliest = self.nonce() + MIN_SCHEDULE_CANCEL_DELAY_MS;
+            if time_ms < earliest {
+                return Err(HyperliquidError::InvalidInput {
+                    message: format!("Scheduled cancel time {} must be at least {}ms in the future", time_ms, MIN_SCHEDULE_CANCEL_DELAY_MS),
+                });
+            }
+        }
+        
+        let request = self.signed_l1_request(&L1Action::ScheduleCancel { time: time_ms })?;
+        let body = self.post_exchange(request).await?;
+        exchange_status(parse_exchange_reply(&body)?)
+    }
+    
+    /// Lets `builder` charge up to `max_fee_rate` (a percentage such as
+    /// `"0.01%"`) on this account's orders. Orders carrying a builder code are
+    /// rejected until the builder has been approved.
+    pub fn approve_builder_fee(&self, builder: String, max_fee_rate: String) -> Result<ExchangeStatus, HyperliquidError> {
+        self.block_on(self.approve_builder_fee_async(builder, max_fee_rate))
+    }
+    
+    pub async fn approve_builder_fee_async(&self, builder: String, max_fee_rate: String) -> Result<ExchangeStatus, HyperliquidError> {
+        let builder_address = builder.parse::<Address>().map_err(|e| HyperliquidError::InvalidInput {
+            message: format!("Invalid builder address {:?}: {}", builder, e),
+        })?;
+        let rate = max_fee_rate.strip_suffix('%').and_then(|rate| rate.parse::<f64>().ok());
+        let max_rate_percent = f64::from(MAX_SPOT_BUILDER_FEE) / 1_000.0;
+        if !rate.is_some_and(|rate| rate > 0.0 && rate <= max_rate_percent) {
+            return Err(HyperliquidError::InvalidInput {
+                message: format!("Invalid max fee rate {:?}: expected a percentage up to {}%, e.g. \"0.01%\"", max_fee_rate, max_rate_percent),
+            });
+        }
+        
+        let hyperliquid_chain = if self.client.http_client.is_mainnet() { "Mainnet" } else { "Testnet" };
+        let nonce = self.nonce();
+        let signature = sign_user_action(
+            &self.client.wallet,
+            "HyperliquidTransaction:ApproveBuilderFee(string hyperliquidChain,string maxFeeRate,address builder,uint64 nonce)",
+            &[
+                Eip712Field::String(hyperliquid_chain),
+                Eip712Field::String(&max_fee_rate),
+                Eip712Field::Address(builder_address),
+                Eip712Field::U64(nonce),
+            ],
+        )?;
+        let request = serde_json::json!({
+            "action": {
+                "type": "approveBuilderFee",
+                "signatureChainId": format!("{:#x}", USER_ACTION_CHAIN_ID),
+                "hyperliquidChain": hyperliquid_chain,
+                "maxFeeRate": max_fee_rate,
+                "builder": format!("{:?}", builder_address),
+                "nonce": nonce,
+            },
+            "nonce": nonce,
+            "signature": signature,
+            "vaultAddress": null,
+        });
+        
+        let body = self.post_exchange(request).await?;
+        exchange_status(parse_exchange_reply(&body)?)
+    }
+}
+
+pub trait MidsCallback: Send + Sync {
+    fn on_mids(&self, mids: HashMap<String, f64>);
+}
+
+pub trait L2BookCallback: Send + Sync {
+    fn on_book(&self, book: L2Book);
+}
+
+#[derive(Debug, Clone)]
+pub enum UserEventKind {
+    Fill,
+    OrderUpdate,
+}
+
+#[derive(Debug, Clone)]
+pub struct UserEvent {
+    pub kind: UserEventKind,
+    pub asset: String,
+    pub side: OrderSide,
+    pub size: f64,
+    pub price: f64,
+    pub oid: u64,
+    pub timestamp: u64,
+    /// Order status such as `open`, `filled` or `canceled`; only set for
+    /// order updates.
+    pub status: Option<String>,
+}
+
+pub trait UserEventCallback: Send + Sync {
+    fn on_event(&self, event: UserEvent);
+}
+
+#[derive(Debug, Clone)]
+pub enum ConnectionStatus {
+    Connected,
+    Reconnecting,
+}
+
+pub trait ConnectionStatusCallback: Send + Sync {
+    fn on_status(&self, status: ConnectionStatus);
+}
+
+struct ActiveSubscription {
+    subscriptions: Vec<Subscription>,
+    sender: tokio::sync::mpsc::UnboundedSender<Message>,
+    /// Ids the SDK assigned on the current connection.
+    sdk_ids: Vec<u32>,
+}
+
+/// Websocket state behind `HyperliquidInfo`. The SDK needs mutable access to
+/// subscribe, so subscriptions get their own client, created on first use.
+#[derive(Default)]
+struct WsState {
+    client: Option<InfoClient>,
+    subscriptions: HashMap<u32, ActiveSubscription>,
+    next_id: u32,
+}
+
+/// Shared between a `HyperliquidInfo`, its receive loops and the supervisor
+/// that reconnects the websocket.
+struct WsShared {
+    state: tokio::sync::Mutex<WsState>,
+    base_url: BaseUrl,
+    /// Woken by a receive loop when the SDK reports a dropped connection.
+    reconnect: tokio::sync::Notify,
+    status_callback: std::sync::Mutex<Option<Box<dyn ConnectionStatusCallback>>>,
+    /// Set by `HyperliquidInfo::shutdown`; nothing reconnects or subscribes
+    /// afterwards.
+    closed: AtomicBool,
+    /// Wakes the supervisor so it exits right away on shutdown.
+    shutdown: tokio::sync::Notify,
+}
+
+impl WsShared {
+    fn notify_status(&self, status: ConnectionStatus) {
+        if let Some(callback) = self.status_callback.lock().unwrap_or_else(|e| e.into_inner()).as_ref() {
+            callback.on_status(status);
+        }
+    }
+    
+    async fn reconnect_once(&self) -> Result<(), HyperliquidError> {
+        let mut state = self.state.lock().await;
+        if self.closed.load(Ordering::Relaxed) {
+            return Ok(());
+        }
+        let WsState { client, subscriptions, .. } = &mut *state;
+        
+        // Detach our senders from the old connection so it can't deliver
+        // duplicates if it turns out to still be alive.
+        if let Some(old_client) = client.as_mut() {
+            for active in subscriptions.values() {
+                for sdk_id in &active.sdk_ids {
+                    let _ = old_client.unsubscribe(*sdk_id).await;
+                }
+            }
+        }
+        
+        let mut new_client = connect_info(&self.base_url).await?;
+        for active in subscriptions.values_mut() {
+            let mut sdk_ids = Vec::with_capacity(active.subscriptions.len());
+            for subscription in &active.subscriptions {
+                sdk_ids.push(new_client.subscribe(subscription.clone(), active.sender.clone()).await?);
+            }
+            active.sdk_ids = sdk_ids;
+        }
+        *client = Some(new_client);
+        Ok(())
+    }
+}
+
+/// Watches the websocket for as long as its `HyperliquidInfo` is alive and
+/// reconnects it when the SDK reports a drop.
+async fn supervise_ws(shared: std::sync::Weak<WsShared>) {
+    loop {
+        let Some(ws) = shared.upgrade() else { return };
+        if ws.closed.load(Ordering::Relaxed) {
+            return;
+        }
+        let dropped = tokio::select! {
+            _ = ws.shutdown.notified() => return,
+            _ = ws.reconnect.notified() => true,
+            _ = tokio::time::sleep(Duration::from_millis(WS_SUPERVISOR_CHECK_MS)) => false,
+        };
+        drop(ws);
+        if dropped {
+            reconnect_with_backoff(&shared).await;
+        }
+    }
+}
+
+/// Replaces the websocket client and re-establishes every active
+/// subscription on it, retrying with capped, jittered exponential backoff.
+/// The client is only held during an attempt, so retries stop once every
+/// `HyperliquidInfo` sharing it has been dropped.
+async fn reconnect_with_backoff(shared: &std::sync::Weak<WsShared>) {
+    let mut attempt = 0;
+    loop {
+        let Some(ws) = shared.upgrade() else { return };
+        if ws.closed.load(Ordering::Relaxed) {
+            return;
+        }
+        if attempt == 0 {
+            ws.notify_status(ConnectionStatus::Reconnecting);
+        }
+        match ws.reconnect_once().await {
+            Ok(()) => {
+                if !ws.closed.load(Ordering::Relaxed) {
+                    ws.notify_status(ConnectionStatus::Connected);
+                }
+                return;
+            }
+            Err(e) => log::warn!("websocket reconnect attempt {} failed: {}", attempt + 1, e),
+        }
+        drop(ws);
+        tokio::time::sleep(reconnect_delay(attempt)).await;
+        attempt += 1;
+    }
+}
+
+fn reconnect_delay(attempt: u32) -> Duration {
+    let delay_ms = WS_RECONNECT_BASE_DELAY_MS
+        .saturating_mul(1u64 << attempt.min(16))
+        .min(WS_RECONNECT_MAX_DELAY_MS);
+    // Wait somewhere between half and all of the delay so that many clients
+    // dropped at once don't reconnect in lockstep.
+    let jitter = u64::from(SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().subsec_nanos()) % (delay_ms / 2 + 1);
+    Duration::from_millis(delay_ms / 2 + jitter)
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis() as u64
+}
+
+/// Read-only client for market and account data and websocket subscriptions.
+///
+/// Cloning is cheap: clones share the HTTP client, the websocket connection
+/// and its subscriptions, the runtime and the rate limiter. All methods take
+/// `&self` and may be called from several threads at once.
+#[derive(Clone)]
+pub struct HyperliquidInfo {
+    client: Arc<InfoClient>,
+    ws: Arc<WsShared>,
+    base_url: BaseUrl,
+    runtime: tokio::runtime::Handle,
+    timeout: Duration,
+    retry: RetryConfig,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    meta: Arc<MetaCache>,
+    cancellation: Option<Arc<CancellationToken>>,
+}
+
+impl HyperliquidInfo {
+    pub fn new(base_url: BaseUrl) -> Result<Self, HyperliquidError> {
+        Self::with_runtime(base_url, shared_runtime()?)
+    }
+    
+    /// Like `new`, but drives all requests on the caller's runtime instead of
+    /// the crate's shared one.
+    pub fn with_runtime(base_url: BaseUrl, runtime: tokio::runtime::Handle) -> Result<Self, HyperliquidError> {
+        let base_url = base_url.validated()?;
+        let timeout = Duration::from_millis(DEFAULT_TIMEOUT_MS);
+        let client = block_on_with_timeout(&runtime, timeout, connect_info(&base_url))?;
+        
+        Ok(HyperliquidInfo {
+            client: Arc::new(client),
+            ws: Arc::new(WsShared {
+                state: tokio::sync::Mutex::new(WsState::default()),
+                base_url: base_url.clone(),
+                reconnect: tokio::sync::Notify::new(),
+                status_callback: std::sync::Mutex::new(None),
+                closed: AtomicBool::new(false),
+                shutdown: tokio::sync::Notify::new(),
+            }),
+            base_url,
+            runtime,
+            timeout,
+            retry: RetryConfig::default(),
+            rate_limiter: None,
+            meta: Arc::new(MetaCache::new(Duration::from_millis(DEFAULT_META_TTL_MS))),
+            cancellation: None,
+        })
+    }
+    
+    /// Sets how long blocking calls wait before failing with a `NetworkError`.
+    pub fn with_timeout(mut self, timeout_ms: u64) -> Self {
+        self.timeout = Duration::from_millis(timeout_ms);
+        self
+    }
+    
+    /// Makes blocking calls fail with `Cancelled` once `token` is cancelled.
+    /// What a cancelled call already sent still happens: an order may have
+    /// reached the exchange, so look it up (placing it with a cloid helps)
+    /// before placing it again.
+    pub fn with_cancellation(mut self, token: Arc<CancellationToken>) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+    
+    /// A clone of this client whose blocking calls `token` can abort; see
+    /// `with_cancellation`. The `_async` methods are not affected.
+    pub fn cancellable(self: Arc<Self>, token: Arc<CancellationToken>) -> Arc<Self> {
+        Arc::new((*self).clone().with_cancellation(token))
+    }
+    
+    /// Sets how read-only requests are retried after transient failures.
+    pub fn with_retry(mut self, retry: RetryConfig) -> Self {
+        self.retry = retry;
+        self
+    }
+    
+    /// Throttles every HTTP request this client makes, retries included.
+    pub fn with_rate_limit(mut self, rate_limit: RateLimitConfig) -> Result<Self, HyperliquidError> {
+        self.rate_limiter = Some(Arc::new(RateLimiter::new(rate_limit)?));
+        Ok(self)
+    }
+    
+    /// Sets how long asset metadata is reused before being fetched again
+    /// (one minute by default).
+    pub fn with_meta_ttl(mut self, ttl_ms: u64) -> Self {
+        self.meta = Arc::new(MetaCache::new(Duration::from_millis(ttl_ms)));
+        self
+    }
+    
+    /// Sends this client's requests over `pool`'s connections. Must be called
+    /// before the client is cloned.
+    pub fn with_connection_pool(mut self, pool: Arc<ConnectionPool>) -> Result<Self, HyperliquidError> {
+        pool.join(&mut self.client)?;
+        Ok(self)
+    }
+    
+    fn block_on<T>(&self, future: impl Future<Output = Result<T, HyperliquidError>>) -> Result<T, HyperliquidError> {
+        block_on_with_timeout(&self.runtime, self.timeout, cancellable(self.cancellation.as_deref(), future))
+    }
+    
+    /// Issues a read-only request, retrying it per the client's `RetryConfig`.
+    async fn request<T, E, F, Fut>(&self, mut request: F) -> Result<T, HyperliquidError>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+        E: Into<HyperliquidError>,
+    {
+        with_retry(&self.retry, || {
+            let attempt = request();
+            async move {
+                self.throttle().await;
+                attempt.await
+            }
+        }).await
+    }
+    
+    async fn throttle(&self) {
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.acquire().await;
+        }
+    }
+    
+    /// Opens `subscriptions` and feeds every message they produce to `handler`
+    /// on the runtime. The returned id covers all of them and stays valid across
+    /// reconnects; the receive loop ends once it is unsubscribed.
+    fn subscribe<F>(&self, subscriptions: Vec<Subscription>, mut handler: F) -> Result<u32, HyperliquidError>
+    where
+        F: FnMut(Message) + Send + 'static,
+    {
+        if subscriptions.is_empty() {
+            return Err(HyperliquidError::InvalidInput { message: "Nothing to subscribe to".to_string() });
+        }
+        let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+        
+        let subscription_id = self.block_on(async {
+            let mut state = self.ws.state.lock().await;
+            if self.ws.closed.load(Ordering::Relaxed) {
+                return Err(HyperliquidError::InvalidInput { message: "The client has been shut down".to_string() });
+            }
+            if state.client.is_none() {
+                state.client = Some(connect_info(&self.base_url).await?);
+                self.runtime.spawn(supervise_ws(Arc::downgrade(&self.ws)));
+            }
+            let client = state.client.as_mut().expect("websocket client was just created");
+            
+            let mut sdk_ids = Vec::with_capacity(subscriptions.len());
+            for subscription in &subscriptions {
+                sdk_ids.push(client.subscribe(subscription.clone(), sender.clone()).await?);
+            }
+            
+            let subscription_id = state.next_id;
+            state.next_id += 1;
+            state.subscriptions.insert(subscription_id, ActiveSubscription { subscriptions, sender, sdk_ids });
+            Ok(subscription_id)
+        })?;
+        
+        let ws = Arc::downgrade(&self.ws);
+        self.runtime.spawn(async move {
+            while let Some(message) = receiver.recv().await {
+                let Some(ws) = ws.upgrade() else { return };
+                match message {
+                    // The SDK's way of saying the connection went away.
+                    Message::NoData | Message::HyperliquidError(_) => ws.reconnect.notify_one(),
+                    message => handler(message),
+                }
+            }
+        });
+        
+        Ok(subscription_id)
+    }
+    
+    /// Registers a callback told when the websocket drops and when it has been
+    /// re-established, e.g. to show a "reconnecting" indicator.
+    pub fn set_connection_status_callback(&self, callback: Box<dyn ConnectionStatusCallback>) {
+        *self.ws.status_callback.lock().unwrap_or_else(|e| e.into_inner()) = Some(callback);
+    }
+    
+    /// Streams mid prices for every asset to `callback` until unsubscribed.
+    pub fn subscribe_all_mids(&self, callback: Box<dyn MidsCallback>) -> Result<u32, HyperliquidError> {
+        self.subscribe(vec![Subscription::AllMids], move |message| {
+            if let Message::AllMids(all_mids) = message {
+                let mids = all_mids.data.mids
+                    .into_iter()
+                    .filter_map(|(asset, mid)| mid.parse().ok().map(|mid| (asset, mid)))
+                    .collect();
+                callback.on_mids(mids);
+            }
+        })
+    }
+    
+    /// Streams the order book for `asset` to `callback` until unsubscribed. The
+    /// first update is the current snapshot; each later one replaces the whole
+    /// book rather than patching it.
+    pub fn subscribe_l2_book(&self, asset: String, callback: Box<dyn L2BookCallback>) -> Result<u32, HyperliquidError> {
+        self.subscribe(vec![Subscription::L2Book { coin: asset }], move |message| {
+            if let Message::L2Book(book) = message {
+                let levels = book.data.levels.into_iter().map(|levels| {
+                    levels.into_iter().map(|level| Ok(BookLevel {
+                        price: parse_number("px", &level.px)?,
+                        size: parse_number("sz", &level.sz)?,
+                        num_orders: level.n as u32,
+                    })).collect::<Result<Vec<_>, HyperliquidError>>()
+                }).collect::<Result<Vec<_>, _>>();
+                match levels {
+                    Ok(levels) => callback.on_book(L2Book::from_levels(book.data.coin, book.data.time, levels)),
+                    Err(e) => log::warn!("skipping l2Book update for {}: {}", book.data.coin, e),
+                }
+            }
+        })
+    }
+    
+    /// Streams fills and order status changes for `address` to `callback` until
+    /// unsubscribed. Dropped connections are re-established and resubscribed.
+    pub fn subscribe_user_events(&self, address: String, callback: Box<dyn UserEventCallback>) -> Result<u32, HyperliquidError> {
+        let user = address.parse::<Address>()
+            .map_err(|e| HyperliquidError::InvalidInput { message: e.to_string() })?;
+        
+        let subscriptions = vec![Subscription::UserEvents { user }, Subscription::OrderUpdates { user }];
+        self.subscribe(subscriptions, move |message| match message {
+            Message::User(user_events) => {
+                if let UserData::Fills(fills) = user_events.data {
+                    for fill in fills {
+                        let (size, price) = match (parse_number("sz", &fill.sz), parse_number("px", &fill.px)) {
+                            (Ok(size), Ok(price)) => (size, price),
+                            (Err(e), _) | (_, Err(e)) => {
+                                log::warn!("skipping fill of order {}: {}", fill.oid, e);
+                                continue;
+                            }
+                        };
+                        callback.on_event(UserEvent {
+                            kind: UserEventKind::Fill,
+                            asset: fill.coin,
+                            side: OrderSide::from_wire(&fill.side),
+                            size,
+                            price,
+                            oid: fill.oid,
+                            timestamp: fill.time,
+                            status: None,
+                        });
+                    }
+                }
+            }
+            Message::OrderUpdates(updates) => {
+                for update in updates.data {
+                    let (size, price) = match (parse_number("sz", &update.order.sz), parse_number("limitPx", &update.order.limit_px)) {
+                        (Ok(size), Ok(price)) => (size, price),
+                        (Err(e), _) | (_, Err(e)) => {
+                            log::warn!("skipping update of order {}: {}", update.order.oid, e);
+                            continue;
+                        }
+                    };
+                    callback.on_event(UserEvent {
+                        kind: UserEventKind::OrderUpdate,
+                        asset: update.order.coin,
+                        side: OrderSide::from_wire(&update.order.side),
+                        size,
+                        price,
+                        oid: update.order.oid,
+                        timestamp: update.status_timestamp,
+                        status: Some(update.status),
+                    });
+                }
+            }
+            _ => {}
+        })
+    }
+    
+    /// Removes a subscription created by one of the `subscribe_*` methods.
+    pub fn unsubscribe(&self, subscription_id: u32) -> Result<(), HyperliquidError> {
+        self.block_on(async {
+            let mut state = self.ws.state.lock().await;
+            let active = state.subscriptions.remove(&subscription_id).ok_or_else(|| HyperliquidError::InvalidInput {
+                message: format!("Unknown subscription {}", subscription_id),
+            })?;
+            if let Some(client) = state.client.as_mut() {
+                for sdk_id in active.sdk_ids {
+                    client.unsubscribe(sdk_id).await?;
+                }
+            }
+            Ok(())
+        })
+    }
+    
+    /// Stops all background work: every subscription is removed, the
+    /// websocket is closed and the reconnect supervisor exits, after which
+    /// subscribing fails. HTTP requests keep working. Affects every clone of
+    /// this client. The runtime itself is shared by the process (or owned by
+    /// whoever passed it to `with_runtime`) and stays up.
+    pub fn shutdown(&self) -> Result<(), HyperliquidError> {
+        self.ws.closed.store(true, Ordering::Relaxed);
+        self.ws.shutdown.notify_one();
+        self.block_on(async {
+            let mut state = self.ws.state.lock().await;
+            let subscriptions = std::mem::take(&mut state.subscriptions);
+            if let Some(mut client) = state.client.take() {
+                for sdk_id in subscriptions.into_values().flat_map(|active| active.sdk_ids) {
+                    // The connection is dropped next anyway; this only
+                    // tells the server sooner.
+                    let _ = client.unsubscribe(sdk_id).await;
+                }
+            }
+            Ok(())
+        })
+    }
+    
+    /// The network this client reads from.
+    pub fn get_base_url(&self) -> BaseUrl {
+        self.base_url.clone()
+    }
+    
+    /// Drops cached asset metadata and fetches it again, e.g. to pick up a
+    /// market listed within the cache's TTL.
+    pub fn refresh_meta(&self) -> Result<(), HyperliquidError> {
+        self.block_on(self.refresh_meta_async())
+    }
+    
+    pub async fn refresh_meta_async(&self) -> Result<(), HyperliquidError> {
+        self.meta.clear();
+        tokio::try_join!(
+            self.request(|| self.meta.perp(&self.client)),
+            self.request(|| self.meta.spot(&self.client)),
+        )?;
+        Ok(())
+    }
+    
+    /// Whether `address` has ever been used on this client's network. `false`
+    /// usually means the address belongs to the other network, and is also
+    /// logged as a warning.
+    pub fn verify_account_exists(&self, address: String) -> Result<bool, HyperliquidError> {
+        self.block_on(self.verify_account_exists_async(address))
+    }
+    
+    pub async fn verify_account_exists_async(&self, address: String) -> Result<bool, HyperliquidError> {
+        let addr = address.parse::<Address>()
+            .map_err(|e| HyperliquidError::InvalidInput { message: e.to_string() })?;
+        
+        self.request(|| account_exists(&self.client, addr, &self.base_url)).await
+    }
+    
+    #[deprecated(note = "use get_clearinghouse_state")]
+    pub fn get_user_state(&self, address: String) -> Result<UserState, HyperliquidError> {
+        self.get_perp_state(address)
+    }
+    
+    #[deprecated(note = "use get_clearinghouse_state_async")]
+    pub async fn get_user_state_async(&self, address: String) -> Result<UserState, HyperliquidError> {
+        self.get_perp_state_async(address).await
+    }
+    
+    /// `get_perp_state` under the name of the request it makes.
+    pub fn get_clearinghouse_state(&self, address: String) -> Result<UserState, HyperliquidError> {
+        self.get_perp_state(address)
+    }
+    
+    pub async fn get_clearinghouse_state_async(&self, address: String) -> Result<UserState, HyperliquidError> {
+        self.get_perp_state_async(address).await
+    }
+    
+    /// Margin, positions and withdrawable USDC of the perp account of
+    /// `address` (the `clearinghouseState`). Spot holdings are separate; see
+    /// `get_spot_state`.
+    pub fn get_perp_state(&self, address: String) -> Result<UserState, HyperliquidError> {
+        self.block_on(self.get_perp_state_async(address))
+    }
+    
+    pub async fn get_perp_state_async(&self, address: String) -> Result<UserState, HyperliquidError> {
+        let addr = address.parse::<Address>()
+            .map_err(|e| HyperliquidError::InvalidInput { message: e.to_string() })?;
+        
+        let state: RawClearinghouseState = self.request(|| post_info(&self.client, serde_json::json!({ "type": "clearinghouseState", "user": addr }))).await?;
+        
+        UserState::from_clearinghouse_state(address, state)
+    }
+    
+    /// Token balances of the spot account of `address` (the
+    /// `spotClearinghouseState`), with the USD cost basis of each.
+    pub fn get_spot_state(&self, address: String) -> Result<SpotState, HyperliquidError> {
+        self.block_on(self.get_spot_state_async(address))
+    }
+    
+    pub async fn get_spot_state_async(&self, address: String) -> Result<SpotState, HyperliquidError> {
+        let addr = address.parse::<Address>()
+            .map_err(|e| HyperliquidError::InvalidInput { message: e.to_string() })?;
+        
+        let state: RawSpotClearinghouseState = self.request(|| post_info(&self.client, serde_json::json!({ "type": "spotClearinghouseState", "user": addr }))).await?;
+        let balances = state.balances.into_iter().map(SpotBalance::try_from).collect::<Result<_, _>>()?;
+        Ok(SpotState { address, balances })
+    }
+    
+    /// Resting orders of `address`, only those on `asset` if given. The API
+    /// has no per-asset query, so the filtering happens here.
+    pub fn get_open_orders(&self, address: String, asset: Option<String>) -> Result<Vec<OpenOrder>, HyperliquidError> {
+        self.block_on(self.get_open_orders_async(address, asset))
+    }
+    
+    pub async fn get_open_orders_async(&self, address: String, asset: Option<String>) -> Result<Vec<OpenOrder>, HyperliquidError> {
+        let addr = address.parse::<Address>()
+            .map_err(|e| HyperliquidError::InvalidInput { message: e.to_string() })?;
+        
+        let orders: Vec<RawOpenOrder> = self.request(|| post_info(&self.client, serde_json::json!({ "type": "openOrders", "user": addr }))).await?;
+        orders
+            .into_iter()
+            .filter(|order| asset.as_deref().is_none_or(|asset| order.coin == asset))
+            .map(OpenOrder::try_from)
+            .collect()
+    }
+    
+    /// Resting orders of `address` with their order type, trigger and TP/SL
+    /// details, as the web frontend lists them.
+    pub fn get_frontend_open_orders(&self, address: String) -> Result<Vec<FrontendOrder>, HyperliquidError> {
+        self.block_on(self.get_frontend_open_orders_async(address))
+    }
+    
+    pub async fn get_frontend_open_orders_async(&self, address: String) -> Result<Vec<FrontendOrder>, HyperliquidError> {
+        let addr = address.parse::<Address>()
+            .map_err(|e| HyperliquidError::InvalidInput { message: e.to_string() })?;
+        
+        let orders: Vec<RawFrontendOrder> = self.request(|| post_info(&self.client, serde_json::json!({ "type": "frontendOpenOrders", "user": addr }))).await?;
+        orders.into_iter().map(FrontendOrder::try_from).collect()
+    }
+    
+    /// Spot token balances of `address`, including USDC held in the spot wallet.
+    pub fn get_user_balances(&self, address: String) -> Result<Vec<UserBalance>, HyperliquidError> {
+        self.block_on(self.get_user_balances_async(address))
+    }
+    
+    pub async fn get_user_balances_async(&self, address: String) -> Result<Vec<UserBalance>, HyperliquidError> {
+        let addr = address.parse::<Address>()
+            .map_err(|e| HyperliquidError::InvalidInput { message: e.to_string() })?;
+        
+        let balances = self.request(|| self.client.user_token_balances(addr)).await?;
+        let mut result = Vec::new();
+        
+        for balance in balances.balances {
+            result.push(UserBalance {
+                token: balance.coin,
+                hold: parse_number("hold", &balance.hold)?,
+                total: parse_number("total", &balance.total)?,
+            });
+        }
+        
+        Ok(result)
+    }
+    
+    /// Broadcasts an order signed by `HyperliquidExchange::sign_order`, so the
+    /// process holding the key never needs to reach the network. Sent once
+    /// and not retried: a payload can only be accepted once, so after a
+    /// `NetworkError` check whether the order landed before signing it again.
+    pub fn submit_signed(&self, payload: String) -> Result<OrderResponse, HyperliquidError> {
+        self.block_on(self.submit_signed_async(payload))
+    }
+    
+    pub async fn submit_signed_async(&self, payload: String) -> Result<OrderResponse, HyperliquidError> {
+        let request: serde_json::Value = serde_json::from_str(&payload)
+            .map_err(|e| HyperliquidError::InvalidInput { message: format!("Payload is not JSON: {}", e) })?;
+        let orders = request["action"]["orders"].as_array()
+            .filter(|orders| request["action"]["type"] == "order" && orders.len() == 1)
+            .ok_or_else(|| HyperliquidError::InvalidInput { message: "Payload is not a signed order from sign_order".to_string() })?;
+        let cloid = orders[0]["c"].as_str().map(str::to_string);
+        
+        self.throttle().await;
+        log::debug!("POST /exchange {}", request);
+        let body = self.client.http_client.post("/exchange", payload).await?;
+        let response = exchange_response(&body)?;
+        let status = order_statuses(&response)?
+            .into_iter()
+            .next()
+            .ok_or_else(|| HyperliquidError::ApiError { message: "Exchange returned no status".to_string() })?;
+        let mut result = OrderResponse::from(status);
+        result.cloid = cloid;
+        Ok(result)
+    }
+    
+    /// Unstable, advanced: posts `request_json`, any `/info` request body such
+    /// as `{"type": "vaultDetails", "vaultAddress": "0x..."}`, and returns the
+    /// response body unparsed. An escape hatch for endpoints and fields the
+    /// typed methods don't cover; the exchange may change these responses
+    /// without notice. Retried like other reads.
+    pub fn info_raw(&self, request_json: String) -> Result<String, HyperliquidError> {
+        self.block_on(self.info_raw_async(request_json))
+    }
+    
+    pub async fn info_raw_async(&self, request_json: String) -> Result<String, HyperliquidError> {
+        let request: serde_json::Value = serde_json::from_str(&request_json)
+            .map_err(|e| HyperliquidError::InvalidInput { message: format!("Request is not JSON: {}", e) })?;
+        if !request["type"].is_string() {
+            return Err(HyperliquidError::InvalidInput {
+                message: "Info requests are JSON objects with a string \"type\"".to_string(),
+            });
+        }
+        self.request(|| post_info_raw(&self.client, &request)).await
+    }
+    
+    /// Round-trip time in milliseconds of a single `allMids` request, for
+    /// checking the endpoint is reachable. Not retried, so a failure means the
+    /// endpoint is unreachable or erroring right now.
+    pub fn ping(&self) -> Result<u64, HyperliquidError> {
+        self.block_on(self.ping_async())
+    }
+    
+    pub async fn ping_async(&self) -> Result<u64, HyperliquidError> {
+        self.throttle().await;
+        let started = std::time::Instant::now();
+        let _: serde_json::Value = post_info(&self.client, serde_json::json!({ "type": "allMids" })).await?;
+        Ok(started.elapsed().as_millis() as u64)
+    }
+    
+    /// Mid prices as the exchange sends them, keeping their exact decimal form.
+    pub fn get_all_mids(&self) -> Result<HashMap<String, String>, HyperliquidError> {
+        self.block_on(self.get_all_mids_async())
+    }
+    
+    pub async fn get_all_mids_async(&self) -> Result<HashMap<String, String>, HyperliquidError> {
+        let mids = self.request(|| self.client.all_mids()).await?;
+        Ok(mids)
+    }
+    
+    pub fn get_all_mids_f64(&self) -> Result<HashMap<String, f64>, HyperliquidError> {
+        self.block_on(self.get_all_mids_f64_async())
+    }
+    
+    pub async fn get_all_mids_f64_async(&self) -> Result<HashMap<String, f64>, HyperliquidError> {
+        self.get_all_mids_async()
+            .await?
+            .into_iter()
+            .map(|(asset, mid)| {
+                let mid = parse_number(&format!("{} mid", asset), &mid)?;
+                Ok((asset, mid))
+            })
+            .collect()
+    }
+    
+    /// Mid price of a single asset. Spot pairs can be given by name
+    /// (`PURR/USDC`) or index (`@1`).
+    pub fn get_mid(&self, asset: String) -> Result<f64, HyperliquidError> {
+        self.block_on(self.get_mid_async(asset))
+    }
+    
+    pub async fn get_mid_async(&self, asset: String) -> Result<f64, HyperliquidError> {
+        // The info API only serves all mids at once.
+        let mids = self.request(|| self.client.all_mids()).await?;
+        if !mids.contains_key(&asset) && is_spot_asset(&asset) {
+            let precision = self.request(|| fetch_asset_precision(&self.client, &self.meta, &asset)).await?;
+            return find_mid(&mids, &asset, precision.mid_key.as_deref());
+        }
+        find_mid(&mids, &asset, None)
+    }
+    
+    /// Order book snapshot of `asset`, keeping at most `n_levels` per side.
+    /// `mantissa` (1, 2 or 5) aggregates prices at five significant figures
+    /// into buckets of that step, as the web UI's grouping does.
+    pub fn get_l2_book(&self, asset: String, n_levels: Option<u32>, mantissa: Option<u32>) -> Result<L2Book, HyperliquidError> {
+        self.block_on(self.get_l2_book_async(asset, n_levels, mantissa))
+    }
+    
+    pub async fn get_l2_book_async(&self, asset: String, n_levels: Option<u32>, mantissa: Option<u32>) -> Result<L2Book, HyperliquidError> {
+        let mut request = serde_json::json!({ "type": "l2Book", "coin": asset });
+        if let Some(mantissa) = mantissa {
+            if !L2_BOOK_MANTISSAS.contains(&mantissa) {
+                return Err(HyperliquidError::InvalidInput {
+                    message: format!("Mantissa must be one of {:?}, got {}", L2_BOOK_MANTISSAS, mantissa),
+                });
+            }
+            // The exchange only accepts a mantissa at five significant figures.
+            request["nSigFigs"] = 5.into();
+            request["mantissa"] = mantissa.into();
+        }
+        let book: RawL2Book = self.request(|| post_info(&self.client, request.clone())).await?;
+        
+        let depth = n_levels.map_or(usize::MAX, |n| n as usize);
+        let levels = book.levels.into_iter().map(|levels| {
+            levels.into_iter().take(depth).map(BookLevel::try_from).collect::<Result<Vec<_>, _>>()
+        }).collect::<Result<_, _>>()?;
+        
+        Ok(L2Book::from_levels(book.coin, book.time, levels))
+    }
+    
+    /// Candles for `asset` between `start_time` and `end_time` (milliseconds).
+    /// `interval` is one of `1m`, `3m`, `5m`, `15m`, `30m`, `1h`, `2h`, `4h`,
+    /// `8h`, `12h`, `1d`, `3d`, `1w` or `1M`.
+    pub fn get_candles(&self, asset: String, interval: String, start_time: u64, end_time: u64) -> Result<Vec<Candle>, HyperliquidError> {
+        self.block_on(self.get_candles_async(asset, interval, start_time, end_time))
+    }
+    
+    pub async fn get_candles_async(&self, asset: String, interval: String, start_time: u64, end_time: u64) -> Result<Vec<Candle>, HyperliquidError> {
+        if !CANDLE_INTERVALS.contains(&interval.as_str()) {
+            return Err(HyperliquidError::InvalidInput {
+                message: format!("Unsupported candle interval {:?}, expected one of {}", interval, CANDLE_INTERVALS.join(", ")),
+            });
+        }
+        
+        let candles = self.request(|| self.client.candles_snapshot(asset.clone(), interval.clone(), start_time, end_time)).await?;
+        let mut result = Vec::new();
+        
+        for candle in candles {
+            result.push(Candle {
+                time_open: candle.time_open,
+                time_close: candle.time_close,
+                open: parse_number("o", &candle.open)?,
+                high: parse_number("h", &candle.high)?,
+                low: parse_number("l", &candle.low)?,
+                close: parse_number("c", &candle.close)?,
+                volume: parse_number("v", &candle.vlm)?,
+            });
+        }
+        
+        Ok(result)
+    }
+    
+    /// The most recent `count` candles of `asset`, oldest first, ending with
+    /// the one still forming. `count` is capped at 5000, the most the
+    /// exchange returns per request.
+    pub fn get_latest_candles(&self, asset: String, interval: String, count: u32) -> Result<Vec<Candle>, HyperliquidError> {
+        self.block_on(self.get_latest_candles_async(asset, interval, count))
+    }
+    
+    pub async fn get_latest_candles_async(&self, asset: String, interval: String, count: u32) -> Result<Vec<Candle>, HyperliquidError> {
+        let interval_ms = candle_interval_ms(&interval).ok_or_else(|| HyperliquidError::InvalidInput {
+            message: format!("Unsupported candle interval {:?}, expected one of {}", interval, CANDLE_INTERVALS.join(", ")),
+        })?;
+        let count = count.min(MAX_CANDLES) as usize;
+        let end_time = now_ms();
+        let start_time = end_time.saturating_sub(count as u64 * interval_ms);
+        
+        let mut candles = self.get_candles_async(asset, interval, start_time, end_time).await?;
+        // The range can straddle one more candle boundary than `count`.
+        candles.drain(..candles.len().saturating_sub(count));
+        Ok(candles)
+    }
+    
+    pub fn get_user_fills(&self, address: String) -> Result<Vec<Fill>, HyperliquidError> {
+        self.block_on(self.get_user_fills_async(address))
+    }
+    
+    pub async fn get_user_fills_async(&self, address: String) -> Result<Vec<Fill>, HyperliquidError> {
+        let addr = address.parse::<Address>()
+            .map_err(|e| HyperliquidError::InvalidInput { message: e.to_string() })?;
+        
+        let fills = self.request(|| self.client.user_fills(addr)).await?;
+        fills.into_iter().map(Fill::try_from).collect()
+    }
+    
+    /// Fills of `address` from `start_time` up to `end_time` (now if omitted),
+    /// oldest first. The exchange returns at most `MAX_FILLS_PER_PAGE` fills per
+    /// call and only keeps the most recent 10000, so page through longer ranges
+    /// by calling again with `start_time` just past the last fill's timestamp;
+    /// fills sharing that timestamp can be told apart by `hash`.
+    pub fn get_user_fills_by_time(&self, address: String, start_time: u64, end_time: Option<u64>) -> Result<Vec<Fill>, HyperliquidError> {
+        self.block_on(self.get_user_fills_by_time_async(address, start_time, end_time))
+    }
+    
+    pub async fn get_user_fills_by_time_async(&self, address: String, start_time: u64, end_time: Option<u64>) -> Result<Vec<Fill>, HyperliquidError> {
+        let addr = address.parse::<Address>()
+            .map_err(|e| HyperliquidError::InvalidInput { message: e.to_string() })?;
+        
+        let fills: Vec<UserFillsResponse> = self.request(|| post_info(&self.client, serde_json::json!({
+            "type": "userFillsByTime",
+            "user": addr,
+            "startTime": start_time,
+            "endTime": end_time,
+        }))).await?;
+        if fills.len() >= MAX_FILLS_PER_PAGE {
+            log::debug!("userFillsByTime returned a full page; more fills may follow");
+        }
+        fills.into_iter().map(Fill::try_from).collect()
+    }
+    
+    /// Hourly funding rates for `asset` from `start_time` (milliseconds) up to
+    /// `end_time`, or up to now when `end_time` is `None`.
+    pub fn get_funding_history(&self, asset: String, start_time: u64, end_time: Option<u64>) -> Result<Vec<FundingEntry>, HyperliquidError> {
+        self.block_on(self.get_funding_history_async(asset, start_time, end_time))
+    }
+    
+    pub async fn get_funding_history_async(&self, asset: String, start_time: u64, end_time: Option<u64>) -> Result<Vec<FundingEntry>, HyperliquidError> {
+        let history = self.request(|| self.client.funding_history(asset.clone(), start_time, end_time)).await?;
+        let mut result = Vec::new();
+        
+        for entry in history {
+            result.push(FundingEntry {
+                time: entry.time,
+                funding_rate: parse_number("fundingRate", &entry.funding_rate)?,
+                premium: parse_number("premium", &entry.premium)?,
+            });
+        }
+        
+        Ok(result)
+    }
+    
+    /// Metadata for every tradeable perpetual, in asset index order.
+    pub fn get_meta(&self) -> Result<Vec<AssetMeta>, HyperliquidError> {
+        self.block_on(self.get_meta_async())
+    }
+    
+    pub async fn get_meta_async(&self) -> Result<Vec<AssetMeta>, HyperliquidError> {
+        let meta = self.request(|| self.meta.perp(&self.client)).await?;
+        Ok(meta.universe.iter().cloned().map(AssetMeta::from).collect())
+    }
+    
+    /// What-if numbers for opening `size` of `asset` at `entry_price` as an
+    /// isolated position with `leverage`. Maintenance margin is half the
+    /// initial margin at the tier's maximum leverage; tier deductions are
+    /// ignored, so the liquidation price errs on the side of being too close.
+    pub fn simulate_position(&self, asset: String, side: OrderSide, size: f64, entry_price: f64, leverage: u32) -> Result<PositionSimulation, HyperliquidError> {
+        self.block_on(self.simulate_position_async(asset, side, size, entry_price, leverage))
+    }
+    
+    pub async fn simulate_position_async(&self, asset: String, side: OrderSide, size: f64, entry_price: f64, leverage: u32) -> Result<PositionSimulation, HyperliquidError> {
+        if !(size > 0.0 && entry_price > 0.0) {
+            return Err(HyperliquidError::InvalidInput {
+                message: format!("Size and entry price must be positive, got {} at {}", size, entry_price),
+            });
+        }
+        
+        let meta = self.request(|| self.meta.perp(&self.client)).await?;
+        let asset_meta = meta.universe.iter()
+            .find(|a| a.name == asset)
+            .ok_or_else(|| HyperliquidError::InvalidInput { message: format!("Unknown asset {}", asset) })?;
+        
+        let notional = size * entry_price;
+        let max_leverage = meta.max_leverage_at(asset_meta, notional);
+        if leverage == 0 || f64::from(leverage) > max_leverage {
+            return Err(HyperliquidError::InvalidInput {
+                message: format!("Leverage {} out of range for {} USD of {} (1-{})", leverage, notional, asset, max_leverage),
+            });
+        }
+        
+        let margin_required = notional / f64::from(leverage);
+        let maintenance_rate = 1.0 / (2.0 * max_leverage);
+        let maintenance_margin = notional * maintenance_rate;
+        // Liquidation happens once losses eat the margin above maintenance,
+        // with maintenance itself shrinking or growing with the mark price.
+        let direction = if side.is_buy() { 1.0 } else { -1.0 };
+        let liquidation_price = entry_price
+            - direction * (margin_required - maintenance_margin) / size / (1.0 - maintenance_rate * direction);
+        
+        Ok(PositionSimulation {
+            notional,
+            margin_required,
+            maintenance_margin,
+            liquidation_price: liquidation_price.max(0.0),
+            fee: notional * BASE_TAKER_FEE_RATE,
+        })
+    }
+    
+    /// Snaps `price` to the precision the exchange accepts for `asset`: five
+    /// significant figures and at most `6 - sz_decimals` decimal places (8 for
+    /// spot pairs).
+    pub fn round_price(&self, asset: String, price: f64) -> Result<f64, HyperliquidError> {
+        self.block_on(self.round_price_async(asset, price))
+    }
+    
+    pub async fn round_price_async(&self, asset: String, price: f64) -> Result<f64, HyperliquidError> {
+        let precision = self.request(|| fetch_asset_precision(&self.client, &self.meta, &asset)).await?;
+        Ok(precision.round_price(price))
+    }
+    
+    /// Snaps `size` to the asset's `sz_decimals`.
+    pub fn round_size(&self, asset: String, size: f64) -> Result<f64, HyperliquidError> {
+        self.block_on(self.round_size_async(asset, size))
+    }
+    
+    pub async fn round_size_async(&self, asset: String, size: f64) -> Result<f64, HyperliquidError> {
+        let precision = self.request(|| fetch_asset_precision(&self.client, &self.meta, &asset)).await?;
+        Ok(precision.round_size(size))
+    }
+    
+    /// Names of the tradeable perps (`ETH`), followed by the spot pairs
+    /// (`PURR/USDC`, or `@<index>` for pairs without a canonical name) when
+    /// `include_spot` is set. These are the `asset` values orders accept.
+    pub fn get_asset_names(&self, include_spot: bool) -> Result<Vec<String>, HyperliquidError> {
+        self.block_on(self.get_asset_names_async(include_spot))
+    }
+    
+    pub async fn get_asset_names_async(&self, include_spot: bool) -> Result<Vec<String>, HyperliquidError> {
+        let perps = self.request(|| self.meta.perp(&self.client));
+        let spot = async {
+            if !include_spot {
+                return Ok(None);
+            }
+            self.request(|| self.meta.spot(&self.client)).await.map(Some)
+        };
+        let (meta, spot_meta) = tokio::try_join!(perps, spot)?;
+        
+        let mut names: Vec<String> = meta.universe.iter().filter(|a| !a.is_delisted).map(|a| a.name.clone()).collect();
+        if let Some(spot_meta) = spot_meta {
+            names.extend(spot_meta.universe.iter().map(|pair| pair.name.clone()));
+        }
+        Ok(names)
+    }
+    
+    /// Metadata for every spot pair. Orders for a pair use its `name`, or
+    /// `@<index>`, as the asset.
+    pub fn get_spot_meta(&self) -> Result<Vec<SpotAssetMeta>, HyperliquidError> {
+        self.block_on(self.get_spot_meta_async())
+    }
+    
+    pub async fn get_spot_meta_async(&self) -> Result<Vec<SpotAssetMeta>, HyperliquidError> {
+        let meta = self.request(|| self.meta.spot(&self.client)).await?;
+        let token = |index: Option<&u32>| index.and_then(|index| meta.tokens.iter().find(|t| t.index == *index));
+        
+        let mut result = Vec::new();
+        for pair in &meta.universe {
+            let (Some(base), Some(quote)) = (token(pair.tokens.first()), token(pair.tokens.get(1))) else {
+                continue;
+            };
+            result.push(SpotAssetMeta {
+                name: pair.name.clone(),
+                index: pair.index,
+                base_token: base.name.clone(),
+                quote_token: quote.name.clone(),
+                base_token_index: base.index,
+                quote_token_index: quote.index,
+                sz_decimals: base.sz_decimals,
+            });
+        }
+        
+        Ok(result)
+    }
+    
+    /// Spot token balances of `address`. Same as `get_user_balances`, named to
+    /// make clear these are spot holdings and not perp margin.
+    pub fn get_spot_balances(&self, address: String) -> Result<Vec<UserBalance>, HyperliquidError> {
+        self.get_user_balances(address)
+    }
+    
+    pub async fn get_spot_balances_async(&self, address: String) -> Result<Vec<UserBalance>, HyperliquidError> {
+        self.get_user_balances_async(address).await
+    }
+    
+    /// Looks up a single order of `address`, including ones that are no longer
+    /// open. Cheaper than listing all open orders when tracking one.
+    pub fn get_order_status(&self, address: String, oid: u64) -> Result<OrderStatus, HyperliquidError> {
+        self.block_on(self.get_order_status_async(address, oid))
+    }
+    
+    pub async fn get_order_status_async(&self, address: String, oid: u64) -> Result<OrderStatus, HyperliquidError> {
+        let addr = address.parse::<Address>()
+            .map_err(|e| HyperliquidError::InvalidInput { message: e.to_string() })?;
+        
+        let response: RawOrderStatusResponse = self.request(|| post_info(&self.client, serde_json::json!({ "type": "orderStatus", "user": addr, "oid": oid }))).await?;
+        OrderStatus::try_from(response)
+    }
+    
+    /// Recently resolved orders of `address` (filled, canceled, rejected, ...),
+    /// for reconciling state after being offline.
+    pub fn get_historical_orders(&self, address: String) -> Result<Vec<HistoricalOrder>, HyperliquidError> {
+        self.block_on(self.get_historical_orders_async(address))
+    }
+    
+    pub async fn get_historical_orders_async(&self, address: String) -> Result<Vec<HistoricalOrder>, HyperliquidError> {
+        let addr = address.parse::<Address>()
+            .map_err(|e| HyperliquidError::InvalidInput { message: e.to_string() })?;
+        
+        let orders: Vec<RawOrderStatus> = self.request(|| post_info(&self.client, serde_json::json!({ "type": "historicalOrders", "user": addr }))).await?;
+        orders.into_iter().map(HistoricalOrder::try_from).collect()
+    }
+    
+    /// Predicted funding for every perp, in one request.
+    pub fn get_predicted_fundings(&self) -> Result<Vec<PredictedFunding>, HyperliquidError> {
+        self.block_on(self.get_predicted_fundings_async())
+    }
+    
+    pub async fn get_predicted_fundings_async(&self) -> Result<Vec<PredictedFunding>, HyperliquidError> {
+        let entries: Vec<(String, Vec<(String, Option<RawPredictedFunding>)>)> =
+            self.request(|| post_info(&self.client, serde_json::json!({ "type": "predictedFundings" }))).await?;
+        
+        let mut fundings = Vec::with_capacity(entries.len());
+        for (asset, venues) in entries {
+            let own = venues.into_iter().find_map(|(venue, funding)| funding.filter(|_| venue == HYPERLIQUID_FUNDING_VENUE));
+            if let Some(funding) = own {
+                fundings.push(PredictedFunding {
+                    funding_rate: parse_number("fundingRate", &funding.funding_rate)?,
+                    next_funding_time: funding.next_funding_time,
+                    asset,
+                });
+            }
+        }
+        Ok(fundings)
+    }
+    
+    /// Mark, oracle and mid prices, open interest, funding and volume for every
+    /// perp, in one request.
+    pub fn get_asset_contexts(&self) -> Result<Vec<AssetContext>, HyperliquidError> {
+        self.block_on(self.get_asset_contexts_async())
+    }
+    
+    pub async fn get_asset_contexts_async(&self) -> Result<Vec<AssetContext>, HyperliquidError> {
+        let (meta, contexts): (RawMeta, Vec<RawAssetContext>) =
+            self.request(|| post_info(&self.client, serde_json::json!({ "type": "metaAndAssetCtxs" }))).await?;
+        
+        meta.universe
+            .into_iter()
+            .zip(contexts)
+            .map(|(asset, context)| context.into_context(asset.name))
+            .collect()
+    }
+    
+    /// Every perp's metadata together with its mark, oracle and mid prices,
+    /// open interest, funding and volume, in one request; what a full markets
+    /// screen needs.
+    pub fn get_meta_with_contexts(&self) -> Result<Vec<AssetMetaWithContext>, HyperliquidError> {
+        self.block_on(self.get_meta_with_contexts_async())
+    }
+    
+    pub async fn get_meta_with_contexts_async(&self) -> Result<Vec<AssetMetaWithContext>, HyperliquidError> {
+        let (meta, contexts): (RawMeta, Vec<RawAssetContext>) =
+            self.request(|| post_info(&self.client, serde_json::json!({ "type": "metaAndAssetCtxs" }))).await?;
+        
+        meta.universe
+            .into_iter()
+            .zip(contexts)
+            .map(|(asset, context)| {
+                let context = context.into_context(asset.name.clone())?;
+                Ok(AssetMetaWithContext { meta: AssetMeta::from(asset), context })
+            })
+            .collect()
+    }
+    
+    /// Funding payments on `address`'s positions between `start_time` and
+    /// `end_time` (now if omitted), in milliseconds.
+    pub fn get_user_funding(&self, address: String, start_time: u64, end_time: Option<u64>) -> Result<Vec<UserFunding>, HyperliquidError> {
+        self.block_on(self.get_user_funding_async(address, start_time, end_time))
+    }
+    
+    pub async fn get_user_funding_async(&self, address: String, start_time: u64, end_time: Option<u64>) -> Result<Vec<UserFunding>, HyperliquidError> {
+        let addr = address.parse::<Address>()
+            .map_err(|e| HyperliquidError::InvalidInput { message: e.to_string() })?;
+        
+        let fundings: Vec<RawUserFunding> = self.request(|| post_info(&self.client, serde_json::json!({
+            "type": "userFunding",
+            "user": addr,
+            "startTime": start_time,
+            "endTime": end_time,
+        }))).await?;
+        fundings.into_iter().map(UserFunding::try_from).collect()
+    }
+    
+    /// Deposits, withdrawals and transfers of `address` between `start_time`
+    /// and `end_time` (now if omitted), in milliseconds.
+    pub fn get_ledger_updates(&self, address: String, start_time: u64, end_time: Option<u64>) -> Result<Vec<LedgerUpdate>, HyperliquidError> {
+        self.block_on(self.get_ledger_updates_async(address, start_time, end_time))
+    }
+    
+    pub async fn get_ledger_updates_async(&self, address: String, start_time: u64, end_time: Option<u64>) -> Result<Vec<LedgerUpdate>, HyperliquidError> {
+        let addr = address.parse::<Address>()
+            .map_err(|e| HyperliquidError::InvalidInput { message: e.to_string() })?;
+        
+        let updates: Vec<RawLedgerUpdate> = self.request(|| post_info(&self.client, serde_json::json!({
+            "type": "userNonFundingLedgerUpdates",
+            "user": addr,
+            "startTime": start_time,
+            "endTime": end_time,
+        }))).await?;
+        updates.into_iter().map(LedgerUpdate::try_from).collect()
+    }
+    
+    /// Referral code, referees and rewards of `address`.
+    pub fn get_referral_state(&self, address: String) -> Result<ReferralState, HyperliquidError> {
+        self.block_on(self.get_referral_state_async(address))
+    }
+    
+    pub async fn get_referral_state_async(&self, address: String) -> Result<ReferralState, HyperliquidError> {
+        let addr = address.parse::<Address>()
+            .map_err(|e| HyperliquidError::InvalidInput { message: e.to_string() })?;
+        
+        let referral: RawReferral = self.request(|| post_info(&self.client, serde_json::json!({ "type": "referral", "user": addr }))).await?;
+        ReferralState::try_from(referral)
+    }
+    
+    /// Sub-accounts of `master_address`, each with its perp account state.
+    pub fn get_sub_accounts(&self, master_address: String) -> Result<Vec<SubAccount>, HyperliquidError> {
+        self.block_on(self.get_sub_accounts_async(master_address))
+    }
+    
+    pub async fn get_sub_accounts_async(&self, master_address: String) -> Result<Vec<SubAccount>, HyperliquidError> {
+        let master = master_address.parse::<Address>()
+            .map_err(|e| HyperliquidError::InvalidInput { message: e.to_string() })?;
+        
+        let sub_accounts = self.request(|| fetch_sub_accounts(&self.client, master)).await?;
+        sub_accounts.into_iter().map(SubAccount::try_from).collect()
+    }
+    
+    /// Highest fee, in tenths of a basis point, that `address` has approved
+    /// for `builder`; 0 if none.
+    pub fn get_max_builder_fee(&self, address: String, builder: String) -> Result<u32, HyperliquidError> {
+        self.block_on(self.get_max_builder_fee_async(address, builder))
+    }
+    
+    pub async fn get_max_builder_fee_async(&self, address: String, builder: String) -> Result<u32, HyperliquidError> {
+        let addr = address.parse::<Address>()
+            .map_err(|e| HyperliquidError::InvalidInput { message: e.to_string() })?;
+        let builder = builder.parse::<Address>()
+            .map_err(|e| HyperliquidError::InvalidInput { message: format!("Invalid builder address: {}", e) })?;
+        
+        self.request(|| post_info(&self.client, serde_json::json!({ "type": "maxBuilderFee", "user": addr, "builder": builder }))).await
+    }
+    
+    /// Address-based request usage of `address`, as counted by the exchange.
+    pub fn get_rate_limit(&self, address: String) -> Result<RateLimitStatus, HyperliquidError> {
+        self.block_on(self.get_rate_limit_async(address))
+    }
+    
+    pub async fn get_rate_limit_async(&self, address: String) -> Result<RateLimitStatus, HyperliquidError> {
+        let addr = address.parse::<Address>()
+            .map_err(|e| HyperliquidError::InvalidInput { message: e.to_string() })?;
+        
+        let limit: RawUserRateLimit = self.request(|| post_info(&self.client, serde_json::json!({ "type": "userRateLimit", "user": addr }))).await?;
+        RateLimitStatus::try_from(limit)
+    }
+    
+    /// State of the current perp deploy auction.
+    pub fn get_perp_deploy_auction(&self) -> Result<DeployAuctionStatus, HyperliquidError> {
+        self.block_on(self.get_perp_deploy_auction_async())
+    }
+    
+    pub async fn get_perp_deploy_auction_async(&self) -> Result<DeployAuctionStatus, HyperliquidError> {
+        let status: RawDeployAuctionStatus = self.request(|| post_info(&self.client, serde_json::json!({ "type": "perpDeployAuctionStatus" }))).await?;
+        DeployAuctionStatus::try_from(status)
+    }
+    
+    /// Milliseconds since the epoch on the exchange's clock.
+    pub fn get_server_time(&self) -> Result<u64, HyperliquidError> {
+        self.block_on(self.get_server_time_async())
+    }
+    
+    pub async fn get_server_time_async(&self) -> Result<u64, HyperliquidError> {
+        self.request(|| fetch_server_time(&self.client)).await
+    }
+    
+    /// User state, open orders and spot balances of `address`, requested
+    /// concurrently. Fails if any of them fails.
+    pub fn get_user_overview(&self, address: String) -> Result<UserOverview, HyperliquidError> {
+        self.block_on(self.get_user_overview_async(address))
+    }
+    
+    pub async fn get_user_overview_async(&self, address: String) -> Result<UserOverview, HyperliquidError> {
+        let (state, open_orders, balances) = tokio::try_join!(
+            self.get_perp_state_async(address.clone()),
+            self.get_open_orders_async(address.clone(), None),
+            self.get_user_balances_async(address),
+        )?;
+        Ok(UserOverview { state, open_orders, balances })
+    }
+    
+    /// Perp states of many addresses, requested concurrently but at most
+    /// `MAX_CONCURRENT_USER_STATES` at a time (and within the client's rate
+    /// limit). The result is index-aligned with `addresses`; an invalid
+    /// address or failed request only fails its own entry.
+    pub fn get_user_states(&self, addresses: Vec<String>) -> Result<Vec<UserStateResult>, HyperliquidError> {
+        self.block_on(self.get_user_states_async(addresses))
+    }
+    
+    pub async fn get_user_states_async(&self, addresses: Vec<String>) -> Result<Vec<UserStateResult>, HyperliquidError> {
+        let permits = Arc::new(tokio::sync::Semaphore::new(MAX_CONCURRENT_USER_STATES));
+        // Dropping the set aborts what is still running, so a timed out or
+        // cancelled call stops using permits and rate limit tokens.
+        let mut tasks = tokio::task::JoinSet::new();
+        for (index, address) in addresses.iter().enumerate() {
+            let client = self.clone();
+            let address = address.clone();
+            let permits = permits.clone();
+            tasks.spawn_on(async move {
+                let _permit = permits.acquire_owned().await;
+                (index, client.get_perp_state_async(address).await)
+            }, &self.runtime);
+        }
+        
+        let mut outcomes: Vec<Option<Result<UserState, String>>> = vec![None; addresses.len()];
+        while let Some(joined) = tasks.join_next().await {
+            match joined {
+                Ok((index, outcome)) => outcomes[index] = Some(outcome.map_err(|e| e.to_string())),
+                // Only panics land here; the task's address stays without an
+                // outcome and is reported below.
+                Err(e) => log::warn!("user state task failed: {}", e),
+            }
+        }
+        Ok(addresses
+            .into_iter()
+            .zip(outcomes)
+            .map(|(address, outcome)| {
+                let (state, error) = match outcome {
+                    Some(Ok(state)) => (Some(state), None),
+                    Some(Err(e)) => (None, Some(e)),
+                    None => (None, Some("Request task failed".to_string())),
+                };
+                UserStateResult { address, state, error }
+            })
+            .collect())
+    }
+}
+
+/// Parses a numeric string field of an API response, naming the field if it
+/// is malformed rather than quietly treating it as zero.
+fn parse_number(field: &str, value: &str) -> Result<f64, HyperliquidError> {
+    value.parse().map_err(|e| HyperliquidError::ApiError {
+        message: format!("Failed to parse {} {:?}: {}", field, value, e),
+    })
+}
+
+/// The wallet's address EIP-55 checksummed, as wallets display it.
+fn checksummed_address(wallet: &PrivateKeySigner) -> String {
+    wallet.address().to_checksum(None)
+}
+
+/// Accepts a private key as 64 hex characters, with or without `0x` and
+/// surrounding whitespace, as wallets export it in different forms. Errors
+/// never echo the input.
+fn parse_private_key(private_key: &str) -> Result<PrivateKeySigner, HyperliquidError> {
+    let trimmed = private_key.trim();
+    let hex = trimmed.strip_prefix("0x").or_else(|| trimmed.strip_prefix("0X")).unwrap_or(trimmed);
+    if hex.len() != 64 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(HyperliquidError::InvalidPrivateKey {
+            message: format!("Expected 64 hex characters, optionally prefixed with 0x, got {} characters", hex.len()),
+        });
+    }
+    hex.parse::<PrivateKeySigner>()
+        .map_err(|e| HyperliquidError::InvalidPrivateKey { message: e.to_string() })
+}
+
+/// Validates a transfer destination. Money-moving actions report a bad address
+/// explicitly instead of letting the exchange reject it.
+fn parse_destination(destination: &str) -> Result<Address, HyperliquidError> {
+    destination.parse::<Address>().map_err(|e| HyperliquidError::InvalidInput {
+        message: format!("Invalid destination address {:?}: {}", destination, e),
+    })
+}
+
+fn check_positive_amount(amount: f64) -> Result<(), HyperliquidError> {
+    if amount.is_nan() || amount <= 0.0 {
+        return Err(HyperliquidError::InvalidInput {
+            message: format!("Amount must be positive, got {}", amount),
+        });
+    }
+    Ok(())
+}
+
+/// Cancel requests for the open orders on `asset`, or for all of them when
+/// no asset is given.
+fn open_order_cancels(orders: Vec<RawOpenOrder>, asset: Option<&str>) -> Vec<CancelRequest> {
+    orders
+        .into_iter()
+        .filter(|order| asset.is_none_or(|asset| order.coin == asset))
+        .map(|order| CancelRequest { asset: order.coin, oid: order.oid })
+        .collect()
+}
+
+/// A field of a user-signed action, in the order of its EIP-712 type.
+enum Eip712Field<'a> {
+    String(&'a str),
+    Address(Address),
+    U64(u64),
+}
+
+/// Signs a user action (transfers, agent and builder approvals) under the
+/// `HyperliquidSignTransaction` domain. `type_signature` is the action's
+/// EIP-712 type, e.g. `HyperliquidTransaction:ApproveAgent(...)`, and
+/// `fields` its values in the same order.
+fn sign_user_action(wallet: &PrivateKeySigner, type_signature: &str, fields: &[Eip712Field]) -> Result<serde_json::Value, HyperliquidError> {
+    let domain = alloy::sol_types::eip712_domain! {
+        name: "HyperliquidSignTransaction",
+        version: "1",
+        chain_id: USER_ACTION_CHAIN_ID,
+        verifying_contract: Address::ZERO,
+    };
+    
+    let mut encoded = Vec::with_capacity((fields.len() + 1) * 32);
+    encoded.extend_from_slice(keccak256(type_signature).as_slice());
+    for field in fields {
+        let word = match field {
+            Eip712Field::String(value) => keccak256(value),
+            Eip712Field::Address(address) => B256::left_padding_from(address.as_slice()),
+            Eip712Field::U64(value) => B256::from(U256::from(*value)),
+        };
+        encoded.extend_from_slice(word.as_slice());
+    }
+    
+    sign_typed_data(wallet, &domain, keccak256(&encoded))
+}
+
+/// Signs an L1 action (orders, cancels, TWAPs, ...) the way the exchange
+/// verifies it: the msgpack encoding of the action, the nonce and the vault
+/// are hashed into a connection id, which is signed as a phantom `Agent`.
+fn sign_l1_action<A: Serialize>(wallet: &PrivateKeySigner, action: &A, nonce: u64, vault: Option<Address>, is_mainnet: bool) -> Result<serde_json::Value, HyperliquidError> {
+    let mut bytes = rmp_serde::to_vec_named(action)
+        .map_err(|e| HyperliquidError::ApiError { message: format!("Failed to encode action: {}", e) })?;
+    bytes.extend_from_slice(&nonce.to_be_bytes());
+    match vault {
+        Some(vault) => {
+            bytes.push(1);
+            bytes.extend_from_slice(vault.as_slice());
+        }
+        None => bytes.push(0),
+    }
+    let connection_id = keccak256(&bytes);
+    
+    let domain = alloy::sol_types::eip712_domain! {
+        name: "Exchange",
+        version: "1",
+        chain_id: L1_ACTION_CHAIN_ID,
+        verifying_contract: Address::ZERO,
+    };
+    let type_hash = keccak256("Agent(string source,bytes32 connectionId)");
+    let source = if is_mainnet { "a" } else { "b" };
+    
+    let mut encoded = Vec::with_capacity(3 * 32);
+    encoded.extend_from_slice(type_hash.as_slice());
+    encoded.extend_from_slice(keccak256(source).as_slice());
+    encoded.extend_from_slice(connection_id.as_slice());
+    
+    sign_typed_data(wallet, &domain, keccak256(&encoded))
+}
+
+/// Signs an EIP-712 struct hash under `domain` and returns the signature in
+/// the `{r, s, v}` form the exchange expects.
+fn sign_typed_data(wallet: &PrivateKeySigner, domain: &alloy::sol_types::Eip712Domain, struct_hash: B256) -> Result<serde_json::Value, HyperliquidError> {
+    let mut digest_input = Vec::with_capacity(2 + 2 * 32);
+    digest_input.extend_from_slice(&[0x19, 0x01]);
+    digest_input.extend_from_slice(domain.separator().as_slice());
+    digest_input.extend_from_slice(struct_hash.as_slice());
+    
+    let signature = wallet.sign_hash_sync(&keccak256(&digest_input))
+        .map_err(|e| HyperliquidError::ApiError { message: format!("Failed to sign action: {}", e) })?;
+    Ok(serde_json::json!({
+        "r": format!("{:#x}", signature.r()),
+        "s": format!("{:#x}", signature.s()),
+        "v": 27 + u64::from(signature.v()),
+    }))
+}
+
+/// Unwraps the reply to a hand-signed exchange action: the `response` object
+/// of an `ok`, or the exchange's rejection as an error.
+fn exchange_response(body: &str) -> Result<serde_json::Value, HyperliquidError> {
+    let reply: RawExchangeReply = serde_json::from_str(body)
+        .map_err(|e| HyperliquidError::ApiError { message: e.to_string() })?;
+    match (reply.status.as_str(), reply.response) {
+        ("ok", response) => Ok(response),
+        (_, serde_json::Value::String(message)) => Err(HyperliquidError::from_api_message(message)),
+        (_, response) => Err(HyperliquidError::from_api_message(response.to_string())),
+    }
+}
+
+/// The per-order statuses in the `response` of an order action.
+fn order_statuses(response: &serde_json::Value) -> Result<Vec<ExchangeDataStatus>, HyperliquidError> {
+    serde_json::from_value(response["data"]["statuses"].clone())
+        .map_err(|e| HyperliquidError::ApiError { message: format!("Unexpected order response {}: {}", response, e) })
+}
+
+/// Rejects slippage outside `0..=MAX_SLIPPAGE` (including NaN), which would
+/// price a market order at or below zero.
+fn validate_slippage(fraction: f64) -> Result<f64, HyperliquidError> {
+    if !(0.0..=MAX_SLIPPAGE).contains(&fraction) {
+        return Err(HyperliquidError::InvalidInput {
+            message: format!("Slippage must be between 0 and {}, got {}", MAX_SLIPPAGE, fraction),
+        });
+    }
+    Ok(fraction)
+}
+
+/// Formats a size the way the exchange hashes it: at most 8 decimals, no
+/// trailing zeros.
+fn float_to_wire(value: f64) -> String {
+    let formatted = format!("{:.8}", value);
+    let trimmed = formatted.trim_end_matches('0').trim_end_matches('.');
+    if trimmed == "-0" { "0".to_string() } else { trimmed.to_string() }
+}
+
+/// Posts a raw request to the `/info` endpoint, for data the SDK's typed
+/// responses don't carry.
+async fn post_info<T: serde::de::DeserializeOwned>(client: &InfoClient, request: serde_json::Value) -> Result<T, HyperliquidError> {
+    let body = post_info_raw(client, &request).await?;
+    serde_json::from_str(&body).map_err(|e| HyperliquidError::ApiError { message: e.to_string() })
+}
+
+/// Like `post_info`, but returns the response body as the server sent it.
+async fn post_info_raw(client: &InfoClient, request: &serde_json::Value) -> Result<String, HyperliquidError> {
+    log::debug!("POST /info {}", request);
+    let body = client.http_client.post("/info", request.to_string()).await?;
+    log::trace!("/info response: {}", body);
+    Ok(body)
+}
+
+async fn fetch_asset_meta(client: &InfoClient, cache: &MetaCache, asset: &str) -> Result<RawAssetMeta, HyperliquidError> {
+    let meta = cache.perp(client).await?;
+    meta.universe
+        .iter()
+        .find(|a| a.name == asset)
+        .cloned()
+        .ok_or_else(|| HyperliquidError::InvalidInput { message: format!("Unknown asset {}", asset) })
+}
+
+/// There is no time endpoint; book snapshots are stamped with the server's
+/// clock.
+async fn fetch_server_time(client: &InfoClient) -> Result<u64, HyperliquidError> {
+    let book: RawTimestamped = post_info(client, serde_json::json!({ "type": "l2Book", "coin": SERVER_TIME_COIN })).await?;
+    Ok(book.time)
+}
+
+/// The exchange's time and its offset from the device clock, in milliseconds.
+async fn measure_clock_offset(client: &InfoClient) -> Result<(u64, i64), HyperliquidError> {
+    let sent_at = now_ms();
+    let server_time = fetch_server_time(client).await?;
+    // Assume the server read its clock halfway through the round trip.
+    let local_time = sent_at + now_ms().saturating_sub(sent_at) / 2;
+    Ok((server_time, server_time as i64 - local_time as i64))
+}
+
+/// `userRole` is `missing` for addresses the network has never seen.
+async fn account_exists(client: &InfoClient, user: Address, base_url: &BaseUrl) -> Result<bool, HyperliquidError> {
+    let role: RawUserRole = post_info(client, serde_json::json!({ "type": "userRole", "user": user })).await?;
+    let exists = role.role != "missing";
+    if !exists {
+        log::warn!("{:?} has no account on {:?}; is the key for the other network?", user, base_url);
+    }
+    Ok(exists)
+}
+
+/// The API answers `null` rather than `[]` for wallets without sub-accounts.
+async fn fetch_sub_accounts(client: &InfoClient, master: Address) -> Result<Vec<RawSubAccount>, HyperliquidError> {
+    let sub_accounts: Option<Vec<RawSubAccount>> = post_info(client, serde_json::json!({ "type": "subAccounts", "user": master })).await?;
+    Ok(sub_accounts.unwrap_or_default())
+}
+
+/// Length of a candle `interval`; months are taken as 31 days so a range
+/// built from it never falls short.
+fn candle_interval_ms(interval: &str) -> Option<u64> {
+    const MINUTE: u64 = 60_000;
+    let minutes = match interval {
+        "1m" => 1,
+        "3m" => 3,
+        "5m" => 5,
+        "15m" => 15,
+        "30m" => 30,
+        "1h" => 60,
+        "2h" => 2 * 60,
+        "4h" => 4 * 60,
+        "8h" => 8 * 60,
+        "12h" => 12 * 60,
+        "1d" => 24 * 60,
+        "3d" => 3 * 24 * 60,
+        "1w" => 7 * 24 * 60,
+        "1M" => 31 * 24 * 60,
+        _ => return None,
+    };
+    Some(minutes * MINUTE)
+}
+
+/// Rounds a size to the nearest multiple of the asset's size step.
+fn round_size(size: f64, sz_decimals: u32) -> f64 {
+    let factor = 10f64.powi(sz_decimals as i32);
+    (size * factor).round() / factor
+}
+
+/// Looks up the mid of `asset`, falling back to `mid_key` for spot pairs that
+/// `allMids` lists by index.
+fn find_mid(mids: &HashMap<String, String>, asset: &str, mid_key: Option<&str>) -> Result<f64, HyperliquidError> {
+    let mid = mids.get(asset)
+        .or_else(|| mid_key.and_then(|key| mids.get(key)))
+        .ok_or_else(|| HyperliquidError::InvalidInput { message: format!("No mid price for asset {}", asset) })?;
+    parse_number(&format!("{} mid", asset), mid)
+}
+
+/// Whether `value` already sits on the step it was rounded to, allowing for
+/// floating-point noise.
+fn is_on_step(value: f64, rounded: f64) -> bool {
+    (value - rounded).abs() <= rounded.abs() * 1e-9
+}
+
+fn is_spot_asset(asset: &str) -> bool {
+    asset.contains('/') || asset.starts_with('@')
+}
+
+/// What is needed to round prices and sizes for a perp or spot asset.
+struct AssetPrecision {
+    sz_decimals: u32,
+    is_spot: bool,
+    /// `@<index>` key spot pairs may be listed under in `allMids`.
+    mid_key: Option<String>,
+}
+
+impl AssetPrecision {
+    fn round_price(&self, price: f64) -> f64 {
+        round_price_to(price, self.sz_decimals, if self.is_spot { 8 } else { 6 })
+    }
+    
+    fn round_size(&self, size: f64) -> f64 {
+        round_size(size, self.sz_decimals)
+    }
+}
+
+async fn fetch_asset_precision(client: &InfoClient, cache: &MetaCache, asset: &str) -> Result<AssetPrecision, HyperliquidError> {
+    if !is_spot_asset(asset) {
+        let meta = fetch_asset_meta(client, cache, asset).await?;
+        return Ok(AssetPrecision { sz_decimals: meta.sz_decimals, is_spot: false, mid_key: None });
+    }
+    
+    let meta = cache.spot(client).await?;
+    let pair = meta.universe
+        .iter()
+        .find(|p| p.name == asset || format!("@{}", p.index) == asset)
+        .ok_or_else(|| HyperliquidError::InvalidInput { message: format!("Unknown spot pair {}", asset) })?;
+    let base = meta.tokens
+        .iter()
+        .find(|t| Some(&t.index) == pair.tokens.first())
+        .ok_or_else(|| HyperliquidError::ApiError { message: format!("Spot pair {} has no base token", asset) })?;
+    
+    Ok(AssetPrecision {
+        sz_decimals: base.sz_decimals,
+        is_spot: true,
+        mid_key: Some(format!("@{}", pair.index)),
+    })
+}
+
+/// Looks up a spot token by name (`PURR`) or by its full `NAME:tokenId` form.
+async fn fetch_spot_token(client: &InfoClient, cache: &MetaCache, token: &str) -> Result<RawSpotToken, HyperliquidError> {
+    let meta = cache.spot(client).await?;
+    meta.tokens
+        .iter()
+        .find(|t| t.name == token || t.wire_name() == token)
+        .cloned()
+        .ok_or_else(|| HyperliquidError::InvalidInput { message: format!("Unknown spot token {}", token) })
+}
+
+/// Parses a client order id given as 16 bytes of hex, with or without a `0x`
+/// prefix.
+fn parse_cloid(cloid: &str) -> Result<Uuid, HyperliquidError> {
+    let hex = cloid.strip_prefix("0x").unwrap_or(cloid);
+    if hex.len() != 32 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(HyperliquidError::InvalidInput {
+            message: format!("Invalid cloid {:?}: expected 16 bytes of hex, e.g. 0x1234567890abcdef1234567890abcdef", cloid),
+        });
+    }
+    Uuid::try_parse(hex).map_err(|e| HyperliquidError::InvalidInput { message: e.to_string() })
+}
+
+fn format_cloid(cloid: Uuid) -> String {
+    format!("0x{}", cloid.simple())
+}
+
+/// Rounds a price to 5 significant figures and at most `max_decimals -
+/// sz_decimals` decimal places, which is the precision the exchange accepts.
+/// `max_decimals` is 6 for perps and 8 for spot.
+fn round_price_to(price: f64, sz_decimals: u32, max_decimals: u32) -> f64 {
+    if price == 0.0 {
+        return 0.0;
+    }
+    let magnitude = price.abs().log10().floor() as i32;
+    let sig_fig_decimals = (4 - magnitude).max(0);
+    let max_decimals = (max_decimals as i32).saturating_sub(sz_decimals as i32).max(0);
+    let decimals = sig_fig_decimals.min(max_decimals);
+    let factor = 10f64.powi(decimals);
+    (price * factor).round() / factor
+}
+
+pub fn create_exchange_client(private_key: String, network: Network, config: Option<ExchangeClientConfig>) -> Result<Arc<HyperliquidExchange>, HyperliquidError> {
+    let client = HyperliquidExchange::new(private_key, network)?;
+    configure_exchange_client(client, config.unwrap_or_default())
+}
+
+pub fn create_exchange_client_from_mnemonic(phrase: String, derivation_index: u32, network: Network, config: Option<ExchangeClientConfig>) -> Result<Arc<HyperliquidExchange>, HyperliquidError> {
+    let client = HyperliquidExchange::from_mnemonic(phrase, derivation_index, network)?;
+    configure_exchange_client(client, config.unwrap_or_default())
+}
+
+fn configure_exchange_client(mut client: HyperliquidExchange, config: ExchangeClientConfig) -> Result<Arc<HyperliquidExchange>, HyperliquidError> {
+    if let Some(pool) = config.pool {
+        client = client.with_connection_pool(pool)?;
+    }
+    if let Some(vault_address) = config.vault_address {
+        client = client.with_vault(vault_address)?;
+    }
+    if let Some(builder) = config.builder {
+        client = client.with_builder(builder)?;
+    }
+    if let Some(dedupe_window_ms) = config.dedupe_window_ms {
+        client = client.with_order_dedupe(dedupe_window_ms);
+    }
+    if let Some(timeout_ms) = config.timeout_ms {
+        client = client.with_timeout(timeout_ms);
+    }
+    if let Some(retry) = config.retry {
+        client = client.with_retry(retry);
+    }
+    if let Some(rate_limit) = config.rate_limit {
+        client = client.with_rate_limit(rate_limit)?;
+    }
+    Ok(Arc::new(client))
+}
+
+/// Routes log records at `max_level` and above to `callback`, replacing any
+/// callback set before. Has no effect if the app installed its own `log`
+/// backend first.
+pub fn set_log_callback(callback: Box<dyn LogCallback>, max_level: LogLevel) {
+    *CALLBACK_LOGGER.callback.lock().unwrap_or_else(|e| e.into_inner()) = Some(callback);
+    // Only the first call installs the logger; later ones just swap the callback.
+    let _ = log::set_logger(&CALLBACK_LOGGER);
+    log::set_max_level(max_level.into());
+}
+
+pub fn create_info_client(base_url: BaseUrl, config: Option<InfoClientConfig>) -> Result<Arc<HyperliquidInfo>, HyperliquidError> {
+    let config = config.unwrap_or_default();
+    let mut client = HyperliquidInfo::with_runtime(base_url, config.runtime.shared_runtime()?)?;
+    if let Some(pool) = config.pool {
+        client = client.with_connection_pool(pool)?;
+    }
+    if let Some(meta_ttl_ms) = config.meta_ttl_ms {
+        client = client.with_meta_ttl(meta_ttl_ms);
+    }
+    if let Some(timeout_ms) = config.timeout_ms {
+        client = client.with_timeout(timeout_ms);
+    }
+    if let Some(retry) = config.retry {
+        client = client.with_retry(retry);
+    }
+    if let Some(rate_limit) = config.rate_limit {
+        client = client.with_rate_limit(rate_limit)?;
+    }
+    Ok(Arc::new(client))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn clearinghouse_state_margin_fields_read_their_own_source() {
+        let state: RawClearinghouseState = serde_json::from_value(serde_json::json!({
+            "assetPositions": [],
+            "marginSummary": {
+                "accountValue": "1250.5",
+                "totalNtlPos": "3000.0",
+                "totalRawUsd": "-1749.5",
+                "totalMarginUsed": "300.0"
+            },
+            "crossMarginSummary": {
+                "accountValue": "1250.5",
+                "totalNtlPos": "3000.0",
+                "totalRawUsd": "-1749.5",
+                "totalMarginUsed": "300.0"
+            },
+            "crossMaintenanceMarginUsed": "75.0",
+            "withdrawable": "950.5"
+        })).unwrap();
+        
+        let state = UserState::from_clearinghouse_state("0xabc".to_string(), state).unwrap();
+        assert_eq!(state.margin_summary_raw_usd, -1749.5);
+        assert_eq!(state.margin_summary_account_value, 1250.5);
+        assert_eq!(state.margin_summary_total_margin_used, 300.0);
+        assert_eq!(state.withdrawable, 950.5);
+        assert_eq!(state.cross_maintenance_margin_used, 75.0);
+    }
+    
+    fn raw_open_order(coin: &str, oid: u64) -> RawOpenOrder {
+        serde_json::from_value(serde_json::json!({
+            "coin": coin,
+            "limitPx": "100.0",
+            "oid": oid,
+            "side": "B",
+            "sz": "1.0",
+            "timestamp": 1_700_000_000_000u64
+        })).unwrap()
+    }
+    
+    #[test]
+    fn open_order_cancels_filters_by_asset() {
+        let orders = || vec![raw_open_order("ETH", 1), raw_open_order("BTC", 2), raw_open_order("ETH", 3)];
+        
+        let eth: Vec<u64> = open_order_cancels(orders(), Some("ETH")).into_iter().map(|cancel| cancel.oid).collect();
+        assert_eq!(eth, vec![1, 3]);
+        
+        let all: Vec<u64> = open_order_cancels(orders(), None).into_iter().map(|cancel| cancel.oid).collect();
+        assert_eq!(all, vec![1, 2, 3]);
+        
+        assert!(open_order_cancels(orders(), Some("SOL")).is_empty());
+    }
+    
+    #[test]
+    fn wallet_address_is_eip55_checksummed() {
+        let wallet = parse_private_key("0x0000000000000000000000000000000000000000000000000000000000000001").unwrap();
+        assert_eq!(checksummed_address(&wallet), "0x7E5F4552091A69125d5DfCb7b8C2659029395Bdf");
+        
+        let wallet = parse_private_key("0000000000000000000000000000000000000000000000000000000000000002").unwrap();
+        assert_eq!(checksummed_address(&wallet), "0x2B5AD5c4795c026514f8317c7a215E218DcCD6cF");
+    }
+    
+    #[test]
+    fn order_dedupe_makes_repeats_wait_for_the_first_send() {
+        let dedupe = OrderDedupe::new(Duration::from_secs(60));
+        let cloid = Uuid::new_v4();
+        let DedupeCheck::New(guard) = dedupe.begin(cloid) else { panic!("first begin should be new") };
+        assert!(matches!(dedupe.begin(cloid), DedupeCheck::InFlight(_)));
+        
+        drop(guard);
+        let DedupeCheck::Unresolved(guard) = dedupe.begin(cloid) else { panic!("unanswered order should be unresolved") };
+        dedupe.finish(cloid, &OrderResponse::from(ExchangeDataStatus::Success));
+        drop(guard);
+        assert!(matches!(dedupe.begin(cloid), DedupeCheck::Done(_)));
+    }
+    
+    #[test]
+    fn asset_ids_number_perps_and_spot_pairs() {
+        let perp: RawMeta = serde_json::from_value(serde_json::json!({
+            "universe": [
+                { "name": "BTC", "szDecimals": 5, "maxLeverage": 40 },
+                { "name": "ETH", "szDecimals": 4, "maxLeverage": 25 }
+            ]
+        })).unwrap();
+        let spot: RawSpotMeta = serde_json::from_value(serde_json::json!({
+            "universe": [
+                { "name": "PURR/USDC", "tokens": [1, 0], "index": 0 },
+                { "name": "@1", "tokens": [2, 0], "index": 1 }
+            ],
+            "tokens": [
+                { "name": "USDC", "szDecimals": 8, "index": 0, "tokenId": "0x6d1e7cde53ba9467b783cb7c530ce054" },
+                { "name": "PURR", "szDecimals": 0, "index": 1, "tokenId": "0xc1fb593aeffbeb02f85e0308e9956a90" },
+                { "name": "HFUN", "szDecimals": 2, "index": 2, "tokenId": "0xbaf265ef389da684513d98d68edf4eae" }
+            ]
+        })).unwrap();
+        
+        let ids = asset_ids(&perp, &spot);
+        assert_eq!(ids["BTC"], 0);
+        assert_eq!(ids["ETH"], 1);
+        assert_eq!(ids["PURR/USDC"], 10_000);
+        assert_eq!(ids["@1"], 10_001);
+        assert_eq!(ids["HFUN/USDC"], 10_001);
+    }
+    
+    fn order_response(status: serde_json::Value) -> OrderResponse {
+        OrderResponse::from(serde_json::from_value::<ExchangeDataStatus>(status).unwrap())
+    }
+    
+    #[test]
+    fn order_response_from_resting_and_filled_statuses() {
+        let resting = order_response(serde_json::json!({ "resting": { "oid": 77 } }));
+        assert_eq!(resting.status, "resting");
+        assert_eq!(resting.state, OrderState::Resting);
+        assert_eq!(resting.exchange_status, ExchangeStatus::Ok);
+        assert_eq!(resting.oid, Some(77));
+        assert!(resting.resting);
+        
+        let filled = order_response(serde_json::json!({ "filled": { "totalSz": "0.25", "avgPx": "1891.4", "oid": 78 } }));
+        assert_eq!(filled.status, "filled");
+        assert_eq!(filled.state, OrderState::Filled);
+        assert_eq!(filled.exchange_status, ExchangeStatus::Ok);
+        assert_eq!(filled.oid, Some(78));
+        assert_eq!(filled.filled_size, Some(0.25));
+        assert_eq!(filled.average_price, Some(1891.4));
+        assert!(!filled.resting);
+    }
+    
+    #[test]
+    fn order_response_from_statuses_without_an_order() {
+        let success = order_response(serde_json::json!("success"));
+        assert_eq!(success.status, "success");
+        assert_eq!(success.state, OrderState::Accepted);
+        assert_eq!(success.exchange_status, ExchangeStatus::Ok);
+        
+        let waiting_for_fill = order_response(serde_json::json!("waitingForFill"));
+        assert_eq!(waiting_for_fill.state, OrderState::WaitingForFill);
+        assert_eq!(waiting_for_fill.exchange_status, ExchangeStatus::Waiting);
+        
+        let waiting_for_trigger = order_response(serde_json::json!("waitingForTrigger"));
+        assert_eq!(waiting_for_trigger.state, OrderState::WaitingForTrigger);
+        assert_eq!(waiting_for_trigger.exchange_status, ExchangeStatus::Waiting);
+        
+        let error = order_response(serde_json::json!({ "error": "Order must have minimum value of $10." }));
+        assert_eq!(error.status, "error");
+        assert_eq!(error.state, OrderState::Rejected);
+        assert_eq!(error.exchange_status, ExchangeStatus::Err { message: "Order must have minimum value of $10.".to_string() });
+        assert_eq!(error.error.as_deref(), Some("Order must have minimum value of $10."));
+        assert_eq!(error.oid, None);
+    }
+    
+    #[test]
+    fn order_state_from_order_status_strings() {
+        assert_eq!(OrderState::from_order_status("open"), OrderState::Resting);
+        assert_eq!(OrderState::from_order_status("filled"), OrderState::Filled);
+        assert_eq!(OrderState::from_order_status("canceled"), OrderState::Canceled);
+        assert_eq!(OrderState::from_order_status("marginCanceled"), OrderState::Canceled);
+        assert_eq!(OrderState::from_order_status("reduceOnlyCanceled"), OrderState::Canceled);
+        assert_eq!(OrderState::from_order_status("rejected"), OrderState::Rejected);
+        assert_eq!(OrderState::from_order_status("perpMarginRejected"), OrderState::Rejected);
+        assert_eq!(OrderState::from_order_status("triggered"), OrderState::Accepted);
+    }
+}